@@ -14,6 +14,7 @@ use std::string;
 
 use depot_client;
 use hcore;
+use protobuf;
 use rustc_serialize::json;
 
 pub type Result<T> = result::Result<T, Error>;
@@ -26,13 +27,18 @@ pub enum Error {
     DepotClient(depot_client::Error),
     FileNameError,
     HabitatCore(hcore::Error),
+    InvalidSignature(String),
     InvalidTomlError(String),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
     JsonDecode(json::DecoderError),
     JsonEncode(json::EncoderError),
+    Protobuf(protobuf::ProtobufError),
     StrFromUtf8Error(str::Utf8Error),
     StringFromUtf8Error(string::FromUtf8Error),
+    UnsignedGossipFile(String),
+    UnsupportedWireVersion(u16),
+    UntrustedSigner(String),
     WireDecode(String),
 }
 
@@ -50,12 +56,21 @@ impl fmt::Display for Error {
             Error::DepotClient(ref err) => format!("{}", err),
             Error::FileNameError => format!("Failed to extract a filename"),
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::InvalidSignature(ref e) => format!("Invalid signature: {}", e),
             Error::InvalidTomlError(ref e) => format!("Invalid TOML: {}", e),
             Error::IO(ref err) => format!("{}", err),
             Error::JsonDecode(ref e) => format!("JSON decoding error: {}", e),
             Error::JsonEncode(ref e) => format!("JSON encoding error: {}", e),
+            Error::Protobuf(ref e) => format!("Protobuf error: {}", e),
             Error::StrFromUtf8Error(ref e) => format!("{}", e),
             Error::StringFromUtf8Error(ref e) => format!("{}", e),
+            Error::UnsignedGossipFile(ref s) => {
+                format!("{} is not signed by a trusted user", s)
+            }
+            Error::UnsupportedWireVersion(v) => {
+                format!("Wire version {} is newer than the versions we speak", v)
+            }
+            Error::UntrustedSigner(ref s) => format!("{} is not a trusted user", s),
             Error::WireDecode(ref m) => format!("Failed to decode wire message: {}", m),
         };
         write!(f, "{}", msg)
@@ -73,12 +88,17 @@ impl error::Error for Error {
             Error::DepotClient(ref err) => err.description(),
             Error::FileNameError => "Failed to extract a filename from a path",
             Error::HabitatCore(ref err) => err.description(),
+            Error::InvalidSignature(_) => "Invalid signature",
             Error::InvalidTomlError(_) => "Invalid TOML",
             Error::IO(ref err) => err.description(),
             Error::JsonDecode(_) => "JSON decoding error: {:?}",
             Error::JsonEncode(_) => "JSON encoding error",
+            Error::Protobuf(ref err) => err.description(),
             Error::StrFromUtf8Error(_) => "Failed to convert a string as UTF-8",
             Error::StringFromUtf8Error(_) => "Failed to convert a string as UTF-8",
+            Error::UnsignedGossipFile(_) => "Gossip file is not signed by a trusted user",
+            Error::UnsupportedWireVersion(_) => "Wire version is newer than the versions we speak",
+            Error::UntrustedSigner(_) => "Gossip file is signed by an untrusted user",
             Error::WireDecode(_) => "Failed to decode wire message",
         }
     }
@@ -114,6 +134,12 @@ impl From<json::EncoderError> for Error {
     }
 }
 
+impl From<protobuf::ProtobufError> for Error {
+    fn from(err: protobuf::ProtobufError) -> Self {
+        Error::Protobuf(err)
+    }
+}
+
 impl From<str::Utf8Error> for Error {
     fn from(err: str::Utf8Error) -> Self {
         Error::StrFromUtf8Error(err)
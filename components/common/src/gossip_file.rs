@@ -7,13 +7,15 @@
 
 use std;
 use std::collections::HashMap;
+use std::collections::hash_map::Values;
 use std::fmt;
 use std::fs::File;
 use std::io::prelude::*;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-use hcore::crypto::{BoxKeyPair, default_cache_key_path};
+use hcore::crypto::{BoxKeyPair, SigKeyPair, default_cache_key_path};
+use hcore::crypto::keys::parse_name_with_rev;
 use hcore::fs;
 use hcore::service::ServiceGroup;
 use openssl::crypto::hash as openssl_hash;
@@ -22,6 +24,8 @@ use rustc_serialize::hex::ToHex;
 use time::{SteadyTime, Duration};
 use toml;
 use error::{Error, Result};
+use message::gossip as proto;
+use wire_message::Protobuf;
 
 const IDEMPOTENCY_INTERVAL_MINUTES: i64 = 5;
 
@@ -37,6 +41,10 @@ pub struct GossipFile {
     version_number: u64,
     written: bool,
     encrypted: bool,
+    /// The name with revision of the user signing key that signed the file
+    signer: Option<String>,
+    /// The service group, file name, version number and checksum, signed by `signer`
+    signature: Option<Vec<u8>>,
 }
 
 impl GossipFile {
@@ -71,6 +79,8 @@ impl GossipFile {
             version_number: version_number,
             written: false,
             encrypted: false,
+            signer: None,
+            signature: None,
         };
         Ok(cf)
     }
@@ -112,6 +122,8 @@ impl GossipFile {
             version_number: version_number,
             written: false,
             encrypted: true,
+            signer: None,
+            signature: None,
         };
         Ok(cf)
     }
@@ -136,10 +148,113 @@ impl GossipFile {
             version_number: version_number,
             written: false,
             encrypted: false,
+            signer: None,
+            signature: None,
         };
         Ok(cf)
     }
 
+    /// Creates a `gossip.toml` GossipFile from a Vec<u8> containing valid TOML, signed with a
+    /// user's signing key. If `encrypt_with` has the user's and the service group's box keys,
+    /// the body is encrypted to the service key as well, so only members of the service group
+    /// can read it.
+    pub fn from_body_signed(signing_pair: &SigKeyPair,
+                            service_group: ServiceGroup,
+                            body: Vec<u8>,
+                            version_number: u64,
+                            encrypt_with: Option<(&BoxKeyPair, &BoxKeyPair)>)
+                            -> Result<GossipFile> {
+        let toml_body = try!(String::from_utf8(body.clone()));
+        try!(is_data_toml(toml_body));
+
+        let checksum = openssl_hash::hash(openssl_hash::Type::SHA256, &body);
+        let (body, encrypted) = match encrypt_with {
+            Some((user_pair, service_pair)) => (try!(user_pair.encrypt(&body, service_pair)), true),
+            None => (body, false),
+        };
+        let mut cf = GossipFile {
+            service_group: service_group,
+            file_name: GOSSIP_TOML.to_string(),
+            body: body,
+            checksum: checksum.as_slice().to_hex(),
+            version_number: version_number,
+            written: false,
+            encrypted: encrypted,
+            signer: Some(signing_pair.name_with_rev()),
+            signature: None,
+        };
+        let signature = try!(signing_pair.sign(cf.signed_message().as_bytes()));
+        cf.signature = Some(signature);
+        Ok(cf)
+    }
+
+    /// The version this file was published with.
+    pub fn version_number(&self) -> u64 {
+        self.version_number
+    }
+
+    /// The name with revision of the user signing key that signed this file, if it is signed.
+    pub fn signer(&self) -> Option<String> {
+        self.signer.clone()
+    }
+
+    /// Verify that this file was signed by one of `trusted_users`, using the public signing
+    /// keys in `cache_key_path`. When `trusted_users` is empty, unsigned files are accepted, but
+    /// a signature that is present must still check out.
+    ///
+    /// # Errors
+    ///
+    /// * If the file is unsigned and we trust particular users
+    /// * If the signer is not the signing key of one of `trusted_users`
+    /// * If we do not have the signer's public key, or the service's secret key for an
+    ///   encrypted file
+    /// * If the signature or the body don't match what was signed
+    pub fn verify<P: AsRef<Path> + ?Sized>(&self,
+                                           trusted_users: &[String],
+                                           cache_key_path: &P)
+                                           -> Result<()> {
+        let (signer, signature) = match (self.signer.as_ref(), self.signature.as_ref()) {
+            (Some(signer), Some(signature)) => (signer, signature),
+            (None, None) => {
+                if trusted_users.is_empty() {
+                    return Ok(());
+                }
+                return Err(Error::UnsignedGossipFile(self.to_string()));
+            }
+            _ => return Err(Error::InvalidSignature(format!("{} has no signer", self))),
+        };
+        let (name, _) = try!(parse_name_with_rev(signer));
+        if !trusted_users.is_empty() &&
+           !trusted_users.iter().any(|user| SigKeyPair::user_key_name(user) == name) {
+            return Err(Error::UntrustedSigner(name));
+        }
+        let pair = try!(SigKeyPair::get_pair_for(signer, cache_key_path));
+        let signed = try!(pair.verify(signature));
+        if signed != self.signed_message().into_bytes() {
+            return Err(Error::InvalidSignature(format!("{} does not match its signature", self)));
+        }
+        let body = if self.encrypted {
+            try!(BoxKeyPair::decrypt(&self.body, cache_key_path))
+        } else {
+            self.body.clone()
+        };
+        let checksum = openssl_hash::hash(openssl_hash::Type::SHA256, &body);
+        if checksum.as_slice().to_hex() != self.checksum {
+            return Err(Error::InvalidSignature(format!("{} does not match its checksum", self)));
+        }
+        Ok(())
+    }
+
+    /// What a user signs: everything that decides whether the file wins over another one, and
+    /// what ends up on disk.
+    fn signed_message(&self) -> String {
+        format!("{}\n{}\n{}\n{}",
+                self.service_group,
+                self.file_name,
+                self.version_number,
+                self.checksum)
+    }
+
     /// Updates this struct against another `GossipFile`. If true is returned, we have changed the gossip file
     /// and the rumour should stay hot. If false is returned, nothing has changed and the rumour
     /// can start to go cold. The algorithm is as follows:
@@ -246,6 +361,50 @@ impl PartialEq for GossipFile {
     }
 }
 
+impl Protobuf for GossipFile {
+    type Message = proto::GossipFile;
+
+    fn to_proto(&self) -> proto::GossipFile {
+        let mut gf = proto::GossipFile::new();
+        gf.set_service_group(self.service_group.to_proto());
+        gf.set_file_name(self.file_name.clone());
+        gf.set_body(self.body.clone());
+        gf.set_checksum(self.checksum.clone());
+        gf.set_version_number(self.version_number);
+        gf.set_written(self.written);
+        gf.set_encrypted(self.encrypted);
+        if let Some(ref signer) = self.signer {
+            gf.set_signer(signer.clone());
+        }
+        if let Some(ref signature) = self.signature {
+            gf.set_signature(signature.clone());
+        }
+        gf
+    }
+
+    fn from_proto(mut gf: proto::GossipFile) -> Result<GossipFile> {
+        Ok(GossipFile {
+            service_group: try!(ServiceGroup::from_proto(gf.take_service_group())),
+            file_name: gf.take_file_name(),
+            body: gf.take_body(),
+            checksum: gf.take_checksum(),
+            version_number: gf.get_version_number(),
+            written: gf.get_written(),
+            encrypted: gf.get_encrypted(),
+            signer: if gf.has_signer() {
+                Some(gf.take_signer())
+            } else {
+                None
+            },
+            signature: if gf.has_signature() {
+                Some(gf.take_signature())
+            } else {
+                None
+            },
+        })
+    }
+}
+
 
 /// try to parse the string as Toml
 fn is_data_toml(body: String) -> Result<()> {
@@ -319,6 +478,8 @@ pub struct GossipFileList {
     gossip_files: HashMap<(ServiceGroup, String), GossipFile>,
     next_idempotency_check: SteadyTime,
     pub file_write_retries: HashMap<String, FileWriteRetry>,
+    // The users whose signed configuration we accept; empty accepts unsigned configuration
+    trusted_users: Vec<String>,
 }
 
 impl GossipFileList {
@@ -329,9 +490,15 @@ impl GossipFileList {
             next_idempotency_check: SteadyTime::now() +
                                     Duration::minutes(IDEMPOTENCY_INTERVAL_MINUTES),
             file_write_retries: HashMap::new(),
+            trusted_users: Vec::new(),
         }
     }
 
+    /// Only accept configuration for our service group that is signed by one of these users.
+    pub fn set_trusted_users(&mut self, users: Vec<String>) {
+        self.trusted_users = users;
+    }
+
     // We should write out if our metadata tells us to. Also, if we haven't checked the state of
     // the filesystem in the last 5 minutes, we're going to call write regardless, just to make
     // sure nothing has changed out from under us on disk. Viva la Chef!
@@ -430,7 +597,16 @@ impl GossipFileList {
         Ok((needs_file_updated, needs_reconfigure))
     }
 
+    /// Merges a gossip file from a rumor, returning true if it changed our list. Configuration
+    /// for our own service group has to pass `GossipFile::verify` first; anything else is
+    /// rejected and logged, so it is neither written nor passed on by us.
     pub fn process(&mut self, remote_gf: GossipFile) -> bool {
+        if remote_gf.service_group == self.my_service_group && remote_gf.file_name == GOSSIP_TOML {
+            if let Err(e) = remote_gf.verify(&self.trusted_users, &default_cache_key_path(None)) {
+                println!("Rejecting configuration {}: {}", remote_gf, e);
+                return false;
+            }
+        }
         if let Some(mut current_gf) = self.get_mut(&remote_gf.service_group, &remote_gf.file_name) {
             return current_gf.update_via(remote_gf);
         }
@@ -439,6 +615,18 @@ impl GossipFileList {
         true
     }
 
+    /// The version of the `gossip.toml` we have for our own service group, if we have one.
+    pub fn config_version(&self) -> Option<u64> {
+        self.gossip_files
+            .get(&(self.my_service_group.clone(), GOSSIP_TOML.to_string()))
+            .map(|gf| gf.version_number)
+    }
+
+    /// Returns an iterator over every gossip file we know about.
+    pub fn files(&self) -> Values<(ServiceGroup, String), GossipFile> {
+        self.gossip_files.values()
+    }
+
     fn get_mut(&mut self,
                service_group: &ServiceGroup,
                file_name: &str)
@@ -459,9 +647,10 @@ mod test {
     use tempdir::TempDir;
     use time::SteadyTime;
 
-    use hcore::crypto::BoxKeyPair;
+    use hcore::crypto::{BoxKeyPair, SigKeyPair};
     use hcore::service::ServiceGroup;
-    use gossip_file::{GossipFile, FileWriteRetry, GOSSIP_TOML};
+    use gossip_file::{GossipFile, GossipFileList, FileWriteRetry, GOSSIP_TOML};
+    use wire_message::Protobuf;
 
     fn fixture(name: &str) -> PathBuf {
         env::current_exe()
@@ -558,6 +747,129 @@ mod test {
     }
 
 
+    #[test]
+    fn new_from_body_signed() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let signing_pair = SigKeyPair::generate_pair_for_user("testuser", cache.path()).unwrap();
+        let gf = GossipFile::from_body_signed(&signing_pair,
+                                              ServiceGroup::from_str("chromeo.footwork@someorg")
+                                                  .unwrap(),
+                                              "Rage=1\n".as_bytes().to_vec(),
+                                              45,
+                                              None)
+            .unwrap();
+        assert_eq!(gf.service_group,
+                   ServiceGroup::from_str("chromeo.footwork@someorg").unwrap());
+        assert_eq!(gf.file_name, GOSSIP_TOML);
+        assert_eq!(gf.body, "Rage=1\n".as_bytes().to_vec());
+        assert_eq!(gf.signer(), Some(signing_pair.name_with_rev()));
+        assert!(gf.verify(&["testuser".to_string()], cache.path()).is_ok());
+        assert!(gf.verify(&[], cache.path()).is_ok());
+    }
+
+    #[test]
+    fn new_from_body_signed_and_encrypted() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let signing_pair = SigKeyPair::generate_pair_for_user("testuser", cache.path()).unwrap();
+        let user_pair = BoxKeyPair::generate_pair_for_user("testuser", cache.path()).unwrap();
+        let service_pair =
+            BoxKeyPair::generate_pair_for_service("someorg", "chromeo.footwork", cache.path())
+                .unwrap();
+        let gf = GossipFile::from_body_signed(&signing_pair,
+                                              ServiceGroup::from_str("chromeo.footwork@someorg")
+                                                  .unwrap(),
+                                              "Rage=1\n".as_bytes().to_vec(),
+                                              45,
+                                              Some((&user_pair, &service_pair)))
+            .unwrap();
+        assert!(gf.body != "Rage=1\n".as_bytes().to_vec());
+        assert_eq!(gf.checksum,
+                   "c14c4757090e7e734941d2b948484b84e4179404f6bab053b3ca21cbb7b0d6c8");
+        assert_eq!(BoxKeyPair::decrypt(&gf.body, cache.path()).unwrap(),
+                   "Rage=1\n".as_bytes().to_vec());
+        assert!(gf.verify(&["testuser".to_string()], cache.path()).is_ok());
+    }
+
+    #[test]
+    fn verify_rejects_untrusted_and_unsigned() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let signing_pair = SigKeyPair::generate_pair_for_user("mallory", cache.path()).unwrap();
+        let signed = GossipFile::from_body_signed(&signing_pair,
+                                                  ServiceGroup::from_str("chromeo.footwork@someorg")
+                                                      .unwrap(),
+                                                  "Rage=1\n".as_bytes().to_vec(),
+                                                  45,
+                                                  None)
+            .unwrap();
+        assert!(signed.verify(&["testuser".to_string()], cache.path()).is_err());
+
+        let unsigned = GossipFile::from_body(ServiceGroup::from_str("chromeo.footwork@someorg")
+                                                 .unwrap(),
+                                             "Rage=1\n".as_bytes().to_vec(),
+                                             45)
+            .unwrap();
+        assert!(unsigned.verify(&["testuser".to_string()], cache.path()).is_err());
+        assert!(unsigned.verify(&[], cache.path()).is_ok());
+    }
+
+    #[test]
+    fn verify_rejects_forged_signer() {
+        // A member of the service group holds the service key, but not the user's signing key,
+        // so it can't sign in the user's name with a key of its own.
+        let cache = TempDir::new("key_cache").unwrap();
+        let trusted = SigKeyPair::generate_pair_for_user("testuser", cache.path()).unwrap();
+        let forger = SigKeyPair::generate_pair_for_user("mallory", cache.path()).unwrap();
+        let mut gf = GossipFile::from_body_signed(&forger,
+                                                  ServiceGroup::from_str("chromeo.footwork@someorg")
+                                                      .unwrap(),
+                                                  "Rage=1\n".as_bytes().to_vec(),
+                                                  45,
+                                                  None)
+            .unwrap();
+        gf.signer = Some(trusted.name_with_rev());
+        assert!(gf.verify(&["testuser".to_string()], cache.path()).is_err());
+    }
+
+    #[test]
+    fn verify_rejects_tampering() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let signing_pair = SigKeyPair::generate_pair_for_user("testuser", cache.path()).unwrap();
+        let gf = GossipFile::from_body_signed(&signing_pair,
+                                              ServiceGroup::from_str("chromeo.footwork@someorg")
+                                                  .unwrap(),
+                                              "Rage=1\n".as_bytes().to_vec(),
+                                              45,
+                                              None)
+            .unwrap();
+        let trusted = ["testuser".to_string()];
+
+        let mut bumped = gf.clone();
+        bumped.version_number = 46;
+        assert!(bumped.verify(&trusted, cache.path()).is_err());
+
+        let mut rewritten = gf.clone();
+        rewritten.body = "Rage=0\n".as_bytes().to_vec();
+        assert!(rewritten.verify(&trusted, cache.path()).is_err());
+    }
+
+    #[test]
+    fn round_trips_through_protobuf() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let signing_pair = SigKeyPair::generate_pair_for_user("testuser", cache.path()).unwrap();
+        let gf = GossipFile::from_body_signed(&signing_pair,
+                                              ServiceGroup::from_str("chromeo.footwork@someorg")
+                                                  .unwrap(),
+                                              "Rage=1\n".as_bytes().to_vec(),
+                                              45,
+                                              None)
+            .unwrap();
+        let read = GossipFile::from_proto(gf.to_proto()).unwrap();
+        assert_eq!(read, gf);
+        assert_eq!(read.body, gf.body);
+        assert_eq!(read.signer(), gf.signer());
+        assert!(read.verify(&["testuser".to_string()], cache.path()).is_ok());
+    }
+
     #[test]
     fn update_via_when_other_version_is_higher() {
         let mut me = GossipFile::from_body(ServiceGroup::from_str("foofighters.arlandria")
@@ -642,6 +954,22 @@ mod test {
         assert_eq!(me == other, true);
     }
 
+    #[test]
+    fn config_version_is_my_gossip_toml_version() {
+        let mine = ServiceGroup::from_str("heart.barracuda").unwrap();
+        let mut list = GossipFileList::new(mine.clone());
+        assert_eq!(list.config_version(), None);
+        let other = GossipFile::from_body(ServiceGroup::from_str("heart.magic").unwrap(),
+                                          "woot=1\n".as_bytes().to_vec(),
+                                          7)
+            .unwrap();
+        list.process(other);
+        assert_eq!(list.config_version(), None);
+        let gf = GossipFile::from_body(mine, "woot=1\n".as_bytes().to_vec(), 3).unwrap();
+        list.process(gf);
+        assert_eq!(list.config_version(), Some(3));
+    }
+
     #[test]
     fn file_write_retry_encode() {
         let fwr = FileWriteRetry::new("foo".to_string(),
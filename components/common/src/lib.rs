@@ -13,6 +13,7 @@ extern crate ansi_term;
 extern crate log;
 extern crate openssl;
 extern crate pbr;
+extern crate protobuf;
 extern crate regex;
 extern crate rustc_serialize;
 #[cfg(test)]
@@ -25,4 +26,5 @@ pub use self::error::{Error, Result};
 pub mod command;
 pub mod gossip_file;
 pub mod error;
+pub mod message;
 pub mod wire_message;
@@ -0,0 +1,11 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! Messages generated from `protocols/*.proto`. Build with `--features protocols` to regenerate
+//! them.
+
+pub mod gossip;
@@ -12,15 +12,107 @@
 //! over a UTP socket between Supervisors or from a CLI to one or more Supervisors. The message can
 //! optionally be encrypted or decrypted with a symmetric encryption key. The message itself
 //! contains extra information if encryption is used, and blank if the message is plaintext.
+//!
+//! # Wire versions
+//!
+//! * Version 1 is the whole `WireMessage` as JSON, with the message inside it as JSON too.
+//! * Version 2 starts with `HABW` and the version as a big endian `u16`, so a reader can tell the
+//!   two apart and refuse versions newer than it knows. Then come the format as a byte, the key
+//!   and the nonce, each as a big endian `u16` length and that many bytes (none if they are
+//!   empty), and the message as a big endian `u32` length and that many bytes. The message is
+//!   the type's `Protobuf` message, from `protocols/gossip.proto`. It also adds the `Sync` and
+//!   `SyncAck` protocol messages, which are never sent in version 1.
+//!
+//! Every version after 1 starts with the same six bytes; what follows depends on the version.
+//!
+//! # Compatibility
+//!
+//! So that Supervisors of different releases can share a ring while it is upgraded:
+//!
+//! * A reader accepts every version up to `CURRENT_WIRE_VERSION`, and refuses newer ones.
+//! * A writer uses the lowest of its own version and the version the reader advertises; a reader
+//!   that advertises nothing speaks version 1. `negotiate` makes this choice.
+//! * Within a version, a struct may gain fields only if they are `Option`s, which older readers
+//!   skip and newer readers decode as `None` when they are missing. In the proto, such a field is
+//!   `optional` and takes a number no field has had before. Fields can't be removed, renamed,
+//!   renumbered or change type, and a `required` field can never be added.
+//! * Enums may gain variants, but an older reader can't decode a message that contains one, so
+//!   nothing should send a new variant until the whole ring understands it. That means a new
+//!   version, which readers advertise once they understand the variant.
+//! * Anything else needs a new version.
 
+use std::cmp;
 use std::str;
 
 use hcore::crypto::SymKey;
+use hcore::service::ServiceGroup;
+use protobuf::{self, Message};
 use rustc_serialize::{Decodable, Encodable, json};
 
 use error::{Error, Result};
+use message::gossip as proto;
 
+/// The JSON wire version, which every Supervisor speaks
+pub const WIRE_VERSION_JSON: u16 = 1;
+/// The protobuf wire version, which adds the `Sync` and `SyncAck` protocol messages
+pub const WIRE_VERSION_BINARY: u16 = 2;
+/// The newest wire version we speak
+pub const CURRENT_WIRE_VERSION: u16 = WIRE_VERSION_BINARY;
+
+/// The bytes every wire message after version 1 starts with
+const MAGIC: &'static [u8] = b"HABW";
 const WIRE_VERSION: &'static str = "WIRE-1";
+const BINARY_WIRE_VERSION: &'static str = "WIRE-2";
+
+/// The wire version to speak to a reader that advertises `theirs`.
+pub fn negotiate(theirs: Option<u16>) -> u16 {
+    cmp::min(CURRENT_WIRE_VERSION, theirs.unwrap_or(WIRE_VERSION_JSON))
+}
+
+/// The name a `WireMessage` carries for a wire version.
+fn version_name(wire_version: u16) -> Result<&'static str> {
+    match wire_version {
+        WIRE_VERSION_JSON => Ok(WIRE_VERSION),
+        WIRE_VERSION_BINARY => Ok(BINARY_WIRE_VERSION),
+        v => Err(Error::UnsupportedWireVersion(v)),
+    }
+}
+
+/// A type that is sent in wire versions after 1, as a protobuf message.
+pub trait Protobuf: Sized {
+    /// The generated message it is sent as
+    type Message: protobuf::Message + protobuf::MessageStatic;
+
+    fn to_proto(&self) -> Self::Message;
+
+    /// # Errors
+    ///
+    /// * If the message holds something the type can't, such as a malformed id
+    fn from_proto(proto: Self::Message) -> Result<Self>;
+}
+
+impl Protobuf for ServiceGroup {
+    type Message = proto::ServiceGroup;
+
+    fn to_proto(&self) -> proto::ServiceGroup {
+        let mut sg = proto::ServiceGroup::new();
+        sg.set_service(self.service.clone());
+        sg.set_group(self.group.clone());
+        if let Some(ref org) = self.organization {
+            sg.set_organization(org.clone());
+        }
+        sg
+    }
+
+    fn from_proto(mut sg: proto::ServiceGroup) -> Result<ServiceGroup> {
+        let organization = if sg.has_organization() {
+            Some(sg.take_organization())
+        } else {
+            None
+        };
+        Ok(ServiceGroup::new(sg.take_service(), sg.take_group(), organization))
+    }
+}
 
 /// The types of valid messages: currently `Plain` or `Encrypted`.
 #[derive(Debug, PartialEq, RustcEncodable, RustcDecodable)]
@@ -90,13 +182,7 @@ impl WireMessage {
     ///
     /// * If the `msg` cannot be encoded into bytes
     pub fn plain<T: Encodable>(msg: &T) -> Result<WireMessage> {
-        Ok(WireMessage {
-            format: MessageFormat::Plain,
-            version: WIRE_VERSION.to_string(),
-            key: None,
-            nonce: None,
-            msg_bytes: try!(json::encode(&msg)).into_bytes(),
-        })
+        WireMessage::seal(WIRE_VERSION, try!(json::encode(msg)).into_bytes(), None)
     }
 
     /// Creates a new encrypted `WireMessage`.
@@ -141,13 +227,144 @@ impl WireMessage {
     /// * If the message can't be encoded to bytes
     /// * If a crypto error occurs when encrypting
     pub fn encrypted<T: Encodable>(msg: &T, sym_key: &SymKey) -> Result<WireMessage> {
-        let (nonce, ciphertext) = try!(sym_key.encrypt(try!(json::encode(&msg)).as_bytes()));
+        WireMessage::seal(WIRE_VERSION, try!(json::encode(msg)).into_bytes(), Some(sym_key))
+    }
+
+    /// Creates a new `WireMessage` in a particular wire version, encrypted if there is a
+    /// `sym_key`.
+    ///
+    /// # Errors
+    ///
+    /// * If the wire version is one we don't speak
+    /// * If the message can't be encoded to bytes
+    /// * If a crypto error occurs when encrypting
+    pub fn with_version<T: Encodable + Protobuf>(msg: &T,
+                                                 sym_key: Option<&SymKey>,
+                                                 wire_version: u16)
+                                                 -> Result<WireMessage> {
+        let name = try!(version_name(wire_version));
+        let bytes = if wire_version == WIRE_VERSION_JSON {
+            try!(json::encode(msg)).into_bytes()
+        } else {
+            try!(msg.to_proto().write_to_bytes())
+        };
+        WireMessage::seal(name, bytes, sym_key)
+    }
+
+    fn seal(version: &str, bytes: Vec<u8>, sym_key: Option<&SymKey>) -> Result<WireMessage> {
+        match sym_key {
+            Some(sym_key) => {
+                let (nonce, ciphertext) = try!(sym_key.encrypt(&bytes));
+                Ok(WireMessage {
+                    format: MessageFormat::Encrypted,
+                    version: version.to_string(),
+                    key: Some(sym_key.name_with_rev()),
+                    nonce: Some(nonce),
+                    msg_bytes: ciphertext,
+                })
+            }
+            None => {
+                Ok(WireMessage {
+                    format: MessageFormat::Plain,
+                    version: version.to_string(),
+                    key: None,
+                    nonce: None,
+                    msg_bytes: bytes,
+                })
+            }
+        }
+    }
+
+    /// The name with revision of the key this message was encrypted with, if it is encrypted.
+    pub fn key_name_with_rev(&self) -> Option<&str> {
+        self.key.as_ref().map(|k| &k[..])
+    }
+
+    /// The wire version of this message.
+    pub fn wire_version(&self) -> u16 {
+        match &self.version[..] {
+            BINARY_WIRE_VERSION => WIRE_VERSION_BINARY,
+            _ => WIRE_VERSION_JSON,
+        }
+    }
+
+    /// The bytes to send for this message, in its wire version.
+    ///
+    /// # Errors
+    ///
+    /// * If the message can't be encoded
+    /// * If the key, nonce or message is too long for its length prefix
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        if self.wire_version() == WIRE_VERSION_JSON {
+            return Ok(try!(json::encode(self)).into_bytes());
+        }
+        let mut out = Vec::with_capacity(self.msg_bytes.len() + 64);
+        out.extend_from_slice(MAGIC);
+        put_u16(&mut out, self.wire_version());
+        out.push(match self.format {
+            MessageFormat::Plain => 0,
+            MessageFormat::Encrypted => 1,
+        });
+        let key = self.key.as_ref().map(|k| k.as_bytes()).unwrap_or(&[]);
+        try!(put_short_bytes(&mut out, key));
+        let nonce = self.nonce.as_ref().map(|n| &n[..]).unwrap_or(&[]);
+        try!(put_short_bytes(&mut out, nonce));
+        if self.msg_bytes.len() > u32::max_value() as usize {
+            return Err(Error::WireDecode("message is too long".to_string()));
+        }
+        put_u32(&mut out, self.msg_bytes.len() as u32);
+        out.extend_from_slice(&self.msg_bytes);
+        Ok(out)
+    }
+
+    /// Reads a message we received, in any wire version we speak.
+    ///
+    /// # Errors
+    ///
+    /// * If the message is in a newer wire version than we speak
+    /// * If the message is malformed
+    pub fn from_bytes(bytes: &[u8]) -> Result<WireMessage> {
+        if !bytes.starts_with(MAGIC) {
+            let json_str = try!(str::from_utf8(bytes));
+            return Ok(try!(json::decode(json_str)));
+        }
+        let mut frame = Frame {
+            bytes: bytes,
+            pos: MAGIC.len(),
+        };
+        let version = try!(frame.u16());
+        if version < WIRE_VERSION_BINARY {
+            return Err(Error::WireDecode(format!("Wire version {} is never framed", version)));
+        }
+        let name = try!(version_name(version));
+        let format = match try!(frame.take(1))[0] {
+            0 => MessageFormat::Plain,
+            1 => MessageFormat::Encrypted,
+            f => return Err(Error::WireDecode(format!("Unknown message format {}", f))),
+        };
+        let key_len = try!(frame.u16()) as usize;
+        let key = try!(frame.take(key_len));
+        let nonce_len = try!(frame.u16()) as usize;
+        let nonce = try!(frame.take(nonce_len));
+        let msg_len = try!(frame.u32()) as usize;
+        let msg_bytes = try!(frame.take(msg_len));
+        if frame.pos != bytes.len() {
+            return Err(Error::WireDecode("Trailing bytes after the message".to_string()));
+        }
         Ok(WireMessage {
-            format: MessageFormat::Encrypted,
-            version: WIRE_VERSION.to_string(),
-            key: Some(sym_key.name_with_rev()),
-            nonce: Some(nonce),
-            msg_bytes: ciphertext,
+            format: format,
+            version: name.to_string(),
+            key: if key.is_empty() {
+                None
+            } else {
+                Some(try!(str::from_utf8(key)).to_string())
+            },
+            nonce: if nonce.is_empty() {
+                None
+            } else {
+                Some(nonce.to_vec())
+            },
+            msg_bytes: msg_bytes.to_vec(),
         })
     }
 
@@ -187,16 +404,47 @@ impl WireMessage {
     ///
     /// # Errors
     ///
+    /// * If the message is in a wire version after 1; read it with `versioned_msg`
     /// * If the message can't be decoded from bytes
     /// * If the wire message is malformed, that is, missing fields required for encrypted messages
     /// * If a required key with revision is not present for decrypting
     pub fn msg<T: Decodable>(&self, sym_key: Option<&SymKey>) -> Result<T> {
+        if self.wire_version() != WIRE_VERSION_JSON {
+            let msg = format!("Message is in wire version {}, which only a protobuf type can \
+                               be read from",
+                              self.wire_version());
+            return Err(Error::WireDecode(msg));
+        }
+        self.open(sym_key, |bytes| {
+            let msg_str = try!(str::from_utf8(bytes));
+            let decoded: T = try!(json::decode(msg_str));
+            Ok(decoded)
+        })
+    }
+
+    /// Reads the message in whichever wire version it is in.
+    ///
+    /// # Errors
+    ///
+    /// * If the message can't be decoded from bytes
+    /// * If the wire message is malformed, that is, missing fields required for encrypted messages
+    /// * If a required key with revision is not present for decrypting
+    pub fn versioned_msg<T: Decodable + Protobuf>(&self, sym_key: Option<&SymKey>) -> Result<T> {
+        if self.wire_version() == WIRE_VERSION_JSON {
+            return self.msg(sym_key);
+        }
+        self.open(sym_key, |bytes| {
+            let proto: T::Message = try!(protobuf::parse_from_bytes(bytes));
+            T::from_proto(proto)
+        })
+    }
+
+    /// Decrypts the message if it is encrypted, and decodes it with `decode`.
+    fn open<T, F>(&self, sym_key: Option<&SymKey>, decode: F) -> Result<T>
+        where F: Fn(&[u8]) -> Result<T>
+    {
         match self.format {
-            MessageFormat::Plain => {
-                let msg_str = try!(str::from_utf8(&self.msg_bytes));
-                let decoded: T = try!(json::decode(msg_str));
-                Ok(decoded)
-            }
+            MessageFormat::Plain => decode(&self.msg_bytes),
             MessageFormat::Encrypted => {
                 let key_name_with_rev = match self.key.as_ref() {
                     Some(k) => k,
@@ -228,10 +476,150 @@ impl WireMessage {
                     return Err(Error::WireDecode(msg));
                 }
                 let msg = try!(sym_key.decrypt(&nonce[..], &self.msg_bytes[..]));
-                let msg_str = try!(str::from_utf8(&msg));
-                let decoded: T = try!(json::decode(msg_str));
-                Ok(decoded)
+                decode(&msg)
             }
         }
     }
 }
+
+fn put_u16(out: &mut Vec<u8>, n: u16) {
+    out.push((n >> 8) as u8);
+    out.push(n as u8);
+}
+
+fn put_u32(out: &mut Vec<u8>, n: u32) {
+    for i in (0..4).rev() {
+        out.push((n >> (i * 8)) as u8);
+    }
+}
+
+fn put_short_bytes(out: &mut Vec<u8>, bytes: &[u8]) -> Result<()> {
+    if bytes.len() > u16::max_value() as usize {
+        return Err(Error::WireDecode("key or nonce is too long".to_string()));
+    }
+    put_u16(out, bytes.len() as u16);
+    out.extend_from_slice(bytes);
+    Ok(())
+}
+
+/// Reads the fields of a framed message.
+struct Frame<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Frame<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() - self.pos < len {
+            return Err(Error::WireDecode("Message ends too soon".to_string()));
+        }
+        let taken = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(taken)
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        let b = try!(self.take(2));
+        Ok(((b[0] as u16) << 8) | b[1] as u16)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let b = try!(self.take(4));
+        Ok(b.iter().fold(0, |n, &byte| (n << 8) | byte as u32))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use hcore::crypto::SymKey;
+    use hcore::service::ServiceGroup;
+    use tempdir::TempDir;
+
+    use error::Error;
+    use super::{negotiate, MessageFormat, WireMessage, CURRENT_WIRE_VERSION, WIRE_VERSION_BINARY,
+                WIRE_VERSION_JSON};
+
+    fn kayla() -> ServiceGroup {
+        ServiceGroup::new("kayla", "album", Some("flying_colors".to_string()))
+    }
+
+    #[test]
+    fn round_trips_every_version() {
+        for version in WIRE_VERSION_JSON..CURRENT_WIRE_VERSION + 1 {
+            let wire = WireMessage::with_version(&kayla(), None, version).unwrap();
+            let bytes = wire.to_bytes().unwrap();
+            let read = WireMessage::from_bytes(&bytes).unwrap();
+            assert_eq!(read.wire_version(), version);
+            assert_eq!(read.format, MessageFormat::Plain);
+            assert_eq!(read.versioned_msg::<ServiceGroup>(None).unwrap(), kayla());
+        }
+    }
+
+    #[test]
+    fn round_trips_encrypted_every_version() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let sym_key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        for version in WIRE_VERSION_JSON..CURRENT_WIRE_VERSION + 1 {
+            let wire = WireMessage::with_version(&kayla(), Some(&sym_key), version).unwrap();
+            let bytes = wire.to_bytes().unwrap();
+            let read = WireMessage::from_bytes(&bytes).unwrap();
+            assert_eq!(read.format, MessageFormat::Encrypted);
+            assert_eq!(read.key_name_with_rev(), Some(&sym_key.name_with_rev()[..]));
+            assert_eq!(read.versioned_msg::<ServiceGroup>(Some(&sym_key)).unwrap(),
+                       kayla());
+        }
+    }
+
+    #[test]
+    fn version_one_is_json() {
+        let bytes = WireMessage::plain(&kayla()).unwrap().to_bytes().unwrap();
+        assert_eq!(bytes[0], b'{');
+    }
+
+    #[test]
+    fn msg_refuses_later_versions() {
+        let wire = WireMessage::with_version(&kayla(), None, WIRE_VERSION_BINARY).unwrap();
+        assert!(wire.msg::<ServiceGroup>(None).is_err());
+    }
+
+    #[test]
+    fn refuses_newer_versions() {
+        let mut bytes = WireMessage::with_version(&kayla(), None, WIRE_VERSION_BINARY)
+            .unwrap()
+            .to_bytes()
+            .unwrap();
+        bytes[5] = 9;
+        match WireMessage::from_bytes(&bytes) {
+            Err(Error::UnsupportedWireVersion(v)) => assert_eq!(v, 9),
+            _ => panic!("decoded a wire version we don't speak"),
+        }
+    }
+
+    #[test]
+    fn frames_carry_their_version() {
+        for version in WIRE_VERSION_BINARY..CURRENT_WIRE_VERSION + 1 {
+            let bytes = WireMessage::with_version(&kayla(), None, version)
+                .unwrap()
+                .to_bytes()
+                .unwrap();
+            assert_eq!(((bytes[4] as u16) << 8) | bytes[5] as u16, version);
+        }
+    }
+
+    #[test]
+    fn refuses_short_messages() {
+        let bytes = WireMessage::with_version(&kayla(), None, WIRE_VERSION_BINARY)
+            .unwrap()
+            .to_bytes()
+            .unwrap();
+        assert!(WireMessage::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn negotiates_the_lower_version() {
+        assert_eq!(negotiate(None), WIRE_VERSION_JSON);
+        assert_eq!(negotiate(Some(WIRE_VERSION_JSON)), WIRE_VERSION_JSON);
+        assert_eq!(negotiate(Some(WIRE_VERSION_BINARY)), WIRE_VERSION_BINARY);
+        assert_eq!(negotiate(Some(CURRENT_WIRE_VERSION + 1)), CURRENT_WIRE_VERSION);
+    }
+}
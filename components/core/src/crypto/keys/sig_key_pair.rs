@@ -38,6 +38,19 @@ impl SigKeyPair {
                      Some(secret_key)))
     }
 
+    /// Generates a signing key for a user, named with `user_key_name` so that it doesn't share
+    /// its name, or its public key file, with the user's box key.
+    pub fn generate_pair_for_user<P: AsRef<Path> + ?Sized>(user: &str,
+                                                           cache_key_path: &P)
+                                                           -> Result<Self> {
+        Self::generate_pair_for_origin(&Self::user_key_name(user), cache_key_path)
+    }
+
+    /// The name of a user's signing key.
+    pub fn user_key_name(user: &str) -> String {
+        format!("{}@user", user)
+    }
+
     fn mk_key_name(name: &str, revision: &str) -> String {
         format!("{}-{}", name, revision)
     }
@@ -145,6 +158,28 @@ impl SigKeyPair {
         Ok(path)
     }
 
+    /// Signs a message with the secret key, returning the signed message.
+    ///
+    /// # Errors
+    ///
+    /// * If the pair has no secret key
+    pub fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        Ok(sign::sign(data, try!(self.secret())))
+    }
+
+    /// Verifies a signed message with the public key, returning the message that was signed.
+    ///
+    /// # Errors
+    ///
+    /// * If the pair has no public key
+    /// * If the signature is not valid for this key
+    pub fn verify(&self, signed: &[u8]) -> Result<Vec<u8>> {
+        match sign::verify(signed, try!(self.public())) {
+            Ok(data) => Ok(data),
+            Err(_) => Err(Error::CryptoError("Verification failed".to_string())),
+        }
+    }
+
     /// Writes a sig key (public or secret) to the key cache from the contents of a string slice.
     ///
     /// The return is a `Result` of a `String` containing the key's name with revision.
@@ -460,6 +495,28 @@ mod test {
         assert!(cache.path().join(format!("{}.sig.key", pair.name_with_rev())).exists());
     }
 
+    #[test]
+    fn generated_user_pair() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_user("wesker", cache.path()).unwrap();
+
+        assert_eq!(pair.name, "wesker@user");
+        assert_eq!(pair.name, SigKeyPair::user_key_name("wesker"));
+        assert!(cache.path().join(format!("{}.pub", pair.name_with_rev())).exists());
+        assert!(cache.path().join(format!("{}.sig.key", pair.name_with_rev())).exists());
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let signed = pair.sign(b"tangerine").unwrap();
+        assert_eq!(pair.verify(&signed).unwrap(), b"tangerine".to_vec());
+
+        let other = SigKeyPair::generate_pair_for_origin("dragon", cache.path()).unwrap();
+        assert!(other.verify(&signed).is_err());
+    }
+
     #[test]
     fn get_pairs_for() {
         let cache = TempDir::new("key_cache").unwrap();
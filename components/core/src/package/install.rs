@@ -121,6 +121,49 @@ impl PackageInstall {
         }
     }
 
+    /// The name of the signal to send the service when we want it to stop, if the package
+    /// declares one
+    pub fn shutdown_signal(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::ShutdownSignal) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::ShutdownSignal)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// How many seconds to wait for the service to stop before killing it, if the package
+    /// declares it
+    pub fn shutdown_timeout(&self) -> Result<Option<u64>> {
+        match self.read_metafile(MetaFile::ShutdownTimeout) {
+            Ok(body) => {
+                match body.parse::<u64>() {
+                    Ok(secs) => Ok(Some(secs)),
+                    Err(_) => Err(Error::MetaFileMalformed(MetaFile::ShutdownTimeout)),
+                }
+            }
+            Err(Error::MetaFileNotFound(MetaFile::ShutdownTimeout)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The user the service should run as, if the package declares one
+    pub fn svc_user(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::SvcUser) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::SvcUser)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The group the service should run as, if the package declares one
+    pub fn svc_group(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::SvcGroup) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::SvcGroup)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn ident(&self) -> &PackageIdent {
         &self.ident
     }
@@ -27,6 +27,10 @@ pub enum MetaFile {
     LdFlags,
     Manifest,
     Path,
+    ShutdownSignal,
+    ShutdownTimeout,
+    SvcGroup,
+    SvcUser,
 }
 
 impl fmt::Display for MetaFile {
@@ -42,6 +46,10 @@ impl fmt::Display for MetaFile {
             MetaFile::LdFlags => "LDFLAGS",
             MetaFile::Manifest => "MANIFEST",
             MetaFile::Path => "PATH",
+            MetaFile::ShutdownSignal => "SHUTDOWN_SIGNAL",
+            MetaFile::ShutdownTimeout => "SHUTDOWN_TIMEOUT",
+            MetaFile::SvcGroup => "SVC_GROUP",
+            MetaFile::SvcUser => "SVC_USER",
         };
         write!(f, "{}", id)
     }
@@ -13,6 +13,7 @@ use clap::{App, AppSettings, Arg};
 use hcore::crypto::keys::PairType;
 use regex::Regex;
 use url::Url;
+use uuid::Uuid;
 
 pub fn get() -> App<'static, 'static> {
     let alias_apply = sub_config_apply()
@@ -46,6 +47,7 @@ pub fn get() -> App<'static, 'static> {
             (aliases: &["co", "con", "conf", "confi"])
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_config_apply().aliases(&["a", "ap", "app", "appl"]))
+            (subcommand: sub_config_rollback().aliases(&["r", "ro", "rol", "roll"]))
         )
         (@subcommand file =>
             (about: "Commands relating to Habitat files")
@@ -195,6 +197,19 @@ pub fn get() -> App<'static, 'static> {
             (about: "Commands relating to Habitat rings")
             (aliases: &["r", "ri", "rin"])
             (@setting ArgRequiredElseHelp)
+            (@subcommand depart =>
+                (about: "Permanently removes a member from the supervisor ring")
+                (aliases: &["d", "de", "dep", "depa", "depar"])
+                (@arg MEMBER_ID: +required +takes_value {valid_member_id}
+                    "The member id of the departing supervisor")
+                (@arg ORIGIN: --origin +takes_value
+                    "Origin key used to sign the departure")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+                    (default: 127.0.0.1:9634)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+            )
             (@subcommand key =>
                 (about: "Commands relating to Habitat ring keys")
                 (aliases: &["k", "ke"])
@@ -214,6 +229,19 @@ pub fn get() -> App<'static, 'static> {
                     (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
                     (@arg RING: +required +takes_value "Ring key name")
                 )
+                (@subcommand rotate =>
+                    (about: "Generates a new revision of an existing Habitat ring key")
+                    (aliases: &["ro", "rot", "rota", "rotat"])
+                    (@arg RING: +required +takes_value "Ring key name")
+                )
+                (@subcommand retire =>
+                    (about: "Removes older revisions of a ring key once no member uses them")
+                    (aliases: &["re", "ret", "reti", "retir"])
+                    (@arg RING: +required +takes_value "Ring key name")
+                    (@arg SIDECAR: -s --sidecar +takes_value {valid_url}
+                        "The HTTP API of a Supervisor in the ring, to read the members' ring \
+                        key revisions from (default: http://127.0.0.1:9631)")
+                )
             )
         )
         (@subcommand service =>
@@ -297,6 +325,39 @@ fn sub_config_apply() -> App<'static, 'static> {
         (@arg FILE: {file_exists_or_stdin}
             "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>)")
         (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg USER: --user +takes_value
+            "Name of the user key that signs the configuration (default: $HAB_USER, if set \
+            and the service group has an organization)")
+        (@arg ENCRYPT: --encrypt
+            "Encrypt the configuration to the service group's service key")
+    )
+    .arg(Arg::with_name("DRY_RUN")
+        .help("Show how the configuration would change this machine's configuration files, \
+               without applying it")
+        .long("dry-run"))
+}
+
+fn sub_config_rollback() -> App<'static, 'static> {
+    clap_app!(@subcommand rollback =>
+        (about: "Rolls a group of Habitat Supervisors back to an earlier configuration")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+            (default: 127.0.0.1:9634)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg SIDECAR: -s --sidecar +takes_value {valid_url}
+            "The HTTP API of a Supervisor in the service group, to read the configuration \
+            history from (default: http://127.0.0.1:9631)")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg VERSION_NUMBER: +required
+            "The version number of the configuration to roll back to (ex: 41)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg USER: --user +takes_value
+            "Name of the user key that signs the configuration (default: $HAB_USER, if set \
+            and the service group has an organization)")
+        (@arg ENCRYPT: --encrypt
+            "Encrypt the configuration to the service group's service key")
     )
 }
 
@@ -371,6 +432,13 @@ fn valid_service_group(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_member_id(val: String) -> result::Result<(), String> {
+    match Uuid::parse_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("MEMBER_ID: '{}' is not valid", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
@@ -6,32 +6,72 @@
 // open source license such as the Apache 2.0 License.
 
 pub mod apply {
+    use std::ffi::OsString;
+    use std::fs::File;
     use std::path::Path;
     use std::io::{self, Read};
 
     use ansi_term::Colour::{Blue, Green, Yellow};
-    use hcore::crypto::SymKey;
+    use hcore::crypto::{BoxKeyPair, SigKeyPair, SymKey};
+    use hcore::fs;
     use hcore::service::ServiceGroup;
     use common::gossip_file::GossipFile;
+    use toml;
 
-    use error::Result;
+    use command;
+    use error::{Error, Result};
     use gossip::{self, hab_gossip};
 
+    /// Applies a configuration to a service group. If we have a user's `signing_pair`, the
+    /// configuration is signed with it, and if `encrypt_with` has the user's and the service
+    /// group's box keys, encrypted to the service key as well.
     pub fn start(peers: &Vec<String>,
                  ring_key: Option<&SymKey>,
                  sg: &ServiceGroup,
                  number: u64,
-                 file_path: Option<&Path>)
+                 file_path: Option<&Path>,
+                 signing_pair: Option<&SigKeyPair>,
+                 encrypt_with: Option<(&BoxKeyPair, &BoxKeyPair)>)
                  -> Result<()> {
         println!("{}",
                  Yellow.bold().paint(format!("» Applying configuration")));
-        let file = match file_path {
-            Some(p) => try!(GossipFile::from_file(sg.clone(), p, number)),
-            None => {
-                let mut body = String::new();
-                try!(io::stdin().read_to_string(&mut body));
-                try!(GossipFile::from_body(sg.clone(), body.into(), number))
+        let mut body = Vec::new();
+        match file_path {
+            Some(p) => try!(try!(File::open(p)).read_to_end(&mut body)),
+            None => try!(io::stdin().read_to_end(&mut body)),
+        };
+        try!(send(peers, ring_key, sg, number, body, signing_pair, encrypt_with));
+        println!("{}", Blue.paint(format!("★ Applied configuration.")));
+        Ok(())
+    }
+
+    /// Gossips `body` as version `number` of the service group's configuration, signed and
+    /// encrypted as `start` describes.
+    pub fn send(peers: &Vec<String>,
+                ring_key: Option<&SymKey>,
+                sg: &ServiceGroup,
+                number: u64,
+                body: Vec<u8>,
+                signing_pair: Option<&SigKeyPair>,
+                encrypt_with: Option<(&BoxKeyPair, &BoxKeyPair)>)
+                -> Result<()> {
+        let file = match signing_pair {
+            Some(signing_pair) => {
+                println!("{} configuration with {}",
+                         Green.bold().paint("☛ Signing"),
+                         &signing_pair.name_with_rev());
+                if let Some((_, service_pair)) = encrypt_with {
+                    println!("{} configuration for {}",
+                             Green.bold().paint("☛ Encrypting"),
+                             &service_pair.name_with_rev());
+                }
+                try!(GossipFile::from_body_signed(signing_pair,
+                                                  sg.clone(),
+                                                  body,
+                                                  number,
+                                                  encrypt_with))
             }
+            None => try!(GossipFile::from_body(sg.clone(), body, number)),
         };
         let rumor = hab_gossip::Rumor::gossip_file(file);
 
@@ -50,7 +90,159 @@ pub mod apply {
                  &sg,
                  &peers);
         try!(gossip::send_rumors_to_peers(&peers, ring_key, &list));
-        println!("{}", Blue.paint(format!("★ Applied configuration.")));
         Ok(())
     }
+
+    /// Shows how a configuration would change the service group's configuration files on this
+    /// machine, without applying it. The Supervisor renders the files, so the service has to
+    /// have run here; its last `config.toml` tells us which package it runs.
+    pub fn dry_run(sg: &ServiceGroup, file_path: Option<&Path>) -> Result<()> {
+        let config_toml = fs::svc_path(&sg.service).join("config.toml");
+        let mut config = String::new();
+        match File::open(&config_toml) {
+            Ok(mut file) => try!(file.read_to_string(&mut config)),
+            Err(_) => return Err(Error::FileNotFound(config_toml.to_string_lossy().into_owned())),
+        };
+        let ident = match toml::Parser::new(&config).parse() {
+            Some(table) => {
+                match toml::Value::Table(table).lookup("pkg.ident").and_then(|i| i.as_str()) {
+                    Some(ident) => ident.to_string(),
+                    None => return Err(Error::ArgumentError("No package ident in config.toml")),
+                }
+            }
+            None => return Err(Error::ArgumentError("Failed to parse config.toml")),
+        };
+        println!("{}",
+                 Yellow.bold().paint(format!("» Rendering configuration for {} with {}",
+                                             &sg,
+                                             &ident)));
+        let file = match file_path {
+            Some(p) => p.as_os_str().to_os_string(),
+            None => OsString::from("-"),
+        };
+        command::sup::start(vec![OsString::from("config"),
+                                 OsString::from(ident),
+                                 OsString::from("--dry-run"),
+                                 file])
+    }
+}
+
+pub mod rollback {
+    use std::cmp;
+    use std::io::Read;
+    use std::thread;
+    use std::time::Duration;
+
+    use ansi_term::Colour::{Blue, Green, Yellow};
+    use hcore::crypto::{BoxKeyPair, SigKeyPair, SymKey};
+    use hcore::service::ServiceGroup;
+    use hyper;
+    use hyper::status::StatusCode;
+    use rustc_serialize::Decodable;
+    use rustc_serialize::json;
+
+    use error::{Error, Result};
+    use super::apply;
+
+    /// How long to wait for the Supervisor to take up the rolled back configuration
+    const ROLLBACK_WAIT_SECS: u64 = 30;
+
+    /// A configuration in a Supervisor's history, as /config/history lists it
+    #[derive(Debug, RustcDecodable)]
+    struct ConfigSetSummary {
+        id: u64,
+        gossip_version: Option<u64>,
+    }
+
+    /// The part of a configuration in a Supervisor's history we roll back to
+    #[derive(Debug, RustcDecodable)]
+    struct ConfigSet {
+        gossip_toml: Option<String>,
+    }
+
+    /// The version of the configuration a Supervisor has, as /config/version reports it
+    #[derive(Debug, RustcDecodable)]
+    struct ConfigVersion {
+        version: Option<u64>,
+    }
+
+    /// Rolls a service group back to an earlier version of its configuration. The Supervisor at
+    /// `sidecar` keeps the configurations it has run with; the one rendered from `version` is
+    /// gossiped again, with the version number after the one the Supervisor has now, so every
+    /// member of the service group takes it up. It is signed and encrypted the same way
+    /// `apply::start` does.
+    ///
+    /// # Errors
+    ///
+    /// * If the Supervisor doesn't have `version` in its history
+    /// * If the Supervisor hasn't taken up the rolled back configuration after
+    ///   `ROLLBACK_WAIT_SECS`
+    pub fn start(sidecar: &str,
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>,
+                 sg: &ServiceGroup,
+                 version: u64,
+                 signing_pair: Option<&SigKeyPair>,
+                 encrypt_with: Option<(&BoxKeyPair, &BoxKeyPair)>)
+                 -> Result<()> {
+        println!("{}",
+                 Yellow.bold().paint(format!("» Rolling back {} to configuration {}",
+                                             &sg,
+                                             version)));
+        let sidecar = sidecar.trim_right_matches('/');
+        let client = hyper::Client::new();
+        let summaries: Vec<ConfigSetSummary> =
+            try!(get(&client, &format!("{}/config/history", sidecar)));
+        let id = match summaries.iter().find(|s| s.gossip_version == Some(version)) {
+            Some(summary) => summary.id,
+            None => return Err(Error::ConfigVersionNotFound(version)),
+        };
+        let set: ConfigSet = try!(get(&client, &format!("{}/config/history/{}", sidecar, id)));
+        let body = match set.gossip_toml {
+            Some(body) => body,
+            None => return Err(Error::ConfigVersionNotFound(version)),
+        };
+        let current: ConfigVersion = try!(get(&client, &format!("{}/config/version", sidecar)));
+        let number = cmp::max(current.version.unwrap_or(version), version) + 1;
+        println!("{} configuration {} from {}, as version {}",
+                 Green.bold().paint("☛ Found"),
+                 version,
+                 &sidecar,
+                 number);
+        try!(apply::send(peers,
+                         ring_key,
+                         sg,
+                         number,
+                         body.into_bytes(),
+                         signing_pair,
+                         encrypt_with));
+        try!(wait_for(&client, sidecar, number));
+        println!("{}",
+                 Blue.paint(format!("★ Rolled back to configuration {}.", version)));
+        Ok(())
+    }
+
+    /// Waits for the Supervisor at `sidecar` to report configuration `number`, or a later one.
+    fn wait_for(client: &hyper::Client, sidecar: &str, number: u64) -> Result<()> {
+        let mut current = None;
+        for _ in 0..ROLLBACK_WAIT_SECS {
+            let version: ConfigVersion = try!(get(client, &format!("{}/config/version", sidecar)));
+            current = version.version;
+            if current.map_or(false, |current| current >= number) {
+                return Ok(());
+            }
+            thread::sleep(Duration::from_secs(1));
+        }
+        Err(Error::ConfigRollbackNotApplied(number, current))
+    }
+
+    fn get<T: Decodable>(client: &hyper::Client, url: &str) -> Result<T> {
+        let mut res = try!(client.get(url).send());
+        if res.status != StatusCode::Ok {
+            return Err(Error::HTTP(res.status));
+        }
+        let mut body = String::new();
+        try!(res.read_to_string(&mut body));
+        Ok(try!(json::decode(&body)))
+    }
 }
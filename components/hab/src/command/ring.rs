@@ -5,6 +5,50 @@
 // the Software until such time that the Software is made available under an
 // open source license such as the Apache 2.0 License.
 
+pub mod depart {
+    use ansi_term::Colour::{Blue, Green, Yellow};
+    use hcore::crypto::{SigKeyPair, SymKey};
+    use uuid::Uuid;
+
+    use error::Result;
+    use gossip::{self, hab_gossip};
+
+    pub fn start(peers: &Vec<String>,
+                 ring_key: Option<&SymKey>,
+                 member_id: &Uuid,
+                 pair: &SigKeyPair)
+                 -> Result<()> {
+        println!("{}",
+                 Yellow.bold().paint(format!("» Departing member {} from the ring", member_id)));
+        let signature = try!(pair.sign(member_id.simple().to_string().as_bytes()));
+        let departure = hab_gossip::Departure {
+            member_id: member_id.clone(),
+            signer: pair.name_with_rev(),
+            signature: signature,
+        };
+        println!("{} departure with {}",
+                 Green.bold().paint("☛ Signing"),
+                 &pair.name_with_rev());
+
+        let mut list = hab_gossip::RumorList::new();
+        list.add_rumor(hab_gossip::Rumor::departure(departure));
+
+        if let Some(ring_key) = ring_key {
+            println!("{} communication to \"{}\" ring with {}",
+                     Green.bold().paint("☛ Encrypting"),
+                     &ring_key.name,
+                     &ring_key.name_with_rev());
+        }
+        println!("{} departure for {} into ring via {:?}",
+                 Green.bold().paint("↑ Applying"),
+                 member_id,
+                 &peers);
+        try!(gossip::send_rumors_to_peers(&peers, ring_key, &list));
+        println!("{}", Blue.paint(format!("★ Departed member {}.", member_id)));
+        Ok(())
+    }
+}
+
 pub mod key {
     pub mod export {
         use std::io;
@@ -63,4 +107,129 @@ pub mod key {
             Ok(())
         }
     }
+
+    pub mod retire {
+        use std::collections::HashMap;
+        use std::fs;
+        use std::io::Read;
+        use std::path::Path;
+
+        use ansi_term::Colour::{Blue, Green, Yellow};
+        use hcore::crypto::SymKey;
+        use hyper;
+        use hyper::status::StatusCode;
+        use rustc_serialize::json;
+
+        use error::{Error, Result};
+
+        /// The part of a Supervisor's /gossip response we check
+        #[derive(Debug, RustcDecodable)]
+        struct GossipResponse {
+            member_list: MemberListSummary,
+        }
+
+        #[derive(Debug, RustcDecodable)]
+        struct MemberListSummary {
+            members: HashMap<String, MemberSummary>,
+        }
+
+        #[derive(Debug, RustcDecodable)]
+        struct MemberSummary {
+            id: String,
+            health: String,
+            ring_key: Option<String>,
+        }
+
+        /// Removes every revision of a ring key but the newest from the key cache. The Supervisor
+        /// at `sidecar` is asked which revision every live member of the ring encrypts with; if any
+        /// of them still uses an older one, nothing is removed.
+        pub fn start(ring: &str, sidecar: &str, cache: &Path) -> Result<()> {
+            println!("{}",
+                     Yellow.bold().paint(format!("» Retiring old revisions of ring key {}",
+                                                 &ring)));
+            let mut pairs = try!(SymKey::get_pairs_for(ring, cache));
+            if pairs.is_empty() {
+                return Err(Error::CryptoCLI(format!("No revisions found for ring key {}", ring)));
+            }
+            let latest = pairs.remove(0);
+            if pairs.is_empty() {
+                println!("{}",
+                         Blue.paint(format!("★ {} is the only revision; nothing to retire.",
+                                            &latest.name_with_rev())));
+                return Ok(());
+            }
+
+            let sidecar = sidecar.trim_right_matches('/');
+            let gossip = try!(get_gossip(&format!("{}/gossip", sidecar)));
+            for member in gossip.member_list.members.values() {
+                if member.health == "Departed" || member.health == "Confirmed" {
+                    continue;
+                }
+                if member.ring_key.as_ref() != Some(&latest.name_with_rev()) {
+                    let in_use = member.ring_key.clone().unwrap_or(String::from("no revision"));
+                    return Err(Error::RingKeyInUse(member.id.clone(), in_use));
+                }
+            }
+            println!("{} every live member encrypts with {}",
+                     Green.bold().paint("☛ Found"),
+                     &latest.name_with_rev());
+
+            for pair in pairs.iter() {
+                let path = try!(SymKey::get_secret_key_path(&pair.name_with_rev(), cache));
+                try!(fs::remove_file(&path));
+                println!("{} ring key {}",
+                         Green.bold().paint("✓ Removed"),
+                         &pair.name_with_rev());
+            }
+            println!("{}",
+                     Blue.paint(format!("★ Retired {} revision(s) of {}.", pairs.len(), &ring)));
+            Ok(())
+        }
+
+        fn get_gossip(url: &str) -> Result<GossipResponse> {
+            let client = hyper::Client::new();
+            let mut res = try!(client.get(url).send());
+            if res.status != StatusCode::Ok {
+                return Err(Error::HTTP(res.status));
+            }
+            let mut body = String::new();
+            try!(res.read_to_string(&mut body));
+            Ok(try!(json::decode(&body)))
+        }
+    }
+
+    pub mod rotate {
+        use std::path::Path;
+
+        use ansi_term::Colour::{Blue, Green, Yellow};
+        use hcore::crypto::SymKey;
+
+        use error::Result;
+
+        /// Generates a new revision of an existing ring key. Supervisors decrypt with any revision
+        /// of their ring key in the key cache, so the old and new revisions can be used side by
+        /// side until every Supervisor has been restarted with the new one.
+        pub fn start(ring: &str, cache: &Path) -> Result<()> {
+            println!("{}",
+                     Yellow.bold().paint(format!("» Rotating ring key {}", &ring)));
+            let current = try!(SymKey::get_latest_pair_for(ring, cache));
+            println!("{} current revision {}",
+                     Green.bold().paint("☛ Found"),
+                     &current.name_with_rev());
+            let pair = try!(SymKey::generate_pair_for_ring(ring, cache));
+            println!("{}",
+                     Blue.paint(format!("★ Generated ring key pair {}.", &pair.name_with_rev())));
+            println!("");
+            println!("To finish the rotation:");
+            println!("  1. Run `hab ring key export {}` and import it with `hab ring key import` \
+                      on every host in the ring",
+                     &ring);
+            println!("  2. Restart the Supervisors one at a time, so they encrypt with {}",
+                     &pair.name_with_rev());
+            println!("  3. Run `hab ring key retire {}` to remove {} and any older revisions",
+                     &ring,
+                     &current.name_with_rev());
+            Ok(())
+        }
+    }
 }
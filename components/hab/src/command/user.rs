@@ -10,7 +10,7 @@ pub mod key {
         use std::path::Path;
 
         use ansi_term::Colour::{Blue, Yellow};
-        use hcore::crypto::BoxKeyPair;
+        use hcore::crypto::{BoxKeyPair, SigKeyPair};
 
         use error::Result;
 
@@ -20,6 +20,10 @@ pub mod key {
             let pair = try!(BoxKeyPair::generate_pair_for_user(user, cache));
             println!("{}",
                      Blue.paint(format!("★ Generated user key pair {}.", &pair.name_with_rev())));
+            let pair = try!(SigKeyPair::generate_pair_for_user(user, cache));
+            println!("{}",
+                     Blue.paint(format!("★ Generated user signing key pair {}.",
+                                        &pair.name_with_rev())));
             Ok(())
         }
     }
@@ -15,6 +15,8 @@ use std::result;
 use depot_client;
 use common;
 use hcore;
+use hyper;
+use rustc_serialize::json;
 
 pub type Result<T> = result::Result<T, Error>;
 
@@ -23,6 +25,8 @@ pub type Result<T> = result::Result<T, Error>;
 pub enum Error {
     ArgumentError(&'static str),
     CommandNotFoundInPkg((String, String)),
+    ConfigRollbackNotApplied(u64, Option<u64>),
+    ConfigVersionNotFound(u64),
     CryptoCLI(String),
     DepotClient(depot_client::Error),
     ExecCommandNotFound(String),
@@ -30,9 +34,13 @@ pub enum Error {
     FileNotFound(String),
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
+    HTTP(hyper::status::StatusCode),
+    HyperError(hyper::error::Error),
     IO(io::Error),
+    JsonDecode(json::DecoderError),
     PackageArchiveMalformed(String),
     PathPrefixError(path::StripPrefixError),
+    RingKeyInUse(String, String),
     SubcommandNotSupported(String),
     UnsupportedExportFormat(String),
 }
@@ -46,6 +54,24 @@ impl fmt::Display for Error {
                         c,
                         p)
             }
+            Error::ConfigRollbackNotApplied(ref v, ref current) => {
+                match *current {
+                    Some(ref c) => {
+                        format!("The Supervisor did not take up the rolled back configuration \
+                                 as version {}; it reports version {}",
+                                v,
+                                c)
+                    }
+                    None => {
+                        format!("The Supervisor did not take up the rolled back configuration \
+                                 as version {}; it reports no version",
+                                v)
+                    }
+                }
+            }
+            Error::ConfigVersionNotFound(ref v) => {
+                format!("Configuration version {} is not in the Supervisor's history", v)
+            }
             Error::CryptoCLI(ref e) => format!("{}", e),
             Error::DepotClient(ref err) => format!("{}", err),
             Error::ExecCommandNotFound(ref c) => {
@@ -55,12 +81,21 @@ impl fmt::Display for Error {
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
             Error::HabitatCommon(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::HTTP(ref e) => format!("Unexpected HTTP response: {}", e),
+            Error::HyperError(ref err) => format!("{}", err),
             Error::IO(ref err) => format!("{}", err),
+            Error::JsonDecode(ref e) => format!("JSON decoding error: {}", e),
             Error::PackageArchiveMalformed(ref e) => {
                 format!("Package archive was unreadable or contained unexpected contents: {:?}",
                         e)
             }
             Error::PathPrefixError(ref err) => format!("{}", err),
+            Error::RingKeyInUse(ref m, ref k) => {
+                format!("Member {} still encrypts with {}; restart it with the newest ring key \
+                         before retiring older revisions",
+                        m,
+                        k)
+            }
             Error::SubcommandNotSupported(ref e) => {
                 format!("Subcommand `{}' not supported on this operating system", e)
             }
@@ -77,6 +112,12 @@ impl error::Error for Error {
             Error::CommandNotFoundInPkg(_) => {
                 "Command was not found under any 'PATH' directories in the package"
             }
+            Error::ConfigRollbackNotApplied(_, _) => {
+                "The Supervisor did not take up the rolled back configuration"
+            }
+            Error::ConfigVersionNotFound(_) => {
+                "Configuration version was not found in the Supervisor's history"
+            }
             Error::CryptoCLI(_) => "A cryptographic error has occurred",
             Error::DepotClient(ref err) => err.description(),
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
@@ -84,11 +125,15 @@ impl error::Error for Error {
             Error::FileNotFound(_) => "File not found",
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::HTTP(_) => "Received an HTTP error",
+            Error::HyperError(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::JsonDecode(ref err) => err.description(),
             Error::PackageArchiveMalformed(_) => {
                 "Package archive was unreadable or had unexpected contents"
             }
             Error::PathPrefixError(ref err) => err.description(),
+            Error::RingKeyInUse(_, _) => "An older ring key revision is still in use",
             Error::SubcommandNotSupported(_) => "Subcommand not supported on this operating system",
             Error::UnsupportedExportFormat(_) => "Unsupported export format",
         }
@@ -119,12 +164,24 @@ impl From<hcore::Error> for Error {
     }
 }
 
+impl From<hyper::error::Error> for Error {
+    fn from(err: hyper::error::Error) -> Error {
+        Error::HyperError(err)
+    }
+}
+
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
         Error::IO(err)
     }
 }
 
+impl From<json::DecoderError> for Error {
+    fn from(err: json::DecoderError) -> Error {
+        Error::JsonDecode(err)
+    }
+}
+
 impl From<path::StripPrefixError> for Error {
     fn from(err: path::StripPrefixError) -> Error {
         Error::PathPrefixError(err)
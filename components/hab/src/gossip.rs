@@ -202,6 +202,17 @@ pub mod hab_gossip {
     #[derive(Debug, RustcEncodable, Clone, PartialEq, Eq)]
     pub enum Message {
         GossipFile(GossipFile),
+        Departure(Departure),
+    }
+
+    /// A request for a member to leave the ring for good, signed with an origin key.
+    #[derive(Debug, RustcEncodable, Clone, PartialEq, Eq)]
+    pub struct Departure {
+        pub member_id: MemberId,
+        /// The name with revision of the signing key
+        pub signer: String,
+        /// The member id, signed
+        pub signature: Vec<u8>,
     }
 
     /// A UUID for Rumors. In practice, always matches the UUID of a message payload.
@@ -222,6 +233,14 @@ pub mod hab_gossip {
                 payload: Message::GossipFile(cf),
             }
         }
+
+        /// Create a new rumor with a `Message::Departure` payload.
+        pub fn departure(departure: Departure) -> Rumor {
+            Rumor {
+                id: departure.member_id.clone(),
+                payload: Message::Departure(departure),
+            }
+        }
     }
 
     /// A list of rumors, and their corresponding heat. Heat determines whether we need to share the
@@ -52,6 +52,7 @@ use hcore::fs::{cache_artifact_path, cache_analytics_path, FS_ROOT_PATH};
 use hcore::service::ServiceGroup;
 use hcore::package::PackageIdent;
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
+use uuid::Uuid;
 
 use gossip::hab_gossip;
 
@@ -72,6 +73,9 @@ const DEFAULT_BINLINK_DIR: &'static str = "/bin";
 
 const MAX_FILE_UPLOAD_SIZE_BYTES: u64 = 4096;
 
+/// The Supervisor HTTP API to read the configuration history from, unless told otherwise
+const DEFAULT_SIDECAR_URL: &'static str = "http://127.0.0.1:9631";
+
 fn main() {
     env_logger::init().unwrap();
     thread::spawn(|| analytics::instrument_subcommand());
@@ -104,6 +108,7 @@ fn start() -> Result<()> {
         ("config", Some(matches)) => {
             match matches.subcommand() {
                 ("apply", Some(m)) => try!(sub_config_apply(m)),
+                ("rollback", Some(m)) => try!(sub_config_rollback(m)),
                 _ => unreachable!(),
             }
         }
@@ -146,11 +151,14 @@ fn start() -> Result<()> {
         }
         ("ring", Some(matches)) => {
             match matches.subcommand() {
+                ("depart", Some(m)) => try!(sub_ring_depart(m)),
                 ("key", Some(m)) => {
                     match m.subcommand() {
                         ("export", Some(sc)) => try!(sub_ring_key_export(sc)),
                         ("import", Some(_)) => try!(sub_ring_key_import()),
                         ("generate", Some(sc)) => try!(sub_ring_key_generate(sc)),
+                        ("retire", Some(sc)) => try!(sub_ring_key_retire(sc)),
+                        ("rotate", Some(sc)) => try!(sub_ring_key_rotate(sc)),
                         _ => unreachable!(),
                     }
                 }
@@ -227,7 +235,92 @@ fn sub_config_apply(m: &ArgMatches) -> Result<()> {
     };
     sg.organization = org;
 
-    command::config::apply::start(&peers, ring_key.as_ref(), &sg, number, file_path)
+    if m.is_present("DRY_RUN") {
+        return command::config::apply::dry_run(&sg, file_path);
+    }
+
+    let (signing_pair, encrypt_with) = try!(config_keys(m, &sg, &cache));
+    command::config::apply::start(&peers,
+                                  ring_key.as_ref(),
+                                  &sg,
+                                  number,
+                                  file_path,
+                                  signing_pair.as_ref(),
+                                  encrypt_with.as_ref().map(|&(ref user, ref service)| {
+                                      (user, service)
+                                  }))
+}
+
+fn sub_config_rollback(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&hab_gossip::GOSSIP_DEFAULT_PORT.to_string());
+        }
+    }
+    let version = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
+    let sidecar = m.value_of("SIDECAR").unwrap_or(DEFAULT_SIDECAR_URL);
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
+    sg.organization = org_param_or_env(&m).ok();
+
+    let (signing_pair, encrypt_with) = try!(config_keys(m, &sg, &cache));
+    command::config::rollback::start(sidecar,
+                                     &peers,
+                                     ring_key.as_ref(),
+                                     &sg,
+                                     version,
+                                     signing_pair.as_ref(),
+                                     encrypt_with.as_ref().map(|&(ref user, ref service)| {
+                                         (user, service)
+                                     }))
+}
+
+/// The keys to sign and encrypt a configuration for `sg` with. It is signed with the user's
+/// signing key when --user is given, or when the service group has an organization and HAB_USER
+/// is set; otherwise it goes out unsigned. Encrypting it needs a user and an organization, for
+/// the user's and the service group's box keys.
+fn config_keys(m: &ArgMatches,
+               sg: &ServiceGroup,
+               cache: &Path)
+               -> Result<(Option<SigKeyPair>, Option<(BoxKeyPair, BoxKeyPair)>)> {
+    let encrypt = m.is_present("ENCRYPT");
+    if m.value_of("USER").is_none() && sg.organization.is_none() && !encrypt {
+        return Ok((None, None));
+    }
+    let user = match user_param_or_env(&m) {
+        Ok(user) => user,
+        Err(e) => {
+            if encrypt {
+                return Err(e);
+            }
+            return Ok((None, None));
+        }
+    };
+    let signing_pair = try!(SigKeyPair::get_latest_pair_for(&SigKeyPair::user_key_name(&user),
+                                                            cache));
+    if !encrypt {
+        return Ok((Some(signing_pair), None));
+    }
+    if sg.organization.is_none() {
+        return Err(Error::CryptoCLI("Encrypting a configuration needs the service group's \
+                                     organization"
+            .to_string()));
+    }
+    let user_pair = try!(BoxKeyPair::get_latest_pair_for(&user, cache));
+    let service_pair = try!(BoxKeyPair::get_latest_pair_for(&sg.to_string(), cache));
+    Ok((Some(signing_pair), Some((user_pair, service_pair))))
 }
 
 fn sub_file_upload(m: &ArgMatches) -> Result<()> {
@@ -469,6 +562,32 @@ fn sub_pkg_verify(m: &ArgMatches) -> Result<()> {
     command::pkg::verify::start(&src, &default_cache_key_path(fs_root_path))
 }
 
+fn sub_ring_depart(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&hab_gossip::GOSSIP_DEFAULT_PORT.to_string());
+        }
+    }
+    // Validated by the CLI parser
+    let member_id = Uuid::parse_str(m.value_of("MEMBER_ID").unwrap()).unwrap();
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+    let origin = try!(origin_param_or_env(&m));
+    let pair = try!(SigKeyPair::get_latest_pair_for(&origin, &cache));
+
+    command::ring::depart::start(&peers, ring_key.as_ref(), &member_id, &pair)
+}
+
 fn sub_ring_key_export(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
@@ -497,6 +616,25 @@ fn sub_ring_key_import() -> Result<()> {
     command::ring::key::import::start(&content, &default_cache_key_path(fs_root_path))
 }
 
+fn sub_ring_key_retire(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let ring = m.value_of("RING").unwrap();
+    let sidecar = m.value_of("SIDECAR").unwrap_or(DEFAULT_SIDECAR_URL);
+    init();
+
+    command::ring::key::retire::start(ring, sidecar, &default_cache_key_path(fs_root_path))
+}
+
+fn sub_ring_key_rotate(m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let ring = m.value_of("RING").unwrap();
+    init();
+
+    command::ring::key::rotate::start(ring, &default_cache_key_path(fs_root_path))
+}
+
 fn sub_service_key_generate(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
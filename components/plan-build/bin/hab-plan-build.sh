@@ -169,6 +169,19 @@
 # pkg_expose=(80 443)
 # ```
 #
+# ### pkg_shutdown_signal
+# The signal the supervisor sends the service to stop it gracefully. Defaults to `TERM`.
+# ```
+# pkg_shutdown_signal=QUIT
+# ```
+#
+# ### pkg_shutdown_timeout
+# How many seconds the supervisor waits for the service to stop before killing it.
+# Defaults to 8.
+# ```
+# pkg_shutdown_timeout=30
+# ```
+#
 # ### pkg_origin
 # A string to use for the origin. The origin is used to denote a particular upstream of a
 # package; when we resolve dependencies, we consider a version of a package to be equal
@@ -348,6 +361,10 @@ pkg_include_dirs=()
 pkg_svc_run=''
 # An array of ports to expose.
 pkg_expose=()
+# The signal to send the service to stop it
+pkg_shutdown_signal=''
+# How many seconds to wait for the service to stop before killing it
+pkg_shutdown_timeout=''
 # The user to run the service as
 pkg_svc_user=hab
 # The group to run the service as
@@ -1761,6 +1778,10 @@ do_default_install() {
 # * `$pkg_prefix/LDFLAGS` - Any LDFLAGS for things that link against us
 # * `$pkg_prefix/LD_RUN_PATH` - The LD_RUN_PATH for things that link against us
 # * `$pkg_prefix/PATH` - Any PATH entries for things that link against us
+# * `$pkg_prefix/SHUTDOWN_SIGNAL` - The signal to send the service to stop it
+# * `$pkg_prefix/SHUTDOWN_TIMEOUT` - How long to wait for the service to stop
+# * `$pkg_prefix/SVC_USER` - The user to run the service and its hooks as
+# * `$pkg_prefix/SVC_GROUP` - The group to run the service and its hooks as
 _build_metadata() {
   build_line "Building package metadata"
   local ld_run_path_part=""
@@ -1820,6 +1841,19 @@ _build_metadata() {
     echo $port_part > $pkg_prefix/EXPOSES
   fi
 
+  if [[ -n "${pkg_shutdown_signal}" ]]; then
+    echo $pkg_shutdown_signal > $pkg_prefix/SHUTDOWN_SIGNAL
+  fi
+  if [[ -n "${pkg_shutdown_timeout}" ]]; then
+    echo $pkg_shutdown_timeout > $pkg_prefix/SHUTDOWN_TIMEOUT
+  fi
+  if [[ -n "${pkg_svc_user}" ]]; then
+    echo $pkg_svc_user > $pkg_prefix/SVC_USER
+  fi
+  if [[ -n "${pkg_svc_group}" ]]; then
+    echo $pkg_svc_group > $pkg_prefix/SVC_GROUP
+  fi
+
   if [[ ${#pkg_interpreters[@]} -gt 0 ]]; then
     local interpreters="$(printf "${pkg_prefix}/%s\n" ${pkg_interpreters[@]})"
     printf "%s\n" ${pkg_interpreters[@]} \
@@ -1868,7 +1902,7 @@ _build_metadata() {
 }
 
 # Copy the `./config` directory, relative to the Plan, to `$pkg_prefix/config`.
-# Do the same with `default.toml`. Delegates most of the implementation to the
+# Do the same with `default.toml` and `schema.toml`. Delegates most of the implementation to the
 # `do_default_build_config()` function.
 do_build_config() {
   do_default_build_config
@@ -1889,6 +1923,9 @@ do_default_build_config() {
   if [[ -f $PLAN_CONTEXT/default.toml ]]; then
     cp $PLAN_CONTEXT/default.toml $pkg_prefix
   fi
+  if [[ -f $PLAN_CONTEXT/schema.toml ]]; then
+    cp $PLAN_CONTEXT/schema.toml $pkg_prefix
+  fi
   return 0
 }
 
@@ -23,19 +23,24 @@
 //! for selecting a leader deterministically for the group. We rely on the eventual consistency of
 //! every supervisors CensusEntry to elect a new leader in a reasonable amount of time.
 
-use std::collections::{HashMap, BTreeMap};
+use std::collections::{hash_map, HashMap, BTreeMap};
 use std::fmt;
 use std::ops::{Deref, DerefMut};
 use std::sync::{Arc, RwLock};
 use std::thread;
 use std::time::Duration;
 
+use common;
+use common::message::gossip as proto;
+use common::wire_message::Protobuf;
 use toml;
 use uuid::Uuid;
 
 use gossip::member::{MemberId, MemberList, Health};
 use gossip::lamport_clock::LamportClock;
+use gossip::rumor::id_from_proto;
 use error::{Error, Result};
+use health_check;
 use util;
 
 static LOGKEY: &'static str = "CN";
@@ -67,6 +72,17 @@ pub struct CensusEntry {
     pub suspect: bool,
     pub confirmed: bool,
     pub detached: bool,
+    pub package_ident: Option<String>,
+    /// The package we rolled back from, so the rest of the group skips us in a rolling update
+    pub rejected_ident: Option<String>,
+    /// Whether we are in the middle of a rolling update; `None` from members that predate rolling
+    /// updates
+    pub updating: Option<bool>,
+    /// How many times our service has been restarted; `None` from members that predate restart
+    /// policies
+    pub restart_count: Option<u64>,
+    pub restart_backoff: Option<u64>,
+    pub health_check: Option<health_check::Status>,
     pub incarnation: LamportClock,
 }
 
@@ -95,6 +111,12 @@ impl CensusEntry {
             suspect: false,
             confirmed: false,
             detached: false,
+            package_ident: None,
+            rejected_ident: None,
+            updating: Some(false),
+            restart_count: Some(0),
+            restart_backoff: None,
+            health_check: None,
             service: service.into(),
             group: group.into(),
             incarnation: LamportClock::new(),
@@ -168,6 +190,75 @@ impl CensusEntry {
         self.needs_write = Some(true);
     }
 
+    /// Set the package identifier we are running.
+    pub fn package_ident(&mut self, package_ident: Option<String>) {
+        if self.package_ident != package_ident {
+            self.package_ident = package_ident;
+            self.incarnation.increment();
+            self.needs_write = Some(true);
+        }
+    }
+
+    /// Set the package identifier we rolled back from.
+    pub fn rejected_ident(&mut self, rejected_ident: Option<String>) {
+        if self.rejected_ident != rejected_ident {
+            self.rejected_ident = rejected_ident;
+            self.incarnation.increment();
+            self.needs_write = Some(true);
+        }
+    }
+
+    /// Are we in the middle of a rolling update?
+    pub fn is_updating(&self) -> bool {
+        self.updating.unwrap_or(false)
+    }
+
+    /// Set whether we are in the middle of a rolling update.
+    pub fn updating(&mut self, updating: bool) {
+        if self.updating != Some(updating) {
+            self.updating = Some(updating);
+            self.incarnation.increment();
+            self.needs_write = Some(true);
+        }
+    }
+
+    /// Set how many times the supervisor has restarted our service.
+    pub fn restart_count(&mut self, restart_count: u64) {
+        if self.restart_count != Some(restart_count) {
+            self.restart_count = Some(restart_count);
+            self.incarnation.increment();
+            self.needs_write = Some(true);
+        }
+    }
+
+    /// Set the backoff, in seconds, we are waiting out before restarting our service.
+    pub fn restart_backoff(&mut self, restart_backoff: Option<u64>) {
+        if self.restart_backoff != restart_backoff {
+            self.restart_backoff = restart_backoff;
+            self.incarnation.increment();
+            self.needs_write = Some(true);
+        }
+    }
+
+    /// Is this member alive, and not failing its health check? Members that have not reported a
+    /// health check yet are considered healthy.
+    pub fn is_healthy(&self) -> bool {
+        match self.health_check {
+            Some(health_check::Status::Critical) |
+            Some(health_check::Status::Unknown) => false,
+            _ => self.alive,
+        }
+    }
+
+    /// Set the result of our most recent health check.
+    pub fn health_check(&mut self, status: Option<health_check::Status>) {
+        if self.health_check != status {
+            self.health_check = status;
+            self.incarnation.increment();
+            self.needs_write = Some(true);
+        }
+    }
+
     /// Are we in an election?
     pub fn election(&mut self, election: Option<bool>) {
         self.election = election;
@@ -272,12 +363,164 @@ impl PartialEq for CensusEntry {
             false
         } else if self.group != other.group {
             false
+        } else if self.package_ident != other.package_ident {
+            false
+        } else if self.rejected_ident != other.rejected_ident {
+            false
+        } else if self.updating != other.updating {
+            false
+        } else if self.restart_count != other.restart_count {
+            false
+        } else if self.restart_backoff != other.restart_backoff {
+            false
+        } else if self.health_check != other.health_check {
+            false
         } else {
             true
         }
     }
 }
 
+impl Protobuf for CensusEntry {
+    type Message = proto::CensusEntry;
+
+    fn to_proto(&self) -> proto::CensusEntry {
+        let mut ce = proto::CensusEntry::new();
+        ce.set_id(self.id.as_bytes().to_vec());
+        ce.set_member_id(self.member_id.as_bytes().to_vec());
+        ce.set_hostname(self.hostname.clone());
+        ce.set_ip(self.ip.clone());
+        ce.set_suitability(self.suitability);
+        if let Some(ref port) = self.port {
+            ce.set_port(port.clone());
+        }
+        if let Some(ref ports) = self.exposes {
+            let mut exposes = proto::Exposes::new();
+            for port in ports.iter() {
+                exposes.mut_ports().push(port.clone());
+            }
+            ce.set_exposes(exposes);
+        }
+        ce.set_leader(self.leader);
+        ce.set_follower(self.follower);
+        ce.set_data_init(self.data_init);
+        if let Some(ref vote) = self.vote {
+            ce.set_vote(vote.clone());
+        }
+        if let Some(election) = self.election {
+            ce.set_election(election);
+        }
+        if let Some(needs_write) = self.needs_write {
+            ce.set_needs_write(needs_write);
+        }
+        ce.set_initialized(self.initialized);
+        ce.set_keep_me(self.keep_me);
+        ce.set_service(self.service.clone());
+        ce.set_group(self.group.clone());
+        ce.set_alive(self.alive);
+        ce.set_suspect(self.suspect);
+        ce.set_confirmed(self.confirmed);
+        ce.set_detached(self.detached);
+        if let Some(ref package_ident) = self.package_ident {
+            ce.set_package_ident(package_ident.clone());
+        }
+        if let Some(ref rejected_ident) = self.rejected_ident {
+            ce.set_rejected_ident(rejected_ident.clone());
+        }
+        if let Some(updating) = self.updating {
+            ce.set_updating(updating);
+        }
+        if let Some(restart_count) = self.restart_count {
+            ce.set_restart_count(restart_count);
+        }
+        if let Some(restart_backoff) = self.restart_backoff {
+            ce.set_restart_backoff(restart_backoff);
+        }
+        if let Some(ref health_check) = self.health_check {
+            ce.set_health_check(health_check.to_proto());
+        }
+        ce.set_incarnation(self.incarnation.counter);
+        ce
+    }
+
+    fn from_proto(mut ce: proto::CensusEntry) -> common::Result<CensusEntry> {
+        Ok(CensusEntry {
+            id: try!(id_from_proto(ce.get_id())),
+            member_id: try!(id_from_proto(ce.get_member_id())),
+            hostname: ce.take_hostname(),
+            ip: ce.take_ip(),
+            suitability: ce.get_suitability(),
+            port: if ce.has_port() {
+                Some(ce.take_port())
+            } else {
+                None
+            },
+            exposes: if ce.has_exposes() {
+                Some(ce.take_exposes().take_ports().into_vec())
+            } else {
+                None
+            },
+            leader: ce.get_leader(),
+            follower: ce.get_follower(),
+            data_init: ce.get_data_init(),
+            vote: if ce.has_vote() {
+                Some(ce.take_vote())
+            } else {
+                None
+            },
+            election: if ce.has_election() {
+                Some(ce.get_election())
+            } else {
+                None
+            },
+            needs_write: if ce.has_needs_write() {
+                Some(ce.get_needs_write())
+            } else {
+                None
+            },
+            initialized: ce.get_initialized(),
+            keep_me: ce.get_keep_me(),
+            service: ce.take_service(),
+            group: ce.take_group(),
+            alive: ce.get_alive(),
+            suspect: ce.get_suspect(),
+            confirmed: ce.get_confirmed(),
+            detached: ce.get_detached(),
+            package_ident: if ce.has_package_ident() {
+                Some(ce.take_package_ident())
+            } else {
+                None
+            },
+            rejected_ident: if ce.has_rejected_ident() {
+                Some(ce.take_rejected_ident())
+            } else {
+                None
+            },
+            updating: if ce.has_updating() {
+                Some(ce.get_updating())
+            } else {
+                None
+            },
+            restart_count: if ce.has_restart_count() {
+                Some(ce.get_restart_count())
+            } else {
+                None
+            },
+            restart_backoff: if ce.has_restart_backoff() {
+                Some(ce.get_restart_backoff())
+            } else {
+                None
+            },
+            health_check: if ce.has_health_check() {
+                Some(health_check::Status::from_proto(ce.get_health_check()))
+            } else {
+                None
+            },
+            incarnation: LamportClock { counter: ce.get_incarnation() },
+        })
+    }
+}
+
 impl fmt::Display for CensusEntry {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}: {}", self.service_group(), self.id)
@@ -371,6 +614,24 @@ impl Census {
         self.population.insert(ce.id, ce);
     }
 
+    /// Remove the entries of members that have departed the ring. Removing an entry leaves
+    /// nothing behind that needs writing, so we flag our own entry to get the change rendered.
+    pub fn remove_departed(&mut self, member_list: &MemberList) {
+        let departed: Vec<CensusEntryId> = self.population
+            .values()
+            .filter(|ce| ce.id != self.me && member_list.is_departed(&ce.member_id))
+            .map(|ce| ce.id.clone())
+            .collect();
+        if departed.is_empty() {
+            return;
+        }
+        for id in departed.iter() {
+            outputln!("Removing departed member {} from the census", id);
+            self.population.remove(id);
+        }
+        self.me_mut().needs_write = Some(true);
+    }
+
     /// Set whether we are in an event
     pub fn in_event(&mut self, status: bool) {
         self.in_event = status;
@@ -619,6 +880,30 @@ impl Census {
         self.population.get(&Uuid::parse_str(&my_vote).unwrap())
     }
 
+    /// Is it our turn to apply a rolling update to the package `ident`?
+    ///
+    /// * No other alive member may be in the middle of an update
+    /// * Of the alive members not yet running `ident`, and that haven't rolled back from it, we
+    ///   must be the one whose `member_id` sorts first lexicographically
+    pub fn rolling_update_turn(&self, ident: &str) -> bool {
+        let someone_updating = self.population
+            .values()
+            .any(|ce| ce.alive && ce.is_updating() && ce.id != self.me);
+        if someone_updating {
+            return false;
+        }
+        let next = self.population
+            .values()
+            .filter(|ce| ce.alive)
+            .filter(|ce| ce.package_ident.as_ref().map_or(true, |i| i != ident))
+            .filter(|ce| ce.rejected_ident.as_ref().map_or(true, |i| i != ident))
+            .min_by_key(|ce| ce.member_id.simple().to_string());
+        match next {
+            Some(ce) => ce.id == self.me,
+            None => false,
+        }
+    }
+
     pub fn total_population(&self) -> usize {
         self.population.len()
     }
@@ -653,6 +938,18 @@ impl Census {
         }
     }
 
+    /// Is the leader suspected of being dead? We give it a chance to come back before we start a
+    /// new election.
+    pub fn leader_suspect(&self) -> bool {
+        !self.has_leader() && self.population.values().any(|ce| ce.leader && ce.suspect)
+    }
+
+    /// Has the leader been confirmed dead, or left the ring?
+    pub fn leader_departed(&self) -> bool {
+        !self.has_leader() &&
+        self.population.values().any(|ce| ce.leader && (ce.confirmed || ce.detached))
+    }
+
     pub fn no_leaders_allowed(&mut self) {
         for (_id, ce) in self.population.iter_mut() {
             ce.leader = false;
@@ -701,6 +998,11 @@ impl CensusList {
         self.local_census_mut().me_mut()
     }
 
+    /// Iterate over the census of every service group we know about.
+    pub fn censuses(&self) -> hash_map::Values<String, Census> {
+        self.censuses.values()
+    }
+
     pub fn local_census(&self) -> &Census {
         self.censuses.get(&self.local_census).unwrap()
     }
@@ -782,6 +1084,10 @@ pub fn start_health_adjuster(census_list: Arc<RwLock<CensusList>>,
             {
                 let mut cl = cl1.write().unwrap();
                 for (_service_group, mut census) in cl.iter_mut() {
+                    {
+                        let ml = ml1.read().unwrap();
+                        census.remove_departed(&ml);
+                    }
                     for (_census_entry_id, mut census_entry) in census.iter_mut() {
                         let ml = ml1.read().unwrap();
                         if let Some(member) = ml.get(&census_entry.member_id) {
@@ -801,6 +1107,8 @@ pub fn start_health_adjuster(census_list: Arc<RwLock<CensusList>>,
                                         census_entry.set_confirmed();
                                     }
                                 }
+                                // Entries for departed members were removed above
+                                Health::Departed => {}
                             }
                         } else {
                             if census_entry.detached == false {
@@ -818,7 +1126,7 @@ pub fn start_health_adjuster(census_list: Arc<RwLock<CensusList>>,
 #[cfg(test)]
 mod test {
     mod census {
-        use gossip::member::MemberId;
+        use gossip::member::{Member, MemberId, MemberList};
         use census::{Census, CensusEntry};
 
         fn generate_ce() -> CensusEntry {
@@ -886,6 +1194,70 @@ mod test {
             assert_eq!(census.minimum_quorum(), true);
         }
 
+        fn run_package(census: &mut Census, ident: &str) {
+            for (_id, mut ce) in census.iter_mut() {
+                ce.package_ident(Some(ident.to_string()));
+            }
+        }
+
+        fn first_by_member_id(census: &Census) -> CensusEntry {
+            census.values()
+                .min_by_key(|ce| ce.member_id.simple().to_string())
+                .unwrap()
+                .clone()
+        }
+
+        #[test]
+        fn rolling_update_turn_goes_in_member_id_order() {
+            let mut census = generate_census();
+            add_entries(&mut census, 4);
+            run_package(&mut census, "core/redis/3.0.7/20160614000000");
+            let new_ident = "core/redis/3.2.0/20160701000000";
+
+            let first = first_by_member_id(&census);
+            census.me = first.id;
+            assert_eq!(census.rolling_update_turn(new_ident), true);
+
+            // Once the first member has updated, it is someone elses turn
+            census.me_mut().package_ident(Some(new_ident.to_string()));
+            assert_eq!(census.rolling_update_turn(new_ident), false);
+        }
+
+        #[test]
+        fn rolling_update_turn_waits_for_members_updating() {
+            let mut census = generate_census();
+            add_entries(&mut census, 4);
+            run_package(&mut census, "core/redis/3.0.7/20160614000000");
+            let new_ident = "core/redis/3.2.0/20160701000000";
+
+            let first = first_by_member_id(&census);
+            census.me = first.id;
+            let me = census.me.clone();
+            {
+                let (_id, mut other) = census.iter_mut().find(|&(id, _)| *id != me).unwrap();
+                other.updating(true);
+            }
+            assert_eq!(census.rolling_update_turn(new_ident), false);
+        }
+
+        #[test]
+        fn rolling_update_turn_skips_members_that_rolled_back() {
+            let mut census = generate_census();
+            add_entries(&mut census, 4);
+            run_package(&mut census, "core/redis/3.0.7/20160614000000");
+            let new_ident = "core/redis/3.2.0/20160701000000";
+
+            let mut order: Vec<CensusEntry> = census.values().cloned().collect();
+            order.sort_by_key(|ce| ce.member_id.simple().to_string());
+            let (first, second) = (order[0].clone(), order[1].clone());
+            census.me = second.id;
+            assert_eq!(census.rolling_update_turn(new_ident), false);
+
+            // Once the first member has rolled back, it is the second member's turn
+            census.get_mut(&first.id).unwrap().rejected_ident(Some(new_ident.to_string()));
+            assert_eq!(census.rolling_update_turn(new_ident), true);
+        }
+
         #[test]
         fn has_leader() {
             let mut census = generate_census();
@@ -899,5 +1271,61 @@ mod test {
             fail_the_leader(&mut census);
             assert_eq!(census.has_leader(), false);
         }
+
+        #[test]
+        fn leader_suspect_is_not_departed() {
+            let mut census = generate_census();
+            add_entries(&mut census, 10);
+            elect_an_entry(&mut census);
+            assert_eq!(census.leader_suspect(), false);
+            assert_eq!(census.leader_departed(), false);
+            {
+                let (_id, mut leader) = census.population
+                    .iter_mut()
+                    .find(|&(_id, ref ce)| ce.leader)
+                    .unwrap();
+                leader.set_suspect();
+            }
+            assert_eq!(census.leader_suspect(), true);
+            assert_eq!(census.leader_departed(), false);
+        }
+
+        #[test]
+        fn leader_departed() {
+            let mut census = generate_census();
+            add_entries(&mut census, 10);
+            elect_an_entry(&mut census);
+            fail_the_leader(&mut census);
+            assert_eq!(census.leader_suspect(), false);
+            assert_eq!(census.leader_departed(), true);
+        }
+
+        #[test]
+        fn remove_departed() {
+            let mut census = generate_census();
+            let me = census.me().clone();
+            census.written();
+            let mut ml = MemberList::new(Member::new(String::from("me"),
+                                                     String::from("192.168.1.1"),
+                                                     String::from("192.168.1.1:9634"),
+                                                     false));
+            let departing = Member::new(String::from("departing"),
+                                        String::from("192.168.1.2"),
+                                        String::from("192.168.1.2:9634"),
+                                        false);
+            let departing_ce = CensusEntry::new("soup", "unit", departing.id.clone());
+            census.add(departing_ce.clone());
+            ml.insert(departing.clone());
+
+            census.remove_departed(&ml);
+            assert_eq!(census.len(), 2);
+
+            ml.depart(&departing.id);
+            census.remove_departed(&ml);
+            assert_eq!(census.len(), 1);
+            assert!(census.get(&departing_ce.id).is_none());
+            assert_eq!(census.me().id, me.id);
+            assert!(census.needs_write());
+        }
     }
 }
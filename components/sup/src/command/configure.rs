@@ -14,13 +14,31 @@
 //! ```
 //!
 //! Will show the `default.toml`.
+//!
+//! ```bash
+//! $ hab-sup config core/redis/3.0.7/20160614234125 --dry-run new.toml
+//! ```
+//!
+//! Will show how the configuration files of a running `redis` would change with `new.toml` as
+//! its gossip configuration.
 
+use std::io;
 use std::io::prelude::*;
 use std::fs::File;
 
-use error::Result;
+use ansi_term::Colour::{Green, Purple};
+use toml;
+
+use error::{Error, Result};
 use config::Config;
 use package::Package;
+use service_config;
+use util::diff;
+
+static LOGKEY: &'static str = "CF";
+
+/// How many unchanged lines to show around each change
+const DIFF_CONTEXT: usize = 3;
 
 /// Print the default.toml for a given package.
 ///
@@ -37,3 +55,41 @@ pub fn display(config: &Config) -> Result<()> {
     println!("{}", s);
     Ok(())
 }
+
+/// Render the package's configuration files with the file in `config.dry_run()` as the gossip
+/// configuration, and print how they would change. Nothing is written or gossiped.
+///
+/// # Failures
+///
+/// * If the package cannot be found, or has never run here
+/// * If the file cannot be read, isn't TOML, or doesn't match the package's schema
+/// * If a template fails to render
+pub fn dry_run(config: &Config) -> Result<()> {
+    let package = try!(Package::load(config.package(), None));
+    let mut body = String::new();
+    match config.dry_run().as_ref().map(|f| f.as_str()) {
+        Some("-") | None => try!(io::stdin().read_to_string(&mut body)),
+        Some(file) => try!(try!(File::open(file)).read_to_string(&mut body)),
+    };
+    let mut toml_parser = toml::Parser::new(&body);
+    let gossip = try!(toml_parser.parse()
+        .ok_or(sup_error!(Error::TomlParser(toml_parser.errors))));
+
+    for (name, rendered) in try!(service_config::dry_run(&package, gossip)) {
+        let path = package.pkg_install.svc_config_path().join(&name);
+        let mut current = String::new();
+        if let Ok(mut file) = File::open(&path) {
+            try!(file.read_to_string(&mut current));
+        }
+        let changes = diff::unified(&current, &rendered, DIFF_CONTEXT);
+        if changes.is_empty() {
+            println!("{} {}", Green.paint("="), name);
+        } else {
+            println!("{} {}", Purple.bold().paint("~"), name);
+            println!("--- {}", path.display());
+            println!("+++ {} (dry run)", path.display());
+            print!("{}", changes);
+        }
+    }
+    Ok(())
+}
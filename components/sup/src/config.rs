@@ -19,6 +19,7 @@ use hcore::package::PackageIdent;
 
 use error::{Error, SupError};
 use gossip::server::GOSSIP_DEFAULT_PORT;
+use limits::Limits;
 use topology::Topology;
 
 static LOGKEY: &'static str = "CFG";
@@ -36,6 +37,7 @@ pub enum Command {
 pub enum UpdateStrategy {
     None,
     AtOnce,
+    Rolling,
 }
 
 impl UpdateStrategy {
@@ -43,6 +45,7 @@ impl UpdateStrategy {
         match strategy {
             "none" => UpdateStrategy::None,
             "at-once" => UpdateStrategy::AtOnce,
+            "rolling" => UpdateStrategy::Rolling,
             s => panic!("Invalid update strategy {}", s),
         }
     }
@@ -53,6 +56,31 @@ impl Default for UpdateStrategy {
     }
 }
 
+/// When should the supervisor restart a service that exited on its own?
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartPolicy {
+    Always,
+    OnFailure,
+    Never,
+}
+
+impl RestartPolicy {
+    pub fn from_str(policy: &str) -> Self {
+        match policy {
+            "always" => RestartPolicy::Always,
+            "on-failure" => RestartPolicy::OnFailure,
+            "never" => RestartPolicy::Never,
+            s => panic!("Invalid restart policy {}", s),
+        }
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> RestartPolicy {
+        RestartPolicy::Always
+    }
+}
+
 impl FromStr for Command {
     type Err = SupError;
     fn from_str(s: &str) -> Result<Command, SupError> {
@@ -91,6 +119,12 @@ pub struct Config {
     gossip_listen_port: u16,
     http_listen_ip: String,
     http_listen_port: u16,
+    control_token: Option<String>,
+    dns_listen: Option<(String, u16)>,
+    config_trusted_users: Vec<String>,
+    control_trusted_users: Vec<String>,
+    config_history: usize,
+    dry_run: Option<String>,
     userkey: Option<String>,
     servicekey: Option<String>,
     infile: Option<String>,
@@ -98,6 +132,15 @@ pub struct Config {
     gossip_peer: Vec<String>,
     gossip_permanent: bool,
     update_strategy: UpdateStrategy,
+    rollback_window: u64,
+    restart_policy: RestartPolicy,
+    restart_backoff_max: u64,
+    restart_max: u32,
+    restart_window: u64,
+    health_check_interval: u64,
+    limits: Limits,
+    log_max_bytes: u64,
+    log_max_files: usize,
     service_group: String,
     file_path: String,
     version_number: u64,
@@ -132,6 +175,107 @@ impl Config {
         self.update_strategy.clone()
     }
 
+    /// Set how many seconds to watch a package update before we consider it good; 0 disables
+    /// rollback
+    pub fn set_rollback_window(&mut self, secs: u64) -> &mut Config {
+        self.rollback_window = secs;
+        self
+    }
+
+    /// Return the rollback window, in seconds
+    pub fn rollback_window(&self) -> u64 {
+        self.rollback_window
+    }
+
+    /// Set the policy for restarting a service that exited on its own
+    pub fn set_restart_policy(&mut self, policy: RestartPolicy) -> &mut Config {
+        self.restart_policy = policy;
+        self
+    }
+
+    /// Return the restart policy
+    pub fn restart_policy(&self) -> RestartPolicy {
+        self.restart_policy
+    }
+
+    /// Set the longest we will wait, in seconds, before restarting a crashing service
+    pub fn set_restart_backoff_max(&mut self, secs: u64) -> &mut Config {
+        self.restart_backoff_max = secs;
+        self
+    }
+
+    /// Return the maximum restart backoff, in seconds
+    pub fn restart_backoff_max(&self) -> u64 {
+        self.restart_backoff_max
+    }
+
+    /// Set how many restarts we allow within the restart window before giving up; 0 means no
+    /// limit
+    pub fn set_restart_max(&mut self, max: u32) -> &mut Config {
+        self.restart_max = max;
+        self
+    }
+
+    /// Return the maximum number of restarts within the restart window
+    pub fn restart_max(&self) -> u32 {
+        self.restart_max
+    }
+
+    /// Set the window, in seconds, over which we count restarts
+    pub fn set_restart_window(&mut self, secs: u64) -> &mut Config {
+        self.restart_window = secs;
+        self
+    }
+
+    /// Return the restart window, in seconds
+    pub fn restart_window(&self) -> u64 {
+        self.restart_window
+    }
+
+    /// Set how often, in seconds, the supervisor runs the health check; 0 disables it
+    pub fn set_health_check_interval(&mut self, secs: u64) -> &mut Config {
+        self.health_check_interval = secs;
+        self
+    }
+
+    /// Return the health check interval, in seconds
+    pub fn health_check_interval(&self) -> u64 {
+        self.health_check_interval
+    }
+
+    /// Set the resource limits for the service
+    pub fn set_limits(&mut self, limits: Limits) -> &mut Config {
+        self.limits = limits;
+        self
+    }
+
+    /// Return the resource limits for the service
+    pub fn limits(&self) -> &Limits {
+        &self.limits
+    }
+
+    /// Set the size, in bytes, at which we rotate the service's log file; 0 never rotates it
+    pub fn set_log_max_bytes(&mut self, bytes: u64) -> &mut Config {
+        self.log_max_bytes = bytes;
+        self
+    }
+
+    /// Return the size at which we rotate the service's log file, in bytes
+    pub fn log_max_bytes(&self) -> u64 {
+        self.log_max_bytes
+    }
+
+    /// Set how many rotated log files we keep
+    pub fn set_log_max_files(&mut self, count: usize) -> &mut Config {
+        self.log_max_files = count;
+        self
+    }
+
+    /// Return how many rotated log files we keep
+    pub fn log_max_files(&self) -> usize {
+        self.log_max_files
+    }
+
     /// Set the `Command` we used
     pub fn set_command(&mut self, command: Command) -> &mut Config {
         self.command = command;
@@ -310,6 +454,74 @@ impl Config {
         self
     }
 
+    /// Set the shared token that authorizes requests to the sidecar's control API
+    pub fn set_control_token(&mut self, token: String) -> &mut Config {
+        self.control_token = Some(token);
+        self
+    }
+
+    /// Return the control API token, if there is one
+    pub fn control_token(&self) -> &Option<String> {
+        &self.control_token
+    }
+
+    /// Set the IP and port the DNS server listens on
+    pub fn set_dns_listen(&mut self, ip: String, port: u16) -> &mut Config {
+        self.dns_listen = Some((ip, port));
+        self
+    }
+
+    /// Return the IP and port the DNS server listens on, if it is to run
+    pub fn dns_listen(&self) -> &Option<(String, u16)> {
+        &self.dns_listen
+    }
+
+    /// Set the users whose signed configuration updates we accept
+    pub fn set_config_trusted_users(&mut self, users: Vec<String>) -> &mut Config {
+        self.config_trusted_users = users;
+        self
+    }
+
+    /// Return the users whose signed configuration updates we accept; if there are none,
+    /// unsigned updates are accepted too
+    pub fn config_trusted_users(&self) -> &[String] {
+        &self.config_trusted_users
+    }
+
+    /// Set the users whose signed control requests we accept
+    pub fn set_control_trusted_users(&mut self, users: Vec<String>) -> &mut Config {
+        self.control_trusted_users = users;
+        self
+    }
+
+    /// Return the users whose signed control requests we accept; if there are none, only the
+    /// control token is accepted
+    pub fn control_trusted_users(&self) -> &[String] {
+        &self.control_trusted_users
+    }
+
+    /// Set how many rendered configurations to keep in the service's history
+    pub fn set_config_history(&mut self, keep: usize) -> &mut Config {
+        self.config_history = keep;
+        self
+    }
+
+    /// Return how many rendered configurations to keep in the service's history; 0 keeps none
+    pub fn config_history(&self) -> usize {
+        self.config_history
+    }
+
+    /// Set the file to render the configuration with, instead of applying it; - is stdin
+    pub fn set_dry_run(&mut self, file: String) -> &mut Config {
+        self.dry_run = Some(file);
+        self
+    }
+
+    /// Return the file to render the configuration with, if this is a dry run
+    pub fn dry_run(&self) -> &Option<String> {
+        &self.dry_run
+    }
+
     pub fn gossip_permanent(&self) -> bool {
         self.gossip_permanent
     }
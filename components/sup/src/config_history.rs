@@ -0,0 +1,265 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! Keeps the last few sets of configuration the supervisor rendered, with the version of the
+//! gossip file that produced them, so they can be looked at and rolled back to.
+//!
+//! Each set is a JSON file in `/hab/svc/<service>/config_history`, named for its id. Ids go up by
+//! one with every set, and the oldest sets are removed once there are more than we keep.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+use rustc_serialize::json;
+use time;
+
+use common::gossip_file::GOSSIP_TOML;
+use error::Result;
+use package::Package;
+
+/// The directory in the service's path the history lives in
+pub const HISTORY_DIR: &'static str = "config_history";
+
+/// How many sets of configuration we keep, unless told otherwise
+pub const DEFAULT_KEEP: usize = 10;
+
+/// One set of rendered configuration
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct ConfigSet {
+    pub id: u64,
+    /// The version of the gossip file behind this configuration, if there was one
+    pub gossip_version: Option<u64>,
+    /// When the configuration was rendered, in seconds since the epoch
+    pub rendered_at: i64,
+    /// The gossip file's contents
+    pub gossip_toml: Option<String>,
+    /// The full configuration the templates were rendered with
+    pub config_toml: String,
+    /// Every rendered configuration file, by name
+    pub files: BTreeMap<String, String>,
+}
+
+impl ConfigSet {
+    // Whether two sets rendered the same files from the same gossip file
+    fn same_as(&self, other: &ConfigSet) -> bool {
+        self.gossip_version == other.gossip_version && self.files == other.files
+    }
+}
+
+/// The configuration history of a service
+#[derive(Debug, Clone)]
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    pub fn new<P: Into<PathBuf>>(path: P) -> History {
+        History { path: path.into() }
+    }
+
+    /// The history of the package's service.
+    pub fn for_package(package: &Package) -> History {
+        History::new(package.pkg_install.svc_path().join(HISTORY_DIR))
+    }
+
+    /// Record the configuration the supervisor just rendered for the package, keeping the last
+    /// `keep` sets. Returns the id of the new set, or `None` if it rendered the same files from
+    /// the same gossip file as the last one, or we keep no history.
+    ///
+    /// # Failures
+    ///
+    /// * If the rendered configuration can't be read
+    /// * If the set can't be written
+    pub fn record(&self,
+                  package: &Package,
+                  gossip_version: Option<u64>,
+                  keep: usize)
+                  -> Result<Option<u64>> {
+        if keep == 0 {
+            return Ok(None);
+        }
+        let pi = &package.pkg_install;
+        let mut files = BTreeMap::new();
+        for config in try!(package.config_files()) {
+            let contents = try!(read(&pi.svc_config_path().join(&config)));
+            files.insert(config, contents);
+        }
+        let gossip_toml = if gossip_version.is_some() {
+            read(&pi.svc_path().join(GOSSIP_TOML)).ok()
+        } else {
+            None
+        };
+        let set = ConfigSet {
+            id: 0,
+            gossip_version: gossip_version,
+            rendered_at: time::now_utc().to_timespec().sec,
+            gossip_toml: gossip_toml,
+            config_toml: try!(read(&pi.svc_path().join("config.toml"))),
+            files: files,
+        };
+        self.push(set, keep)
+    }
+
+    /// The ids of every set we have, oldest first.
+    pub fn ids(&self) -> Result<Vec<u64>> {
+        let mut ids = Vec::new();
+        let entries = match fs::read_dir(&self.path) {
+            Ok(entries) => entries,
+            Err(_) => return Ok(ids),
+        };
+        for entry in entries {
+            let path = try!(entry).path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let id = path.file_stem().and_then(|s| s.to_str()).and_then(|s| s.parse().ok());
+            if let Some(id) = id {
+                ids.push(id);
+            }
+        }
+        ids.sort();
+        Ok(ids)
+    }
+
+    /// The set with this id, if we still have it.
+    pub fn get(&self, id: u64) -> Result<Option<ConfigSet>> {
+        let path = self.set_path(id);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        Ok(Some(try!(json::decode(&try!(read(&path))))))
+    }
+
+    /// Every set we have, oldest first.
+    pub fn list(&self) -> Result<Vec<ConfigSet>> {
+        let mut sets = Vec::new();
+        for id in try!(self.ids()) {
+            if let Some(set) = try!(self.get(id)) {
+                sets.push(set);
+            }
+        }
+        Ok(sets)
+    }
+
+    /// The newest set rendered from this version of the gossip file.
+    pub fn for_gossip_version(&self, version: u64) -> Result<Option<ConfigSet>> {
+        for id in try!(self.ids()).into_iter().rev() {
+            if let Some(set) = try!(self.get(id)) {
+                if set.gossip_version == Some(version) {
+                    return Ok(Some(set));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    fn push(&self, mut set: ConfigSet, keep: usize) -> Result<Option<u64>> {
+        try!(fs::create_dir_all(&self.path));
+        let mut ids = try!(self.ids());
+        let last = ids.last().cloned();
+        if let Some(last) = last {
+            if let Some(previous) = try!(self.get(last)) {
+                if previous.same_as(&set) {
+                    return Ok(None);
+                }
+            }
+        }
+        set.id = last.unwrap_or(0) + 1;
+        let path = self.set_path(set.id);
+        let tmp = path.with_extension("json.write");
+        {
+            let mut file = try!(File::create(&tmp));
+            try!(file.write_all(try!(json::encode(&set)).as_bytes()));
+        }
+        try!(fs::rename(&tmp, &path));
+        ids.push(set.id);
+
+        while ids.len() > keep {
+            let oldest = ids.remove(0);
+            try!(fs::remove_file(self.set_path(oldest)));
+        }
+        Ok(Some(set.id))
+    }
+
+    fn set_path(&self, id: u64) -> PathBuf {
+        self.path.join(format!("{}.json", id))
+    }
+}
+
+fn read(path: &Path) -> Result<String> {
+    let mut file = try!(File::open(path));
+    let mut contents = String::new();
+    try!(file.read_to_string(&mut contents));
+    Ok(contents)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use tempdir::TempDir;
+
+    use super::{ConfigSet, History};
+
+    fn set(gossip_version: Option<u64>, contents: &str) -> ConfigSet {
+        let mut files = BTreeMap::new();
+        files.insert("redis.conf".to_string(), contents.to_string());
+        ConfigSet {
+            id: 0,
+            gossip_version: gossip_version,
+            rendered_at: 0,
+            gossip_toml: None,
+            config_toml: String::new(),
+            files: files,
+        }
+    }
+
+    #[test]
+    fn numbers_sets_in_order() {
+        let tmp = TempDir::new("config_history").unwrap();
+        let history = History::new(tmp.path());
+        assert_eq!(history.push(set(None, "a"), 10).unwrap(), Some(1));
+        assert_eq!(history.push(set(Some(1), "b"), 10).unwrap(), Some(2));
+        let sets = history.list().unwrap();
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets[1].id, 2);
+        assert_eq!(sets[1].gossip_version, Some(1));
+    }
+
+    #[test]
+    fn skips_repeats() {
+        let tmp = TempDir::new("config_history").unwrap();
+        let history = History::new(tmp.path());
+        assert_eq!(history.push(set(Some(1), "a"), 10).unwrap(), Some(1));
+        assert_eq!(history.push(set(Some(1), "a"), 10).unwrap(), None);
+        assert_eq!(history.push(set(Some(2), "a"), 10).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn keeps_the_newest_sets() {
+        let tmp = TempDir::new("config_history").unwrap();
+        let history = History::new(tmp.path());
+        for (i, contents) in ["a", "b", "c", "d"].iter().enumerate() {
+            history.push(set(Some(i as u64), contents), 2).unwrap();
+        }
+        assert_eq!(history.ids().unwrap(), vec![3, 4]);
+        assert!(history.get(1).unwrap().is_none());
+    }
+
+    #[test]
+    fn finds_sets_by_gossip_version() {
+        let tmp = TempDir::new("config_history").unwrap();
+        let history = History::new(tmp.path());
+        history.push(set(Some(1), "a"), 10).unwrap();
+        history.push(set(Some(2), "b"), 10).unwrap();
+        history.push(set(Some(1), "c"), 10).unwrap();
+        assert_eq!(history.for_gossip_version(1).unwrap().unwrap().id, 3);
+        assert!(history.for_gossip_version(5).unwrap().is_none());
+    }
+}
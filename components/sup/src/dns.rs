@@ -0,0 +1,555 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! A small DNS server that answers for service groups from the census, so software that can't be
+//! configured through templates can still find its peers.
+//!
+//! Names live under `hab`:
+//!
+//! * `redis.default.hab`: an A record for every alive member of `redis.default`. Groups with dots
+//!   in them work too: `myapp.myapp.prod.hab`
+//! * `healthy.redis.default.hab`: only the alive members that are not failing their health check
+//! * `leader.redis.default.hab`: only the alive leader
+//! * `<id>.redis.default.hab`: the member with that census entry id, if it is alive
+//! * `_redis._tcp.redis.default.hab`: an SRV record for every port every alive member exposes,
+//!   pointing at its `<id>` name, with its A record alongside. The `healthy` and `leader` names
+//!   work the same way: `_redis._tcp.leader.redis.default.hab`.
+//!
+//! Names are matched without regard to ASCII case, and answers echo the name exactly as it was
+//! asked for. Answers come straight from the census, so they have a short TTL. Only UDP queries
+//! are answered; answers that don't fit in a 512 byte message are truncated.
+
+use std::ascii::AsciiExt;
+use std::net::{Ipv4Addr, SocketAddrV4, UdpSocket};
+use std::str::{self, FromStr};
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+use census::{Census, CensusEntry, CensusList};
+use error::Result;
+
+static LOGKEY: &'static str = "DN";
+
+/// The domain we answer for
+pub const DOMAIN: &'static str = "hab";
+
+/// How long resolvers may cache our answers, in seconds
+pub const TTL: u32 = 5;
+
+/// The largest message we send over UDP
+const MAX_MESSAGE_LEN: usize = 512;
+const HEADER_LEN: usize = 12;
+/// A compression pointer to the name in the question, which always follows the header
+const QUESTION_NAME_POINTER: u16 = 0xc000 | HEADER_LEN as u16;
+
+const TYPE_A: u16 = 1;
+const TYPE_SRV: u16 = 33;
+const TYPE_ANY: u16 = 255;
+const CLASS_IN: u16 = 1;
+const CLASS_ANY: u16 = 255;
+
+const FLAG_RESPONSE: u16 = 0x8000;
+const FLAG_AUTHORITATIVE: u16 = 0x0400;
+const FLAG_TRUNCATED: u16 = 0x0200;
+const FLAG_RECURSION_DESIRED: u16 = 0x0100;
+
+/// The response codes we send
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rcode {
+    NoError = 0,
+    FormErr = 1,
+    NxDomain = 3,
+    NotImp = 4,
+    Refused = 5,
+}
+
+/// The question in a query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Question {
+    /// The labels of the name, byte for byte as they were asked for
+    pub labels: Vec<Vec<u8>>,
+    pub qtype: u16,
+    pub qclass: u16,
+}
+
+/// Which members of a service group a name refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Filter {
+    Alive,
+    Healthy,
+    Leader,
+    Member(String),
+}
+
+/// The data of a record we answer with
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RData {
+    A(Ipv4Addr),
+    Srv(u16, Vec<Vec<u8>>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Record {
+    labels: Vec<Vec<u8>>,
+    data: RData,
+}
+
+/// Start answering DNS queries on `listen`, from the census.
+///
+/// # Errors
+///
+/// * If the socket can't be bound
+/// * If the thread can't be started
+pub fn start(listen: SocketAddrV4, census_list: Arc<RwLock<CensusList>>) -> Result<()> {
+    let socket = try!(UdpSocket::bind(listen));
+    outputln!("Answering DNS queries for .{} on {}", DOMAIN, listen);
+    try!(thread::Builder::new().name("dns".to_string()).spawn(move || {
+        let mut buf = [0u8; MAX_MESSAGE_LEN];
+        loop {
+            let (len, from) = match socket.recv_from(&mut buf) {
+                Ok(received) => received,
+                Err(e) => {
+                    debug!("Failed to receive a DNS query: {}", e);
+                    continue;
+                }
+            };
+            let reply = {
+                let cl = census_list.read().unwrap();
+                answer(&buf[..len], &cl)
+            };
+            if let Some(reply) = reply {
+                if let Err(e) = socket.send_to(&reply, from) {
+                    debug!("Failed to answer a DNS query from {}: {}", from, e);
+                }
+            }
+        }
+    }));
+    Ok(())
+}
+
+/// Answer a query from the census. Returns `None` if the packet is too short to be a query, or
+/// is itself a response.
+pub fn answer(packet: &[u8], census_list: &CensusList) -> Option<Vec<u8>> {
+    if packet.len() < HEADER_LEN {
+        return None;
+    }
+    let id = read_u16(packet, 0);
+    let flags = read_u16(packet, 2);
+    if flags & FLAG_RESPONSE != 0 {
+        return None;
+    }
+    let flags = flags & FLAG_RECURSION_DESIRED;
+    // The opcode is the four bits after the response bit; we only answer standard queries.
+    if (read_u16(packet, 2) >> 11) & 0xf != 0 {
+        return Some(encode(id, flags, None, Rcode::NotImp, &[], &[]));
+    }
+    let question = match parse_question(packet) {
+        Ok(question) => question,
+        Err(rcode) => return Some(encode(id, flags, None, rcode, &[], &[])),
+    };
+    let (rcode, answers, additional) = match lookup(&question, census_list) {
+        Ok((answers, additional)) => (Rcode::NoError, answers, additional),
+        Err(rcode) => (rcode, vec![], vec![]),
+    };
+    Some(encode(id, flags, Some(&question), rcode, &answers, &additional))
+}
+
+/// Parse the single question in a query.
+pub fn parse_question(packet: &[u8]) -> ::std::result::Result<Question, Rcode> {
+    if packet.len() < HEADER_LEN || read_u16(packet, 4) != 1 {
+        return Err(Rcode::FormErr);
+    }
+    let mut labels = Vec::new();
+    let mut pos = HEADER_LEN;
+    loop {
+        let len = match packet.get(pos) {
+            Some(&len) => len as usize,
+            None => return Err(Rcode::FormErr),
+        };
+        pos += 1;
+        if len == 0 {
+            break;
+        }
+        // Compression pointers and extended label types have no business in a question
+        if len > 63 || pos + len > packet.len() {
+            return Err(Rcode::FormErr);
+        }
+        labels.push(packet[pos..pos + len].to_vec());
+        pos += len;
+    }
+    if pos + 4 > packet.len() {
+        return Err(Rcode::FormErr);
+    }
+    Ok(Question {
+        labels: labels,
+        qtype: read_u16(packet, pos),
+        qclass: read_u16(packet, pos + 2),
+    })
+}
+
+/// Find the records that answer a question, and the records that go alongside them.
+fn lookup(question: &Question,
+          census_list: &CensusList)
+          -> ::std::result::Result<(Vec<Record>, Vec<Record>), Rcode> {
+    if question.qclass != CLASS_IN && question.qclass != CLASS_ANY {
+        return Err(Rcode::Refused);
+    }
+    let labels = &question.labels[..];
+    match labels.last() {
+        Some(domain) if domain.eq_ignore_ascii_case(DOMAIN.as_bytes()) => {}
+        _ => return Err(Rcode::Refused),
+    }
+    let labels = &labels[..labels.len() - 1];
+    let (srv, target) = if labels.len() > 2 && labels[0].starts_with(b"_") &&
+                           (labels[1].eq_ignore_ascii_case(b"_tcp") ||
+                            labels[1].eq_ignore_ascii_case(b"_udp")) {
+        (true, &labels[2..])
+    } else {
+        (false, labels)
+    };
+    // Groups may have dots in them, so the whole name is tried as a service group before the
+    // first label is taken as a filter.
+    let (filter, service_group, census) = match find_census(target, census_list) {
+        Some(census) => (Filter::Alive, target, census),
+        None if target.len() > 2 => {
+            let filter = if target[0].eq_ignore_ascii_case(b"healthy") {
+                Filter::Healthy
+            } else if target[0].eq_ignore_ascii_case(b"leader") {
+                Filter::Leader
+            } else {
+                match str::from_utf8(&target[0]) {
+                    Ok(id) => Filter::Member(id.to_ascii_lowercase()),
+                    Err(_) => return Err(Rcode::NxDomain),
+                }
+            };
+            match find_census(&target[1..], census_list) {
+                Some(census) => (filter, &target[1..], census),
+                None => return Err(Rcode::NxDomain),
+            }
+        }
+        None => return Err(Rcode::NxDomain),
+    };
+    let mut entries: Vec<&CensusEntry> = census.values()
+        .filter(|entry| entry.alive)
+        .filter(|entry| match filter {
+            Filter::Alive => true,
+            Filter::Healthy => entry.is_healthy(),
+            Filter::Leader => entry.leader,
+            Filter::Member(ref id) => entry.id.simple().to_string() == *id,
+        })
+        .collect();
+    if let Filter::Member(ref id) = filter {
+        if entries.is_empty() &&
+           !census.values().any(|entry| entry.id.simple().to_string() == *id) {
+            return Err(Rcode::NxDomain);
+        }
+    }
+    entries.sort_by_key(|entry| entry.id.simple().to_string());
+
+    let mut answers = Vec::new();
+    let mut additional = Vec::new();
+    if srv {
+        if question.qtype != TYPE_SRV && question.qtype != TYPE_ANY {
+            return Ok((answers, additional));
+        }
+        for entry in entries {
+            let ip = match Ipv4Addr::from_str(&entry.ip) {
+                Ok(ip) => ip,
+                Err(_) => continue,
+            };
+            let mut member_name = vec![entry.id.simple().to_string().into_bytes()];
+            member_name.extend_from_slice(service_group);
+            member_name.push(DOMAIN.as_bytes().to_vec());
+            for port in ports(entry) {
+                answers.push(Record {
+                    labels: question.labels.clone(),
+                    data: RData::Srv(port, member_name.clone()),
+                });
+            }
+            additional.push(Record {
+                labels: member_name,
+                data: RData::A(ip),
+            });
+        }
+    } else if question.qtype == TYPE_A || question.qtype == TYPE_ANY {
+        for entry in entries {
+            if let Ok(ip) = Ipv4Addr::from_str(&entry.ip) {
+                answers.push(Record {
+                    labels: question.labels.clone(),
+                    data: RData::A(ip),
+                });
+            }
+        }
+    }
+    Ok((answers, additional))
+}
+
+/// The census of the service group the labels name, ignoring ASCII case.
+fn find_census<'a>(labels: &[Vec<u8>], census_list: &'a CensusList) -> Option<&'a Census> {
+    let name = labels.join(&b'.');
+    census_list.iter()
+        .find(|&(service_group, _)| service_group.as_bytes().eq_ignore_ascii_case(&name))
+        .map(|(_, census)| census)
+}
+
+/// The ports a member exposes: its port, then the rest of the ports its package exposes.
+fn ports(entry: &CensusEntry) -> Vec<u16> {
+    let mut ports: Vec<u16> = Vec::new();
+    let exposes = entry.exposes.as_ref().map(|e| &e[..]).unwrap_or(&[]);
+    for port in entry.port.iter().chain(exposes.iter()) {
+        if let Ok(port) = port.parse::<u16>() {
+            if !ports.contains(&port) {
+                ports.push(port);
+            }
+        }
+    }
+    ports
+}
+
+/// Encode a response. Answers that would take the message past `MAX_MESSAGE_LEN` are left out,
+/// and the message marked as truncated; additional records that don't fit are left out quietly.
+fn encode(id: u16,
+          flags: u16,
+          question: Option<&Question>,
+          rcode: Rcode,
+          answers: &[Record],
+          additional: &[Record])
+          -> Vec<u8> {
+    let mut flags = flags | FLAG_RESPONSE | FLAG_AUTHORITATIVE | rcode as u16;
+    let mut body = Vec::new();
+    if let Some(question) = question {
+        write_name(&mut body, &question.labels);
+        write_u16(&mut body, question.qtype);
+        write_u16(&mut body, question.qclass);
+    }
+    let mut ancount = 0;
+    for record in answers {
+        let encoded = encode_record(record, question);
+        if HEADER_LEN + body.len() + encoded.len() > MAX_MESSAGE_LEN {
+            flags |= FLAG_TRUNCATED;
+            break;
+        }
+        body.extend_from_slice(&encoded);
+        ancount += 1;
+    }
+    let mut arcount = 0;
+    if flags & FLAG_TRUNCATED == 0 {
+        for record in additional {
+            let encoded = encode_record(record, question);
+            if HEADER_LEN + body.len() + encoded.len() > MAX_MESSAGE_LEN {
+                break;
+            }
+            body.extend_from_slice(&encoded);
+            arcount += 1;
+        }
+    }
+    let mut message = Vec::with_capacity(HEADER_LEN + body.len());
+    write_u16(&mut message, id);
+    write_u16(&mut message, flags);
+    write_u16(&mut message, if question.is_some() { 1 } else { 0 });
+    write_u16(&mut message, ancount);
+    write_u16(&mut message, 0);
+    write_u16(&mut message, arcount);
+    message.extend_from_slice(&body);
+    message
+}
+
+fn encode_record(record: &Record, question: Option<&Question>) -> Vec<u8> {
+    let mut out = Vec::new();
+    match question {
+        Some(question) if question.labels == record.labels => {
+            write_u16(&mut out, QUESTION_NAME_POINTER)
+        }
+        _ => write_name(&mut out, &record.labels),
+    }
+    let mut rdata = Vec::new();
+    let rtype = match record.data {
+        RData::A(ip) => {
+            rdata.extend_from_slice(&ip.octets());
+            TYPE_A
+        }
+        RData::Srv(port, ref target) => {
+            // Priority and weight; every member is as good as any other
+            write_u16(&mut rdata, 0);
+            write_u16(&mut rdata, 0);
+            write_u16(&mut rdata, port);
+            write_name(&mut rdata, target);
+            TYPE_SRV
+        }
+    };
+    write_u16(&mut out, rtype);
+    write_u16(&mut out, CLASS_IN);
+    write_u16(&mut out, (TTL >> 16) as u16);
+    write_u16(&mut out, TTL as u16);
+    write_u16(&mut out, rdata.len() as u16);
+    out.extend_from_slice(&rdata);
+    out
+}
+
+fn write_name(out: &mut Vec<u8>, labels: &[Vec<u8>]) {
+    for label in labels {
+        out.push(label.len() as u8);
+        out.extend_from_slice(label);
+    }
+    out.push(0);
+}
+
+fn write_u16(out: &mut Vec<u8>, value: u16) {
+    out.push((value >> 8) as u8);
+    out.push(value as u8);
+}
+
+fn read_u16(packet: &[u8], pos: usize) -> u16 {
+    (packet[pos] as u16) << 8 | packet[pos + 1] as u16
+}
+
+#[cfg(test)]
+mod test {
+    use census::{Census, CensusEntry, CensusList};
+    use gossip::member::MemberId;
+
+    use super::{answer, parse_question, read_u16, write_name, write_u16, Rcode, HEADER_LEN,
+                TYPE_A, TYPE_SRV, CLASS_IN};
+
+    fn entry(ip: &str, leader: bool) -> CensusEntry {
+        let mut ce = CensusEntry::new("redis", "default", MemberId::new_v4());
+        ce.ip = ip.to_string();
+        ce.port(Some("6379".to_string()));
+        ce.exposes(Some(vec!["6379".to_string(), "16379".to_string()]));
+        ce.leader(leader);
+        ce
+    }
+
+    fn census_list() -> CensusList {
+        let mut census = Census::new(entry("10.0.0.1", true));
+        census.add(entry("10.0.0.2", false));
+        let mut dead = entry("10.0.0.3", false);
+        dead.set_confirmed();
+        census.add(dead);
+        CensusList::new(census)
+    }
+
+    fn query(name: &str, qtype: u16) -> Vec<u8> {
+        let mut packet = Vec::new();
+        for value in &[0xbeef, 0x0100, 1, 0, 0, 0] {
+            write_u16(&mut packet, *value);
+        }
+        let labels: Vec<Vec<u8>> = name.split('.').map(|l| l.as_bytes().to_vec()).collect();
+        write_name(&mut packet, &labels);
+        write_u16(&mut packet, qtype);
+        write_u16(&mut packet, CLASS_IN);
+        packet
+    }
+
+    fn rcode(reply: &[u8]) -> u16 {
+        read_u16(reply, 2) & 0xf
+    }
+
+    fn ancount(reply: &[u8]) -> u16 {
+        read_u16(reply, 6)
+    }
+
+    fn arcount(reply: &[u8]) -> u16 {
+        read_u16(reply, 10)
+    }
+
+    #[test]
+    fn parses_questions() {
+        let question = parse_question(&query("Redis.Default.HAB", TYPE_A)).unwrap();
+        assert_eq!(question.labels,
+                   vec![b"Redis".to_vec(), b"Default".to_vec(), b"HAB".to_vec()]);
+        assert_eq!(question.qtype, TYPE_A);
+        assert_eq!(parse_question(&query("redis", TYPE_A)[..14]), Err(Rcode::FormErr));
+    }
+
+    #[test]
+    fn answers_alive_members() {
+        let reply = answer(&query("redis.default.hab", TYPE_A), &census_list()).unwrap();
+        assert_eq!(read_u16(&reply, 0), 0xbeef);
+        assert_eq!(rcode(&reply), Rcode::NoError as u16);
+        assert_eq!(ancount(&reply), 2);
+        assert!(!reply.windows(4).any(|w| w == [10, 0, 0, 3]));
+    }
+
+    #[test]
+    fn ignores_case() {
+        let reply = answer(&query("Redis.Default.HAB", TYPE_A), &census_list()).unwrap();
+        assert_eq!(ancount(&reply), 2);
+        let reply = answer(&query("LEADER.redis.default.hab", TYPE_A), &census_list()).unwrap();
+        assert_eq!(ancount(&reply), 1);
+    }
+
+    #[test]
+    fn answers_groups_with_upper_case_names() {
+        let mut census = Census::new(CensusEntry::new("redis", "Prod", MemberId::new_v4()));
+        census.me_mut().ip = "10.0.0.9".to_string();
+        let cl = CensusList::new(census);
+        let reply = answer(&query("redis.Prod.hab", TYPE_A), &cl).unwrap();
+        assert_eq!(ancount(&reply), 1);
+        let reply = answer(&query("redis.prod.hab", TYPE_A), &cl).unwrap();
+        assert_eq!(ancount(&reply), 1);
+    }
+
+    #[test]
+    fn echoes_the_question_exactly() {
+        let mut packet = query("redis.default.hab", TYPE_A);
+        // A label that is not UTF-8 is echoed as it was sent
+        packet[HEADER_LEN + 1] = 0xff;
+        let reply = answer(&packet, &census_list()).unwrap();
+        assert_eq!(&reply[HEADER_LEN..packet.len()], &packet[HEADER_LEN..]);
+        let packet = query("rEdIs.DeFaUlT.hAb", TYPE_A);
+        let reply = answer(&packet, &census_list()).unwrap();
+        assert_eq!(&reply[HEADER_LEN..packet.len()], &packet[HEADER_LEN..]);
+    }
+
+    #[test]
+    fn answers_the_leader() {
+        let reply = answer(&query("leader.redis.default.hab", TYPE_A), &census_list()).unwrap();
+        assert_eq!(ancount(&reply), 1);
+        assert_eq!(&reply[reply.len() - 4..], &[10, 0, 0, 1]);
+    }
+
+    #[test]
+    fn answers_srv_with_every_port() {
+        let reply = answer(&query("_redis._tcp.redis.default.hab", TYPE_SRV),
+                           &census_list())
+            .unwrap();
+        assert_eq!(ancount(&reply), 4);
+        assert_eq!(arcount(&reply), 2);
+    }
+
+    #[test]
+    fn groups_with_dots() {
+        let mut census = Census::new(CensusEntry::new("myapp", "myapp.prod", MemberId::new_v4()));
+        census.me_mut().ip = "10.0.0.9".to_string();
+        census.me_mut().leader(true);
+        let cl = CensusList::new(census);
+        let reply = answer(&query("myapp.myapp.prod.hab", TYPE_A), &cl).unwrap();
+        assert_eq!(ancount(&reply), 1);
+        let reply = answer(&query("leader.myapp.myapp.prod.hab", TYPE_A), &cl).unwrap();
+        assert_eq!(ancount(&reply), 1);
+    }
+
+    #[test]
+    fn unknown_names() {
+        let cl = census_list();
+        let reply = answer(&query("mysql.default.hab", TYPE_A), &cl).unwrap();
+        assert_eq!(rcode(&reply), Rcode::NxDomain as u16);
+        let reply = answer(&query("example.com", TYPE_A), &cl).unwrap();
+        assert_eq!(rcode(&reply), Rcode::Refused as u16);
+        let reply = answer(&query("redis.default.hab", TYPE_SRV), &cl).unwrap();
+        assert_eq!(rcode(&reply), Rcode::NoError as u16);
+        assert_eq!(ancount(&reply), 0);
+    }
+
+    #[test]
+    fn ignores_responses() {
+        let mut packet = query("redis.default.hab", TYPE_A);
+        packet[2] |= 0x80;
+        assert!(answer(&packet, &census_list()).is_none());
+    }
+}
@@ -24,10 +24,13 @@ use std::cmp::PartialEq;
 use std::fmt;
 use std::ops::{Deref, DerefMut};
 
+use common;
+use common::message::gossip as proto;
+use common::wire_message::Protobuf;
 use uuid::Uuid;
 
 use gossip::member::MemberId;
-use gossip::rumor::{RumorList, Rumor};
+use gossip::rumor::{id_from_proto, RumorList, Rumor};
 
 pub type ElectionId = Uuid;
 
@@ -198,12 +201,68 @@ impl Election {
     }
 }
 
+impl ElectionStatus {
+    pub fn to_proto(&self) -> proto::ElectionStatus {
+        match *self {
+            ElectionStatus::Running => proto::ElectionStatus::RUNNING,
+            ElectionStatus::Finished => proto::ElectionStatus::FINISHED,
+        }
+    }
+
+    pub fn from_proto(status: proto::ElectionStatus) -> ElectionStatus {
+        match status {
+            proto::ElectionStatus::RUNNING => ElectionStatus::Running,
+            proto::ElectionStatus::FINISHED => ElectionStatus::Finished,
+        }
+    }
+}
+
+impl Protobuf for Election {
+    type Message = proto::Election;
+
+    fn to_proto(&self) -> proto::Election {
+        let mut election = proto::Election::new();
+        election.set_id(self.id.as_bytes().to_vec());
+        election.set_service(self.service.clone());
+        election.set_group(self.group.clone());
+        election.set_leader_id(self.leader_id.as_bytes().to_vec());
+        election.set_suitability(self.suitability);
+        for vote in self.votes.iter() {
+            election.mut_votes().push(vote.as_bytes().to_vec());
+        }
+        election.set_status(self.status.to_proto());
+        election.set_term(self.term);
+        election
+    }
+
+    fn from_proto(mut election: proto::Election) -> common::Result<Election> {
+        let mut votes = HashSet::new();
+        for vote in election.get_votes().iter() {
+            votes.insert(try!(id_from_proto(vote)));
+        }
+        Ok(Election {
+            id: try!(id_from_proto(election.get_id())),
+            service: election.take_service(),
+            group: election.take_group(),
+            leader_id: try!(id_from_proto(election.get_leader_id())),
+            suitability: election.get_suitability(),
+            votes: votes,
+            status: ElectionStatus::from_proto(election.get_status()),
+            term: election.get_term(),
+        })
+    }
+}
+
 /// The election list tracks elections across service groups.
 #[derive(Debug)]
 pub struct ElectionList {
     pub elections: HashMap<String, Election>,
     my_service_group: String,
     pub member_id: MemberId,
+    /// Our suitability to lead, used in every election we start
+    pub suitability: u32,
+    /// True if an election has changed since the main loop last looked
+    needs_write: bool,
 }
 
 impl ElectionList {
@@ -212,9 +271,21 @@ impl ElectionList {
             elections: HashMap::new(),
             my_service_group: service_group,
             member_id: member_id,
+            suitability: 0,
+            needs_write: false,
         }
     }
 
+    /// True if an election has changed since `written` was last called.
+    pub fn needs_write(&self) -> bool {
+        self.needs_write
+    }
+
+    /// Marks the changes to the elections as seen.
+    pub fn written(&mut self) {
+        self.needs_write = false;
+    }
+
     /// Returns this supervisors election
     pub fn election(&self) -> Option<&Election> {
         self.elections.get(&self.my_service_group)
@@ -226,7 +297,15 @@ impl ElectionList {
     }
 
     /// Update an Election based on a Remote Election. Called from gossip::server.
-    pub fn process(&mut self, mut remote_election: Election) -> bool {
+    pub fn process(&mut self, remote_election: Election) -> bool {
+        let changed = self.merge(remote_election);
+        if changed {
+            self.needs_write = true;
+        }
+        changed
+    }
+
+    fn merge(&mut self, mut remote_election: Election) -> bool {
         let mut updated_term = false;
         if (remote_election.term > self.current_term_for(&remote_election)) &&
            (self.my_service_group == remote_election.service_group()) {
@@ -280,8 +359,11 @@ impl ElectionList {
             .get(&format!("{}.{}", service, group))
             .map_or(0, |e| e.term.clone());
 
-        // Eventually, you need to actually get the suitability here, or in election::new
-        Election::new(service, group, self.member_id.clone(), 0, current_term)
+        Election::new(service,
+                      group,
+                      self.member_id.clone(),
+                      self.suitability,
+                      current_term)
     }
 
     /// Finish the election
@@ -96,6 +96,7 @@ pub enum Error {
     DepotClient(depot_client::Error),
     ExecCommandNotFound(String),
     FileNotFound(String),
+    GroupNotFound(String),
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
     HandlebarsTemplateFileError(handlebars::TemplateFileError),
@@ -106,9 +107,15 @@ pub enum Error {
     /// TODO: once discovery/etcd.rs is purged, this error can be removed
     HyperError(hyper::error::Error),
     InvalidBinding(String),
+    InvalidConfig(String),
+    InvalidDeparture(String),
     InvalidKeyParameter(String),
+    InvalidLimit(String),
     InvalidPidFile,
+    InvalidSchema(String),
     InvalidServiceGroupString(String),
+    InvalidSignal(String),
+    InvalidSuitability(String),
     Io(io::Error),
     IPFailed,
     JsonDecode(json::DecoderError),
@@ -130,6 +137,7 @@ pub enum Error {
     TryRecvError(mpsc::TryRecvError),
     UnknownTopology(String),
     UnpackFailed,
+    UserNotFound(String),
     UuidParseError(uuid::ParseError),
 }
 
@@ -154,6 +162,7 @@ impl fmt::Display for SupError {
             Error::DepotClient(ref err) => format!("{}", err),
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
             Error::HealthCheck(ref e) => format!("Health Check failed: {}", e),
+            Error::GroupNotFound(ref g) => format!("No GID for group '{}' could be found", g),
             Error::HookFailed(ref t, ref e, ref o) => {
                 format!("Hook failed to run: {}, {}, {}", t, e, o)
             }
@@ -165,10 +174,23 @@ impl fmt::Display for SupError {
             Error::InvalidKeyParameter(ref e) => {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
+            Error::InvalidConfig(ref e) => {
+                format!("Configuration does not match the package's schema: {}", e)
+            }
+            Error::InvalidDeparture(ref e) => format!("Invalid departure for member {}", e),
+            Error::InvalidLimit(ref e) => {
+                format!("Invalid limit: {}; use a number with an optional K, M, G or T suffix",
+                        e)
+            }
             Error::InvalidPidFile => format!("Invalid child process PID file"),
+            Error::InvalidSchema(ref e) => format!("Invalid configuration schema: {}", e),
             Error::InvalidServiceGroupString(ref e) => {
                 format!("Invalid service group string: {}", e)
             }
+            Error::InvalidSignal(ref e) => format!("Invalid signal: {}", e),
+            Error::InvalidSuitability(ref e) => {
+                format!("Suitability hook must print a non-negative integer, got: {}", e)
+            }
             Error::Io(ref err) => format!("{}", err),
             Error::IPFailed => format!("Failed to discover this hosts outbound IP address"),
             Error::JsonDecode(ref e) => format!("JSON decoding error: {}", e),
@@ -212,6 +234,7 @@ impl fmt::Display for SupError {
             Error::TryRecvError(ref err) => format!("{}", err),
             Error::UnknownTopology(ref t) => format!("Unknown topology {}!", t),
             Error::UnpackFailed => format!("Failed to unpack a package"),
+            Error::UserNotFound(ref u) => format!("No UID for user '{}' could be found", u),
             Error::UuidParseError(ref e) => format!("Uuid Parse Error: {:?}", e),
         };
         let cstring = Red.bold().paint(content).to_string();
@@ -240,16 +263,25 @@ impl error::Error for SupError {
             Error::DbInvalidPath => "A bad filepath was provided for an internal datastore",
             Error::DepotClient(ref err) => err.description(),
             Error::FileNotFound(_) => "File not found",
+            Error::GroupNotFound(_) => "No matching GID for group found",
             Error::HealthCheck(_) => "Health Check returned an unknown status code",
             Error::HookFailed(_, _, _) => "Hook failed to run",
             Error::HTTP(_) => "Received an HTTP error",
             Error::HyperError(ref err) => err.description(),
             Error::InvalidBinding(_) => "Invalid binding parameter",
+            Error::InvalidConfig(_) => "Configuration does not match the package's schema",
             Error::InvalidKeyParameter(_) => "Key parameter error",
+            Error::InvalidDeparture(_) => "Departure signature does not match the departing member",
+            Error::InvalidLimit(_) => "Limits must be a number with an optional K, M, G or T suffix",
             Error::InvalidPidFile => "Invalid child process PID file",
+            Error::InvalidSchema(_) => "Invalid configuration schema",
             Error::InvalidServiceGroupString(_) => {
                 "Service group strings must be in service.group format (example: redis.default)"
             }
+            Error::InvalidSignal(_) => {
+                "Signal names must be one of HUP, INT, QUIT, KILL, ALRM, TERM, USR1 or USR2"
+            }
+            Error::InvalidSuitability(_) => "Suitability hook must print a non-negative integer",
             Error::Io(ref err) => err.description(),
             Error::IPFailed => "Failed to discover the outbound IP address",
             Error::JsonDecode(_) => "JSON decoding error: {:?}",
@@ -278,6 +310,7 @@ impl error::Error for SupError {
             Error::TryRecvError(_) => "A channel failed to recieve a response",
             Error::UnknownTopology(_) => "Unknown topology",
             Error::UnpackFailed => "Failed to unpack a package",
+            Error::UserNotFound(_) => "No matching UID for user found",
             Error::UuidParseError(_) => "Uuid Parse Error",
         }
     }
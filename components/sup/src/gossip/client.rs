@@ -8,26 +8,31 @@
 //! The Gossip Client.
 //!
 //! This module takes a `UtpSocket`, and lets you send and receive messages with it. Messages are
-//! encoded with json.
+//! sent in the wire version the client is set to, which is JSON unless it is told otherwise, and
+//! received in any wire version we speak. Rumors the wire version can't carry are left out of what
+//! we send. See `common::wire_message` for the versions and the rules for changing what we send.
+//!
+//! If we have a ring key, messages are encrypted with it, and decrypted with whichever revision of
+//! it they were encrypted with; see `gossip::keyring`.
 //!
 
 use std::net::ToSocketAddrs;
-use std::str;
 
-use common::wire_message::WireMessage;
-use hcore::crypto::SymKey;
-use rustc_serialize::json;
+use common::wire_message::{WireMessage, WIRE_VERSION_JSON};
 use utp::UtpSocket;
 
 use error::Result;
+use gossip::keyring::Keyring;
 use gossip::rumor::{Protocol, Peer, RumorList};
+use gossip::sync::Digest;
 
 pub const BUFFER_SIZE: usize = 10000;
 
 /// A Gossip Client.
 pub struct Client<'a> {
     pub socket: UtpSocket,
-    ring_key: Option<&'a SymKey>,
+    keyring: &'a Keyring,
+    wire_version: u16,
 }
 
 impl<'a> Client<'a> {
@@ -36,22 +41,31 @@ impl<'a> Client<'a> {
     /// # Errors
     ///
     /// * If we cannot connect the UTP socket
-    pub fn new<A: ToSocketAddrs>(dst: A, ring_key: Option<&'a SymKey>) -> Result<Client> {
+    pub fn new<A: ToSocketAddrs>(dst: A, keyring: &'a Keyring) -> Result<Client> {
         let socket = try!(UtpSocket::connect(dst));
         Ok(Client {
             socket: socket,
-            ring_key: ring_key,
+            keyring: keyring,
+            wire_version: WIRE_VERSION_JSON,
         })
     }
 
     /// Create a new client from a `UtpSocket`
-    pub fn from_socket(socket: UtpSocket, ring_key: Option<&'a SymKey>) -> Client {
+    pub fn from_socket(socket: UtpSocket, keyring: &'a Keyring) -> Client {
         Client {
             socket: socket,
-            ring_key: ring_key,
+            keyring: keyring,
+            wire_version: WIRE_VERSION_JSON,
         }
     }
 
+    /// Set the wire version we send messages in. It should come from
+    /// `wire_message::negotiate`, so the remote can read it.
+    pub fn set_wire_version(&mut self, wire_version: u16) -> &mut Self {
+        self.wire_version = wire_version;
+        self
+    }
+
     /// Send a ping.
     ///
     /// # Errors
@@ -87,6 +101,30 @@ impl<'a> Client<'a> {
         Ok(())
     }
 
+    /// Send a Sync.
+    ///
+    /// # Errors
+    ///
+    /// * If we cannot send a Sync
+    pub fn sync(&mut self, my_peer: Peer, digest: Digest) -> Result<()> {
+        try!(self.send_message(Protocol::Sync(my_peer, digest)));
+        Ok(())
+    }
+
+    /// Send a SyncAck.
+    ///
+    /// # Errors
+    ///
+    /// * If we cannot send a SyncAck
+    pub fn sync_ack(&mut self,
+                    my_peer: Peer,
+                    rumors_for_remote: RumorList,
+                    digest: Digest)
+                    -> Result<()> {
+        try!(self.send_message(Protocol::SyncAck(my_peer, rumors_for_remote, digest)));
+        Ok(())
+    }
+
     /// Receives a message.
     ///
     /// # Errors
@@ -95,26 +133,27 @@ impl<'a> Client<'a> {
     /// * We cannot decode the data into a `gossip::message::Protocol`
     pub fn recv_message(&mut self) -> Result<Protocol> {
         let mut buf = [0u8; BUFFER_SIZE];
-        let mut json_str = String::new();
+        let mut bytes = Vec::new();
         let mut keep_reading_buffer = true;
 
         while keep_reading_buffer {
             let (amt, _src) = try!(self.socket.recv_from(&mut buf));
             match amt {
                 0 => keep_reading_buffer = false,
-                amt => {
-                    let partial_str = try!(str::from_utf8(&buf[..amt]));
-                    json_str.push_str(partial_str);
-                }
+                amt => bytes.extend_from_slice(&buf[..amt]),
             }
         }
 
-        debug!("Received protocol ({:?}): {}",
+        let wire_msg = try!(WireMessage::from_bytes(&bytes));
+        debug!("Received protocol ({:?}), wire version {}, {} bytes",
                self.socket.peer_addr(),
-               json_str);
-
-        let wire_msg: WireMessage = try!(json::decode(&json_str));
-        Ok(try!(wire_msg.msg(self.ring_key)))
+               wire_msg.wire_version(),
+               bytes.len());
+        let ring_key = match wire_msg.key_name_with_rev() {
+            Some(name_with_rev) => self.keyring.decrypt_key(name_with_rev),
+            None => None,
+        };
+        Ok(try!(wire_msg.versioned_msg(ring_key.as_ref())))
     }
 
     /// Send a message.
@@ -123,16 +162,18 @@ impl<'a> Client<'a> {
     ///
     /// * We cannot encode the `Message`
     /// * We fail to send the encoded buffer to the remote
-    pub fn send_message(&mut self, msg: Protocol) -> Result<()> {
+    pub fn send_message(&mut self, mut msg: Protocol) -> Result<()> {
+        msg.downgrade_for(self.wire_version);
         let encoded = {
-            let wire_msg = match self.ring_key.as_ref() {
-                Some(key) => try!(WireMessage::encrypted(&msg, &key)),
-                None => try!(WireMessage::plain(&msg)),
-            };
-            try!(json::encode(&wire_msg))
+            let wire_msg = try!(WireMessage::with_version(&msg,
+                                                          self.keyring.encrypt_key(),
+                                                          self.wire_version));
+            try!(wire_msg.to_bytes())
         };
-        debug!("Encoded message {:#?}", encoded);
-        try!(self.socket.send_to(encoded.as_bytes()));
+        debug!("Encoded message in wire version {}, {} bytes",
+               self.wire_version,
+               encoded.len());
+        try!(self.socket.send_to(&encoded));
         debug!("Sent protocol: {:?}", msg);
         Ok(())
     }
@@ -61,16 +61,30 @@ impl Encodable for RequestState {
     }
 }
 
-/// The failure detector tracks open pending requests.
+/// The failure detector tracks open pending requests, and counts what happens to them.
 #[derive(Debug, RustcEncodable)]
 pub struct Detector {
     pub open_requests: HashMap<MemberId, RequestState>,
+    /// How many pings we have sent
+    pub pings: u64,
+    /// How many acks we have received for our requests
+    pub acks: u64,
+    /// How many ping-reqs we have sent for members that didn't ack
+    pub pingreqs: u64,
+    /// How many requests have timed out, waiting for an ack or for a ping-req
+    pub timeouts: u64,
 }
 
 impl Detector {
     /// Create a new failure detector.
     pub fn new() -> Detector {
-        Detector { open_requests: HashMap::new() }
+        Detector {
+            open_requests: HashMap::new(),
+            pings: 0,
+            acks: 0,
+            pingreqs: 0,
+            timeouts: 0,
+        }
     }
 
     /// Returns true if a member has an open request
@@ -85,6 +99,7 @@ impl Detector {
 
     /// Set a requests state to PingReq
     pub fn pingreq(&mut self, member_id: &MemberId) {
+        self.pingreqs += 1;
         if let Some(rs) = self.open_requests.get_mut(member_id) {
             rs.status = Status::PingReq;
             rs.timeout = SteadyTime::now() + Duration::milliseconds(REQUEST_FAILURE_TIME);
@@ -93,6 +108,7 @@ impl Detector {
 
     /// Sets a requests state to AwaitingAck
     pub fn awaiting_ack(&mut self, member_id: &MemberId) {
+        self.pings += 1;
         if let Some(rs) = self.open_requests.get_mut(member_id) {
             rs.status = Status::AwaitingAck;
             rs.timeout = SteadyTime::now() + Duration::milliseconds(REQUEST_FAILURE_TIME);
@@ -109,7 +125,9 @@ impl Detector {
 
     /// On success, we remove the request from the detector
     pub fn success(&mut self, member_id: &MemberId) {
-        self.open_requests.remove(member_id);
+        if self.open_requests.remove(member_id).is_some() {
+            self.acks += 1;
+        }
     }
 
     /// Checks the timeout of connections. Returns a tuple of (suspect, confirmed) members.
@@ -128,6 +146,7 @@ impl Detector {
                 }
             }
         }
+        self.timeouts += (pingreq_members.len() + failed_members.len()) as u64;
         for member_id in failed_members.iter() {
             self.failed(member_id);
         }
@@ -173,5 +192,17 @@ mod test {
             assert!(d.open_requests.contains_key(&id));
             assert_eq!(d.open_requests.get(&id).unwrap().status, Status::PingReq);
         }
+
+        #[test]
+        fn counts_requests() {
+            let mut d = Detector::new();
+            let id = MemberId::new_v4();
+            d.start(id);
+            d.awaiting_ack(&id);
+            d.pingreq(&id);
+            d.success(&id);
+            d.success(&id);
+            assert_eq!((d.pings, d.pingreqs, d.acks, d.timeouts), (1, 1, 1, 0));
+        }
     }
 }
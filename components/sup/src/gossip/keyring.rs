@@ -0,0 +1,159 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! The ring keys a supervisor encrypts and decrypts gossip with.
+//!
+//! A ring key can have several revisions in the key cache at once, which is what lets us rotate
+//! it without restarting every supervisor at the same moment. We encrypt with the revision we were
+//! started with, which is the newest one unless we were told otherwise, and decrypt with whichever
+//! revision of the ring key a message was encrypted with, as long as it is in the cache when the
+//! message arrives. A revision imported while we run is accepted straight away, and a revision
+//! removed from the cache is refused straight away. Each revision is read from the cache once, and
+//! kept for as long as its key file is still there.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use hcore::crypto::SymKey;
+use hcore::crypto::keys::parse_name_with_rev;
+
+use error::Result;
+
+/// The ring keys we know
+pub struct Keyring {
+    encrypt_key: Option<SymKey>,
+    cache_key_path: PathBuf,
+    revisions: RwLock<HashMap<String, SymKey>>,
+}
+
+impl Keyring {
+    /// Create a keyring that encrypts with the given revision of a ring key, or that doesn't
+    /// encrypt at all.
+    ///
+    /// # Errors
+    ///
+    /// * If the revision isn't in the key cache
+    pub fn new<P: AsRef<Path> + ?Sized>(ring_name_with_rev: Option<&str>,
+                                        cache_key_path: &P)
+                                        -> Result<Keyring> {
+        let encrypt_key = match ring_name_with_rev {
+            Some(rnwr) => Some(try!(SymKey::get_pair_for(rnwr, cache_key_path))),
+            None => None,
+        };
+        Ok(Keyring {
+            encrypt_key: encrypt_key,
+            cache_key_path: cache_key_path.as_ref().to_path_buf(),
+            revisions: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// The key we encrypt with, if we encrypt.
+    pub fn encrypt_key(&self) -> Option<&SymKey> {
+        self.encrypt_key.as_ref()
+    }
+
+    /// The key to decrypt a message encrypted with `name_with_rev`, if it is a revision of our
+    /// ring key that is in the key cache.
+    pub fn decrypt_key(&self, name_with_rev: &str) -> Option<SymKey> {
+        let ours = match self.encrypt_key {
+            Some(ref key) => key,
+            None => return None,
+        };
+        if name_with_rev == ours.name_with_rev() {
+            return Some(ours.clone());
+        }
+        match parse_name_with_rev(name_with_rev) {
+            Ok((ref name, _)) if *name == ours.name => {}
+            _ => return None,
+        }
+        if let Err(e) = SymKey::get_secret_key_path(name_with_rev, &self.cache_key_path) {
+            debug!("Can't find ring key {}: {}", name_with_rev, e);
+            self.revisions.write().unwrap().remove(name_with_rev);
+            return None;
+        }
+        if let Some(key) = self.revisions.read().unwrap().get(name_with_rev) {
+            return Some(key.clone());
+        }
+        match SymKey::get_pair_for(name_with_rev, &self.cache_key_path) {
+            Ok(key) => {
+                self.revisions.write().unwrap().insert(name_with_rev.to_string(), key.clone());
+                Some(key)
+            }
+            Err(e) => {
+                debug!("Can't load ring key {}: {}", name_with_rev, e);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::{Read, Write};
+    use std::path::PathBuf;
+
+    use hcore::crypto::SymKey;
+    use tempdir::TempDir;
+
+    use super::Keyring;
+
+    static OLD_REVISION: &'static str = "beyonce-20160101000000";
+
+    // Writes an older revision of the ring key into the cache, returning its path.
+    fn write_old_revision(key: &SymKey, cache: &TempDir) -> PathBuf {
+        let path = SymKey::get_secret_key_path(&key.name_with_rev(), cache.path()).unwrap();
+        let mut content = String::new();
+        File::open(&path).unwrap().read_to_string(&mut content).unwrap();
+        let content = content.replace(&key.name_with_rev(), OLD_REVISION);
+        SymKey::write_file_from_str(&content, cache.path()).unwrap();
+        SymKey::get_secret_key_path(OLD_REVISION, cache.path()).unwrap()
+    }
+
+    #[test]
+    fn without_a_ring_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let keyring = Keyring::new(None, cache.path()).unwrap();
+        assert!(keyring.encrypt_key().is_none());
+        assert!(keyring.decrypt_key(OLD_REVISION).is_none());
+    }
+
+    #[test]
+    fn decrypts_with_any_revision_in_the_cache() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        let keyring = Keyring::new(Some(&key.name_with_rev()), cache.path()).unwrap();
+        assert_eq!(keyring.encrypt_key().unwrap().name_with_rev(),
+                   key.name_with_rev());
+        assert!(keyring.decrypt_key(&key.name_with_rev()).is_some());
+
+        // Revisions imported after we started are picked up
+        assert!(keyring.decrypt_key(OLD_REVISION).is_none());
+        let path = write_old_revision(&key, &cache);
+        assert_eq!(keyring.decrypt_key(OLD_REVISION).unwrap().name_with_rev(),
+                   OLD_REVISION);
+
+        // Revisions are loaded once, and kept while their key file is there
+        File::create(&path).unwrap().write_all(b"not a key").unwrap();
+        assert_eq!(keyring.decrypt_key(OLD_REVISION).unwrap().name_with_rev(),
+                   OLD_REVISION);
+
+        // Retired revisions are refused
+        fs::remove_file(path).unwrap();
+        assert!(keyring.decrypt_key(OLD_REVISION).is_none());
+    }
+
+    #[test]
+    fn refuses_other_rings() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        let other = SymKey::generate_pair_for_ring("jayz", cache.path()).unwrap();
+        let keyring = Keyring::new(Some(&key.name_with_rev()), cache.path()).unwrap();
+        assert!(keyring.decrypt_key(&other.name_with_rev()).is_none());
+    }
+}
@@ -11,14 +11,24 @@
 //! them in a `MemberList`.
 
 use std::mem;
-use std::collections::HashMap;
+use std::collections::{hash_map, HashMap};
 use std::fmt;
 use std::ops::Deref;
+use std::path::Path;
 
+use common;
+use common::message::gossip as proto;
+use common::wire_message::{CURRENT_WIRE_VERSION, Protobuf};
+use hcore::crypto::SigKeyPair;
 use rand::{thread_rng, Rng};
 use uuid::Uuid;
 
+use error::{Error, Result};
 use gossip::lamport_clock::LamportClock;
+use gossip::rumor::{id_from_proto, wire_version_from_proto};
+use gossip::sync::SYNC_WIRE_VERSION;
+
+static LOGKEY: &'static str = "MB";
 
 // How many members do we send a PingReq to for a failed node?
 static PINGREQ_MEMBERS: usize = 5;
@@ -33,6 +43,7 @@ pub enum Health {
     Alive,
     Suspect,
     Confirmed,
+    Departed,
 }
 
 /// Each member has a Uuid.
@@ -49,6 +60,11 @@ pub struct Member {
     pub incarnation: Incarnation,
     pub health: Health,
     pub permanent: bool,
+    /// The newest wire version the member speaks; `None` from members that predate wire versions
+    pub wire_version: Option<u16>,
+    /// The ring key revision the member encrypts with; `None` if it doesn't encrypt or predates
+    /// ring key rotation
+    pub ring_key: Option<String>,
 }
 
 impl Member {
@@ -62,6 +78,8 @@ impl Member {
             incarnation: Incarnation::new(),
             health: Health::Alive,
             permanent: permanent,
+            wire_version: Some(CURRENT_WIRE_VERSION),
+            ring_key: None,
         }
     }
 
@@ -75,7 +93,12 @@ impl Member {
     /// * If the incarnation is equal, and the rhs health is confirmed, used rhs
     /// * If the incarnation is equal, and the lhs is alive, and the rhs is suspect, use rhs
     /// * If the incarnation is equal, and the lhs is confirmed, and the rhs is suspect or alive, use the lhs
+    /// * If the rhs is departed, use the lhs; members only depart through a signed `Departure`
+    /// * If the lhs is departed, use the lhs unless the rhs has a higher incarnation
     pub fn update_via(&mut self, my_id: &MemberId, rhs: Member) -> bool {
+        if rhs.health == Health::Departed {
+            return false;
+        }
         if self.incarnation > rhs.incarnation {
             return false;
         } else if self.incarnation < rhs.incarnation {
@@ -110,6 +133,9 @@ impl Member {
             if self.health == Health::Confirmed && rhs.health == Health::Suspect {
                 return false;
             }
+            if self.health == Health::Departed {
+                return false;
+            }
         }
         return false;
     }
@@ -152,6 +178,11 @@ impl MemberList {
         }
     }
 
+    /// Iterate over every member in the list, in no particular order.
+    pub fn members(&self) -> hash_map::Values<MemberId, Member> {
+        self.members.values()
+    }
+
     /// Return a reference to the next member in the list. If the list is empty, we return `None`.
     /// Otherwise, we return `Some<&Member>`. When we have reached the end of the list, we
     /// randomize the order, and re-set our position to the top of the list.
@@ -188,6 +219,7 @@ impl MemberList {
             Health::Alive => warn!("Member {} is alive", remote_member.id),
             Health::Suspect => warn!("Member {} is suspect", remote_member.id),
             Health::Confirmed => warn!("Member {} is confirmed dead", remote_member.id),
+            Health::Departed => warn!("Member {} has departed", remote_member.id),
         }
         self.insert(remote_member);
         return true;
@@ -210,10 +242,15 @@ impl MemberList {
         }
     }
 
+    /// Return true if this member has departed the ring
+    pub fn is_departed(&self, member_id: &MemberId) -> bool {
+        self.members.get(member_id).map_or(false, |m| m.health == Health::Departed)
+    }
+
     /// Set a members health to Suspect.
     pub fn suspect(&mut self, member_id: &MemberId) {
         if let Some(mut member) = self.members.get_mut(member_id) {
-            if member.health != Health::Confirmed {
+            if member.health != Health::Confirmed && member.health != Health::Departed {
                 warn!("Member {} is suspect", member_id);
                 member.health = Health::Suspect;
             }
@@ -223,20 +260,35 @@ impl MemberList {
     /// Set a members health to Confirmed.
     pub fn confirm(&mut self, member_id: &MemberId) {
         if let Some(mut member) = self.members.get_mut(member_id) {
-            if member.health != Health::Confirmed {
+            if member.health != Health::Confirmed && member.health != Health::Departed {
                 warn!("Member {} is confirmed dead", member_id);
                 member.health = Health::Confirmed;
             }
         }
     }
 
+    /// Set a members health to Departed. The member stays in the list at its current
+    /// incarnation, so we never re-add it unless it comes back with a higher one.
+    ///
+    /// Returns true if the member was not already departed.
+    pub fn depart(&mut self, member_id: &MemberId) -> bool {
+        if let Some(mut member) = self.members.get_mut(member_id) {
+            if member.health != Health::Departed {
+                warn!("Member {} has departed", member_id);
+                member.health = Health::Departed;
+                return true;
+            }
+        }
+        false
+    }
+
     /// Selects PINGREQ_MEMBERS number of members to use as targets for a PingReq. The members are
     /// chosen completely randomly.
     pub fn pingreq_targets(&self, myself: &MemberId, target: &MemberId) -> Vec<Member> {
         let mut rng = thread_rng();
         let mut values: Vec<&Member> = self.members
             .values()
-            .filter(|m| &m.id != myself && &m.id != target)
+            .filter(|m| &m.id != myself && &m.id != target && m.health != Health::Departed)
             .collect();
         rng.shuffle(&mut values);
         let mut results: Vec<Member> = Vec::new();
@@ -246,6 +298,21 @@ impl MemberList {
         results
     }
 
+    /// Selects a random live member to trade full state with, if there is one. Members that
+    /// predate `SYNC_WIRE_VERSION` don't know how, so we never pick them.
+    pub fn sync_target(&self, myself: &MemberId) -> Option<Member> {
+        let mut rng = thread_rng();
+        let mut values: Vec<&Member> = self.members
+            .values()
+            .filter(|m| {
+                &m.id != myself && m.health == Health::Alive &&
+                m.wire_version.unwrap_or(0) >= SYNC_WIRE_VERSION
+            })
+            .collect();
+        rng.shuffle(&mut values);
+        values.first().map(|m| (*m).clone())
+    }
+
     /// Return an reference to a given member, if it exists in the MemberList.
     pub fn get(&self, member_id: &MemberId) -> Option<&Member> {
         self.members.get(member_id)
@@ -262,12 +329,13 @@ impl MemberList {
         usual_suspects
     }
 
-    /// Return true if all members other than the provided id are Confirmed.
+    /// Return true if all members other than the provided id are Confirmed or Departed.
     pub fn isolated(&self, myself: &MemberId) -> bool {
         self.members.iter().fold(true, |acc, (id, m)| {
             if id == myself {
                 acc
-            } else if m.health == Health::Confirmed && acc != false {
+            } else if (m.health == Health::Confirmed || m.health == Health::Departed) &&
+                      acc != false {
                 true
             } else {
                 false
@@ -276,6 +344,134 @@ impl MemberList {
     }
 }
 
+/// A request for a member to leave the ring for good, signed with an origin key. Supervisors only
+/// honor departures signed by a key in their key cache.
+#[derive(Clone, Debug, PartialEq, Eq, RustcDecodable, RustcEncodable)]
+pub struct Departure {
+    pub member_id: MemberId,
+    /// The name with revision of the signing key
+    pub signer: String,
+    /// The member id, signed
+    pub signature: Vec<u8>,
+}
+
+impl Departure {
+    /// Create a new departure for a member, signed with the given key.
+    ///
+    /// # Errors
+    ///
+    /// * If the key has no secret key
+    pub fn new(member_id: MemberId, pair: &SigKeyPair) -> Result<Departure> {
+        let signature = try!(pair.sign(member_id.simple().to_string().as_bytes()));
+        Ok(Departure {
+            member_id: member_id,
+            signer: pair.name_with_rev(),
+            signature: signature,
+        })
+    }
+
+    /// Verify the departure against the public keys in our key cache.
+    ///
+    /// # Errors
+    ///
+    /// * If we do not have the signing key
+    /// * If the signature is invalid
+    /// * If the signature is for a different member
+    pub fn verify<P: AsRef<Path> + ?Sized>(&self, cache_key_path: &P) -> Result<()> {
+        let pair = try!(SigKeyPair::get_pair_for(&self.signer, cache_key_path));
+        let signed = try!(pair.verify(&self.signature));
+        if signed == self.member_id.simple().to_string().into_bytes() {
+            Ok(())
+        } else {
+            Err(sup_error!(Error::InvalidDeparture(self.member_id.simple().to_string())))
+        }
+    }
+}
+
+impl Health {
+    pub fn to_proto(&self) -> proto::Health {
+        match *self {
+            Health::Alive => proto::Health::ALIVE,
+            Health::Suspect => proto::Health::SUSPECT,
+            Health::Confirmed => proto::Health::CONFIRMED,
+            Health::Departed => proto::Health::DEPARTED,
+        }
+    }
+
+    pub fn from_proto(health: proto::Health) -> Health {
+        match health {
+            proto::Health::ALIVE => Health::Alive,
+            proto::Health::SUSPECT => Health::Suspect,
+            proto::Health::CONFIRMED => Health::Confirmed,
+            proto::Health::DEPARTED => Health::Departed,
+        }
+    }
+}
+
+impl Protobuf for Member {
+    type Message = proto::Member;
+
+    fn to_proto(&self) -> proto::Member {
+        let mut member = proto::Member::new();
+        member.set_id(self.id.as_bytes().to_vec());
+        member.set_hostname(self.hostname.clone());
+        member.set_ip(self.ip.clone());
+        member.set_gossip_listener(self.gossip_listener.clone());
+        member.set_incarnation(self.incarnation.counter);
+        member.set_health(self.health.to_proto());
+        member.set_permanent(self.permanent);
+        if let Some(wire_version) = self.wire_version {
+            member.set_wire_version(wire_version as u32);
+        }
+        if let Some(ref ring_key) = self.ring_key {
+            member.set_ring_key(ring_key.clone());
+        }
+        member
+    }
+
+    fn from_proto(mut member: proto::Member) -> common::Result<Member> {
+        Ok(Member {
+            id: try!(id_from_proto(member.get_id())),
+            hostname: member.take_hostname(),
+            ip: member.take_ip(),
+            gossip_listener: member.take_gossip_listener(),
+            incarnation: LamportClock { counter: member.get_incarnation() },
+            health: Health::from_proto(member.get_health()),
+            permanent: member.get_permanent(),
+            wire_version: if member.has_wire_version() {
+                Some(wire_version_from_proto(member.get_wire_version()))
+            } else {
+                None
+            },
+            ring_key: if member.has_ring_key() {
+                Some(member.take_ring_key())
+            } else {
+                None
+            },
+        })
+    }
+}
+
+impl Protobuf for Departure {
+    type Message = proto::Departure;
+
+    fn to_proto(&self) -> proto::Departure {
+        let mut departure = proto::Departure::new();
+        departure.set_member_id(self.member_id.as_bytes().to_vec());
+        departure.set_signer(self.signer.clone());
+        departure.set_signature(self.signature.clone());
+        departure
+    }
+
+    fn from_proto(mut departure: proto::Departure) -> common::Result<Departure> {
+        Ok(Departure {
+            member_id: try!(id_from_proto(departure.get_member_id())),
+            signer: departure.take_signer(),
+            signature: departure.take_signature(),
+        })
+    }
+}
+
 impl Deref for MemberList {
     type Target = HashMap<MemberId, Member>;
 
@@ -398,6 +594,57 @@ mod test {
             assert_eq!(bobo.health, Health::Confirmed);
             assert_eq!(r, false);
         }
+
+        #[test]
+        fn update_via_rhs_departed() {
+            let my_id = MemberId::new_v4();
+            let mut bobo = bobo();
+            let mut other_bobo = bobo.clone();
+            other_bobo.incarnation.increment();
+            other_bobo.health = Health::Departed;
+            let r = bobo.update_via(&my_id, other_bobo);
+            assert_eq!(bobo.health, Health::Alive);
+            assert_eq!(r, false);
+        }
+
+        #[test]
+        fn update_via_lhs_departed() {
+            let my_id = MemberId::new_v4();
+            let mut bobo = bobo();
+            let mut other_bobo = bobo.clone();
+            bobo.health = Health::Departed;
+            let r = bobo.update_via(&my_id, other_bobo.clone());
+            assert_eq!(bobo.health, Health::Departed);
+            assert_eq!(r, false);
+
+            // Coming back with a higher incarnation re-adds the member
+            other_bobo.incarnation.increment();
+            let r = bobo.update_via(&my_id, other_bobo);
+            assert_eq!(bobo.health, Health::Alive);
+            assert_eq!(r, true);
+        }
+    }
+
+    mod departure {
+        use hcore::crypto::SigKeyPair;
+        use tempdir::TempDir;
+
+        use gossip::member::{Departure, MemberId};
+
+        #[test]
+        fn verify() {
+            let cache = TempDir::new("key_cache").unwrap();
+            let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+            let departure = Departure::new(MemberId::new_v4(), &pair).unwrap();
+            assert!(departure.verify(cache.path()).is_ok());
+
+            let mut forged = departure.clone();
+            forged.member_id = MemberId::new_v4();
+            assert!(forged.verify(cache.path()).is_err());
+
+            let other_cache = TempDir::new("key_cache").unwrap();
+            assert!(departure.verify(other_cache.path()).is_err());
+        }
     }
 
     mod member_list {
@@ -545,5 +792,57 @@ mod test {
             // One member who is not us is alive, so we are not isolated
             assert_eq!(ml.isolated(&my_id), false);
         }
+
+        #[test]
+        fn depart() {
+            let mut ml = new_member_list();
+            let member = Member::new(String::from("a.foo.com"),
+                                     String::from("192.168.1.1"),
+                                     String::from("192.168.1.1:4312"),
+                                     false);
+            let member_id = member.id.clone();
+            ml.insert(member.clone());
+
+            assert_eq!(ml.depart(&member_id), true);
+            assert_eq!(ml.depart(&member_id), false);
+            assert!(ml.is_departed(&member_id));
+
+            // Departed members are never suspected, confirmed, or re-added on the same
+            // incarnation
+            ml.suspect(&member_id);
+            ml.confirm(&member_id);
+            assert!(ml.is_departed(&member_id));
+            assert_eq!(ml.process(member), false);
+            assert!(ml.is_departed(&member_id));
+        }
+
+        #[test]
+        fn sync_target() {
+            let mut ml = new_member_list();
+            let my_id = ml.my_id.clone();
+            // We never sync with ourselves
+            assert!(ml.sync_target(&my_id).is_none());
+
+            let mut old = Member::new(String::from("old.foo.com"),
+                                      String::from("192.168.1.1"),
+                                      String::from("192.168.1.1:4312"),
+                                      false);
+            old.wire_version = None;
+            let mut dead = Member::new(String::from("dead.foo.com"),
+                                       String::from("192.168.1.1"),
+                                       String::from("192.168.1.1:4312"),
+                                       false);
+            dead.health = Health::Confirmed;
+            ml.insert(old);
+            ml.insert(dead);
+            assert!(ml.sync_target(&my_id).is_none());
+
+            let alive = Member::new(String::from("alive.foo.com"),
+                                    String::from("192.168.1.1"),
+                                    String::from("192.168.1.1:4312"),
+                                    false);
+            ml.insert(alive.clone());
+            assert_eq!(ml.sync_target(&my_id).unwrap().id, alive.id);
+        }
     }
 }
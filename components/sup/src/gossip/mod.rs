@@ -15,3 +15,6 @@ pub mod member;
 pub mod server;
 pub mod client;
 pub mod detector;
+pub mod keyring;
+pub mod snapshot;
+pub mod sync;
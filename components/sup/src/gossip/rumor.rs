@@ -10,10 +10,14 @@
 //! A rumor is the unit of sharing in the gossip protocol - it piggybacks on the SWIM failure
 //! detection protocol to share state.
 
+use std::cmp;
 use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
 
+use common;
 use common::gossip_file::GossipFile;
+use common::message::gossip as proto;
+use common::wire_message::{CURRENT_WIRE_VERSION, WIRE_VERSION_JSON, Protobuf};
 use rustc_serialize::Encodable;
 use uuid::Uuid;
 
@@ -23,7 +27,8 @@ use election::Election;
 /// How many times does a rumor get shared with a member before we stop sharing it?
 pub const COLD_AFTER: usize = 3;
 
-use gossip::member::{Member, MemberId};
+use gossip::member::{Departure, Health, Member, MemberId};
+use gossip::sync::Digest;
 
 /// A Peer is a representation of a member; it tracks how to contact the member, and whether this
 /// request is actually being proxied during a PingReq operation.
@@ -33,6 +38,8 @@ pub struct Peer {
     pub listening_on: String,
     pub proxy_through: Option<String>,
     pub proxy_to: Option<String>,
+    /// The newest wire version the peer speaks; `None` from peers that predate wire versions
+    pub wire_version: Option<u16>,
 }
 
 impl Peer {
@@ -43,6 +50,7 @@ impl Peer {
             listening_on: listening_on,
             proxy_through: None,
             proxy_to: None,
+            wire_version: Some(CURRENT_WIRE_VERSION),
         }
     }
 }
@@ -54,6 +62,25 @@ pub enum Protocol {
     Ack(Peer, RumorList),
     PingReq(Peer, RumorList),
     Inject(RumorList),
+    /// Asks a member to trade state with us; see `gossip::sync`
+    Sync(Peer, Digest),
+    /// Answers a `Sync` with the entries the asking member is missing, and our own digest
+    SyncAck(Peer, RumorList, Digest),
+}
+
+impl Protocol {
+    /// Make the rumors we carry readable by a member that speaks `wire_version`; see
+    /// `RumorList::downgrade_for`.
+    pub fn downgrade_for(&mut self, wire_version: u16) {
+        match *self {
+            Protocol::Ping(_, ref mut rumors) |
+            Protocol::Ack(_, ref mut rumors) |
+            Protocol::PingReq(_, ref mut rumors) |
+            Protocol::Inject(ref mut rumors) |
+            Protocol::SyncAck(_, ref mut rumors, _) => rumors.downgrade_for(wire_version),
+            Protocol::Sync(..) => {}
+        }
+    }
 }
 
 /// Rumors contain Messages as their payload, which are then processed by the correct internal
@@ -64,6 +91,7 @@ pub enum Message {
     CensusEntry(CensusEntry),
     Election(Election),
     GossipFile(GossipFile),
+    Departure(Departure),
     Blank,
 }
 
@@ -110,6 +138,15 @@ impl Rumor {
         }
     }
 
+    /// Create a new rumor with a 'Message::Departure' payload. It shares the id of the departing
+    /// member's rumor, so it replaces that rumor rather than being gossiped alongside it.
+    pub fn departure(departure: Departure) -> Rumor {
+        Rumor {
+            id: departure.member_id.clone(),
+            payload: Message::Departure(departure),
+        }
+    }
+
     /// Create a new rumor with a 'Blank' payload.
     pub fn blank() -> Rumor {
         Rumor {
@@ -289,6 +326,30 @@ impl RumorList {
             rumor_map.remove(rumor_id);
         }
     }
+
+    /// Make the list readable by a member that speaks `wire_version`. Members that only speak JSON
+    /// predate departures, and can't decode a message that mentions one: they get no `Departure`
+    /// rumors, and see departed members as confirmed dead.
+    pub fn downgrade_for(&mut self, wire_version: u16) {
+        if wire_version > WIRE_VERSION_JSON {
+            return;
+        }
+        let mut departures = Vec::new();
+        for (rid, rumor) in self.rumors.iter_mut() {
+            match rumor.payload {
+                Message::Departure(_) => departures.push(rid.clone()),
+                Message::Member(ref mut member) => {
+                    if member.health == Health::Departed {
+                        member.health = Health::Confirmed;
+                    }
+                }
+                _ => {}
+            }
+        }
+        for rid in departures.iter() {
+            self.remove_rumor(rid);
+        }
+    }
 }
 
 impl Deref for RumorList {
@@ -305,11 +366,458 @@ impl DerefMut for RumorList {
     }
 }
 
+/// Reads an id that was sent as its 16 raw bytes.
+pub fn id_from_proto(bytes: &[u8]) -> common::Result<Uuid> {
+    Uuid::from_bytes(bytes).map_err(|e| common::Error::WireDecode(format!("Invalid id: {:?}", e)))
+}
+
+/// Reads a wire version that was sent as a `u32`. Anything past what a `u16` holds is newer than
+/// we speak either way.
+pub fn wire_version_from_proto(wire_version: u32) -> u16 {
+    cmp::min(wire_version, u16::max_value() as u32) as u16
+}
+
+impl Protobuf for Peer {
+    type Message = proto::Peer;
+
+    fn to_proto(&self) -> proto::Peer {
+        let mut peer = proto::Peer::new();
+        peer.set_member_id(self.member_id.as_bytes().to_vec());
+        peer.set_listening_on(self.listening_on.clone());
+        if let Some(ref proxy_through) = self.proxy_through {
+            peer.set_proxy_through(proxy_through.clone());
+        }
+        if let Some(ref proxy_to) = self.proxy_to {
+            peer.set_proxy_to(proxy_to.clone());
+        }
+        if let Some(wire_version) = self.wire_version {
+            peer.set_wire_version(wire_version as u32);
+        }
+        peer
+    }
+
+    fn from_proto(mut peer: proto::Peer) -> common::Result<Peer> {
+        Ok(Peer {
+            member_id: try!(id_from_proto(peer.get_member_id())),
+            listening_on: peer.take_listening_on(),
+            proxy_through: if peer.has_proxy_through() {
+                Some(peer.take_proxy_through())
+            } else {
+                None
+            },
+            proxy_to: if peer.has_proxy_to() {
+                Some(peer.take_proxy_to())
+            } else {
+                None
+            },
+            wire_version: if peer.has_wire_version() {
+                Some(wire_version_from_proto(peer.get_wire_version()))
+            } else {
+                None
+            },
+        })
+    }
+}
+
+impl Protobuf for Protocol {
+    type Message = proto::Protocol;
+
+    fn to_proto(&self) -> proto::Protocol {
+        let (kind, peer, rumors, digest) = match *self {
+            Protocol::Ping(ref p, ref r) => (proto::ProtocolType::PING, Some(p), Some(r), None),
+            Protocol::Ack(ref p, ref r) => (proto::ProtocolType::ACK, Some(p), Some(r), None),
+            Protocol::PingReq(ref p, ref r) => {
+                (proto::ProtocolType::PING_REQ, Some(p), Some(r), None)
+            }
+            Protocol::Inject(ref r) => (proto::ProtocolType::INJECT, None, Some(r), None),
+            Protocol::Sync(ref p, ref d) => (proto::ProtocolType::SYNC, Some(p), None, Some(d)),
+            Protocol::SyncAck(ref p, ref r, ref d) => {
+                (proto::ProtocolType::SYNC_ACK, Some(p), Some(r), Some(d))
+            }
+        };
+        let mut protocol = proto::Protocol::new();
+        protocol.set_kind(kind);
+        if let Some(peer) = peer {
+            protocol.set_peer(peer.to_proto());
+        }
+        if let Some(rumors) = rumors {
+            protocol.set_rumors(rumors.to_proto());
+        }
+        if let Some(digest) = digest {
+            protocol.set_digest(digest.to_proto());
+        }
+        protocol
+    }
+
+    fn from_proto(mut protocol: proto::Protocol) -> common::Result<Protocol> {
+        let peer = if protocol.has_peer() {
+            Some(try!(Peer::from_proto(protocol.take_peer())))
+        } else {
+            None
+        };
+        let rumors = if protocol.has_rumors() {
+            Some(try!(RumorList::from_proto(protocol.take_rumors())))
+        } else {
+            None
+        };
+        let digest = if protocol.has_digest() {
+            Some(try!(Digest::from_proto(protocol.take_digest())))
+        } else {
+            None
+        };
+        let msg = match (protocol.get_kind(), peer, rumors, digest) {
+            (proto::ProtocolType::PING, Some(p), Some(r), _) => Protocol::Ping(p, r),
+            (proto::ProtocolType::ACK, Some(p), Some(r), _) => Protocol::Ack(p, r),
+            (proto::ProtocolType::PING_REQ, Some(p), Some(r), _) => Protocol::PingReq(p, r),
+            (proto::ProtocolType::INJECT, _, Some(r), _) => Protocol::Inject(r),
+            (proto::ProtocolType::SYNC, Some(p), _, Some(d)) => Protocol::Sync(p, d),
+            (proto::ProtocolType::SYNC_ACK, Some(p), Some(r), Some(d)) => {
+                Protocol::SyncAck(p, r, d)
+            }
+            (kind, _, _, _) => {
+                return Err(common::Error::WireDecode(format!("{:?} is missing a field", kind)))
+            }
+        };
+        Ok(msg)
+    }
+}
+
+impl Protobuf for Rumor {
+    type Message = proto::Rumor;
+
+    fn to_proto(&self) -> proto::Rumor {
+        let mut rumor = proto::Rumor::new();
+        rumor.set_id(self.id.as_bytes().to_vec());
+        match self.payload {
+            Message::Member(ref m) => {
+                rumor.set_kind(proto::MessageType::MEMBER);
+                rumor.set_member(m.to_proto());
+            }
+            Message::CensusEntry(ref ce) => {
+                rumor.set_kind(proto::MessageType::CENSUS_ENTRY);
+                rumor.set_census_entry(ce.to_proto());
+            }
+            Message::Election(ref e) => {
+                rumor.set_kind(proto::MessageType::ELECTION);
+                rumor.set_election(e.to_proto());
+            }
+            Message::GossipFile(ref gf) => {
+                rumor.set_kind(proto::MessageType::GOSSIP_FILE);
+                rumor.set_gossip_file(gf.to_proto());
+            }
+            Message::Departure(ref d) => {
+                rumor.set_kind(proto::MessageType::DEPARTURE);
+                rumor.set_departure(d.to_proto());
+            }
+            Message::Blank => rumor.set_kind(proto::MessageType::BLANK),
+        }
+        rumor
+    }
+
+    fn from_proto(mut rumor: proto::Rumor) -> common::Result<Rumor> {
+        let payload = match rumor.get_kind() {
+            proto::MessageType::MEMBER if rumor.has_member() => {
+                Message::Member(try!(Member::from_proto(rumor.take_member())))
+            }
+            proto::MessageType::CENSUS_ENTRY if rumor.has_census_entry() => {
+                Message::CensusEntry(try!(CensusEntry::from_proto(rumor.take_census_entry())))
+            }
+            proto::MessageType::ELECTION if rumor.has_election() => {
+                Message::Election(try!(Election::from_proto(rumor.take_election())))
+            }
+            proto::MessageType::GOSSIP_FILE if rumor.has_gossip_file() => {
+                Message::GossipFile(try!(GossipFile::from_proto(rumor.take_gossip_file())))
+            }
+            proto::MessageType::DEPARTURE if rumor.has_departure() => {
+                Message::Departure(try!(Departure::from_proto(rumor.take_departure())))
+            }
+            proto::MessageType::BLANK => Message::Blank,
+            kind => {
+                return Err(common::Error::WireDecode(format!("{:?} rumor has no payload", kind)))
+            }
+        };
+        Ok(Rumor {
+            id: try!(id_from_proto(rumor.get_id())),
+            payload: payload,
+        })
+    }
+}
+
+impl Protobuf for RumorList {
+    type Message = proto::RumorList;
+
+    fn to_proto(&self) -> proto::RumorList {
+        let mut list = proto::RumorList::new();
+        for rumor in self.rumors.values() {
+            list.mut_rumors().push(rumor.to_proto());
+        }
+        for (member_id, rumor_heat) in self.heat.iter() {
+            for (rumor_id, heat) in rumor_heat.iter() {
+                let mut h = proto::Heat::new();
+                h.set_member_id(member_id.as_bytes().to_vec());
+                h.set_rumor_id(rumor_id.as_bytes().to_vec());
+                h.set_heat(*heat as u64);
+                list.mut_heat().push(h);
+            }
+        }
+        list
+    }
+
+    fn from_proto(mut list: proto::RumorList) -> common::Result<RumorList> {
+        let mut rumors = HashMap::new();
+        for rumor in list.take_rumors().into_iter() {
+            let rumor = try!(Rumor::from_proto(rumor));
+            rumors.insert(rumor.id, rumor);
+        }
+        let mut heat = HashMap::new();
+        for h in list.get_heat().iter() {
+            let member_id = try!(id_from_proto(h.get_member_id()));
+            let rumor_id = try!(id_from_proto(h.get_rumor_id()));
+            heat.entry(member_id)
+                .or_insert(HashMap::new())
+                .insert(rumor_id, h.get_heat() as usize);
+        }
+        Ok(RumorList {
+            rumors: rumors,
+            heat: heat,
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
+    mod protocol {
+        use std::str::FromStr;
+
+        use common::gossip_file::GossipFile;
+        use common::wire_message::{WireMessage, CURRENT_WIRE_VERSION, WIRE_VERSION_JSON};
+        use hcore::crypto::SymKey;
+        use hcore::service::ServiceGroup;
+        use rustc_serialize::json;
+        use tempdir::TempDir;
+        use uuid::Uuid;
+
+        use census::CensusEntry;
+        use election::{Election, ElectionStatus};
+        use gossip::member::{Departure, Health, Member, MemberId};
+        use gossip::rumor::{Message, Peer, Protocol, Rumor, RumorList};
+        use gossip::sync::{CensusEntryDigest, Digest, ElectionDigest, GossipFileDigest,
+                           MemberDigest};
+        use health_check::Status;
+
+        /// A `Ping` with a member and a census entry rumor, as a supervisor from before wire
+        /// versions encodes it.
+        static BASELINE_PING: &'static str = r#"{"variant":"Ping","fields":[
+            {"member_id":"5b3a4a5e-7b5f-4a0e-9d5c-1f2e3d4c5b6a","listening_on":"10.0.0.2:9634",
+             "proxy_through":null,"proxy_to":null},
+            {"rumors":{
+              "0d6c5f2e-8a3b-4c1d-9e7f-6a5b4c3d2e1f":{"id":"0d6c5f2e-8a3b-4c1d-9e7f-6a5b4c3d2e1f",
+                "payload":{"variant":"Member","fields":[
+                  {"id":"0d6c5f2e-8a3b-4c1d-9e7f-6a5b4c3d2e1f","hostname":"ruby","ip":"10.0.0.2",
+                   "gossip_listener":"10.0.0.2:9634","incarnation":{"counter":4},"health":"Alive",
+                   "permanent":false}]}},
+              "c1a2b3c4-d5e6-4f70-8192-a3b4c5d6e7f8":{"id":"c1a2b3c4-d5e6-4f70-8192-a3b4c5d6e7f8",
+                "payload":{"variant":"CensusEntry","fields":[
+                  {"id":"c1a2b3c4-d5e6-4f70-8192-a3b4c5d6e7f8",
+                   "member_id":"0d6c5f2e-8a3b-4c1d-9e7f-6a5b4c3d2e1f","hostname":"ruby",
+                   "ip":"10.0.0.2","suitability":0,"port":"6379","exposes":["6379"],
+                   "leader":false,"follower":false,"data_init":false,"vote":null,
+                   "election":null,"needs_write":null,"initialized":true,"keep_me":true,
+                   "service":"redis","group":"default","alive":true,"suspect":false,
+                   "confirmed":false,"detached":false,"incarnation":{"counter":2}}]}}},
+             "heat":{"5b3a4a5e-7b5f-4a0e-9d5c-1f2e3d4c5b6a":{
+               "0d6c5f2e-8a3b-4c1d-9e7f-6a5b4c3d2e1f":1}}}]}"#;
+
+        fn peer() -> Peer {
+            let mut peer = Peer::new(MemberId::new_v4(), "127.0.0.1:9634".to_string());
+            peer.proxy_to = Some("10.0.0.2:9634".to_string());
+            peer
+        }
+
+        fn rumors() -> RumorList {
+            let mut rl = RumorList::new();
+            let mut member = Member::new("ruby".to_string(),
+                                         "10.0.0.1".to_string(),
+                                         "10.0.0.1:9634".to_string(),
+                                         true);
+            member.ring_key = Some("beyonce-20160504220722".to_string());
+            rl.add_rumor(Rumor::member(member));
+            let mut ce = CensusEntry::new("redis", "default", MemberId::new_v4());
+            ce.exposes = Some(vec!["6379".to_string()]);
+            ce.health_check = Some(Status::Warning);
+            rl.add_rumor(Rumor::census_entry(ce));
+            rl.add_rumor(Rumor::election(Election::new("redis".to_string(),
+                                                       "default".to_string(),
+                                                       MemberId::new_v4(),
+                                                       2,
+                                                       1)));
+            let gf = GossipFile::from_body(ServiceGroup::from_str("redis.default").unwrap(),
+                                           "port = 6379\n".as_bytes().to_vec(),
+                                           3)
+                .unwrap();
+            rl.add_rumor(Rumor::gossip_file(gf));
+            rl.add_rumor(Rumor::departure(Departure {
+                member_id: MemberId::new_v4(),
+                signer: "acme-20160504220722".to_string(),
+                signature: vec![1, 2, 3],
+            }));
+            let blank = Rumor::blank();
+            let blank_id = blank.id;
+            rl.add_rumor(blank);
+            rl.increment_heat_for(&MemberId::new_v4(), &blank_id);
+            rl
+        }
+
+        fn digest() -> Digest {
+            Digest {
+                members: vec![MemberDigest {
+                                  id: MemberId::new_v4(),
+                                  incarnation: 3,
+                                  health: Health::Suspect,
+                              }],
+                census_entries: vec![CensusEntryDigest {
+                                         id: Uuid::new_v4(),
+                                         incarnation: 2,
+                                     }],
+                elections: vec![ElectionDigest {
+                                    service_group: "redis.default".to_string(),
+                                    term: 1,
+                                    status: ElectionStatus::Finished,
+                                    leader_id: MemberId::new_v4(),
+                                    suitability: 2,
+                                    votes: 3,
+                                }],
+                gossip_files: vec![GossipFileDigest {
+                                       service_group: ServiceGroup::from_str("redis.default@acme")
+                                           .unwrap(),
+                                       file_name: "gossip.toml".to_string(),
+                                       version: 4,
+                                   }],
+            }
+        }
+
+        fn every_protocol() -> Vec<Protocol> {
+            vec![Protocol::Ping(peer(), rumors()),
+                 Protocol::Ack(peer(), rumors()),
+                 Protocol::PingReq(peer(), rumors()),
+                 Protocol::Inject(rumors()),
+                 Protocol::Sync(peer(), digest()),
+                 Protocol::SyncAck(peer(), rumors(), digest())]
+        }
+
+        fn assert_same_peer(sent: &Peer, read: &Peer) {
+            assert_eq!(read.member_id, sent.member_id);
+            assert_eq!(read.listening_on, sent.listening_on);
+            assert_eq!(read.proxy_through, sent.proxy_through);
+            assert_eq!(read.proxy_to, sent.proxy_to);
+            assert_eq!(read.wire_version, sent.wire_version);
+        }
+
+        fn assert_same_rumors(sent: &RumorList, read: &RumorList) {
+            assert_eq!(read.rumors, sent.rumors);
+            assert_eq!(read.heat, sent.heat);
+        }
+
+        fn assert_same(sent: &Protocol, read: &Protocol) {
+            match (sent, read) {
+                (&Protocol::Ping(ref p1, ref r1), &Protocol::Ping(ref p2, ref r2)) |
+                (&Protocol::Ack(ref p1, ref r1), &Protocol::Ack(ref p2, ref r2)) |
+                (&Protocol::PingReq(ref p1, ref r1), &Protocol::PingReq(ref p2, ref r2)) => {
+                    assert_same_peer(p1, p2);
+                    assert_same_rumors(r1, r2);
+                }
+                (&Protocol::Inject(ref r1), &Protocol::Inject(ref r2)) => {
+                    assert_same_rumors(r1, r2);
+                }
+                (&Protocol::Sync(ref p1, ref d1), &Protocol::Sync(ref p2, ref d2)) => {
+                    assert_same_peer(p1, p2);
+                    assert_eq!(d2, d1);
+                }
+                (&Protocol::SyncAck(ref p1, ref r1, ref d1),
+                 &Protocol::SyncAck(ref p2, ref r2, ref d2)) => {
+                    assert_same_peer(p1, p2);
+                    assert_same_rumors(r1, r2);
+                    assert_eq!(d2, d1);
+                }
+                _ => panic!("sent {:?}, read {:?}", sent, read),
+            }
+        }
+
+        fn round_trip(protocol: &Protocol,
+                      sym_key: Option<&SymKey>,
+                      wire_version: u16)
+                      -> Protocol {
+            let wire = WireMessage::with_version(protocol, sym_key, wire_version).unwrap();
+            let bytes = wire.to_bytes().unwrap();
+            let read = WireMessage::from_bytes(&bytes).unwrap();
+            assert_eq!(read.wire_version(), wire_version);
+            read.versioned_msg(sym_key).unwrap()
+        }
+
+        #[test]
+        fn round_trips_every_protocol_in_every_version() {
+            for protocol in every_protocol().iter() {
+                for version in WIRE_VERSION_JSON..CURRENT_WIRE_VERSION + 1 {
+                    assert_same(protocol, &round_trip(protocol, None, version));
+                }
+            }
+        }
+
+        #[test]
+        fn reads_a_ping_from_before_wire_versions() {
+            let msg_bytes = json::encode(&BASELINE_PING.as_bytes().to_vec()).unwrap();
+            let bytes = format!(concat!(r#"{{"format":"Plain","version":"WIRE-1","#,
+                                        r#""key":null,"nonce":null,"msg_bytes":{}}}"#),
+                                msg_bytes);
+            let read = WireMessage::from_bytes(bytes.as_bytes()).unwrap();
+            let (peer, rumors) = match read.versioned_msg(None).unwrap() {
+                Protocol::Ping(peer, rumors) => (peer, rumors),
+                protocol => panic!("read {:?}", protocol),
+            };
+            assert_eq!(peer.listening_on, "10.0.0.2:9634");
+            assert_eq!(peer.wire_version, None);
+            assert_eq!(rumors.rumors.len(), 2);
+
+            let member_id = MemberId::parse_str("0d6c5f2e-8a3b-4c1d-9e7f-6a5b4c3d2e1f").unwrap();
+            match rumors.rumors.get(&member_id).unwrap().payload {
+                Message::Member(ref member) => {
+                    assert_eq!(member.health, Health::Alive);
+                    assert_eq!(member.wire_version, None);
+                    assert_eq!(member.ring_key, None);
+                }
+                ref payload => panic!("read {:?}", payload),
+            }
+            let ce_id = Uuid::parse_str("c1a2b3c4-d5e6-4f70-8192-a3b4c5d6e7f8").unwrap();
+            match rumors.rumors.get(&ce_id).unwrap().payload {
+                Message::CensusEntry(ref ce) => {
+                    assert_eq!(ce.member_id, member_id);
+                    assert_eq!(ce.port, Some("6379".to_string()));
+                    assert_eq!(ce.updating, None);
+                    assert!(!ce.is_updating());
+                    assert_eq!(ce.restart_count, None);
+                    assert_eq!(ce.health_check, None);
+                }
+                ref payload => panic!("read {:?}", payload),
+            }
+        }
+
+        #[test]
+        fn round_trips_every_protocol_encrypted() {
+            let cache = TempDir::new("key_cache").unwrap();
+            let sym_key = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+            for protocol in every_protocol().iter() {
+                assert_same(protocol,
+                            &round_trip(protocol, Some(&sym_key), CURRENT_WIRE_VERSION));
+            }
+        }
+    }
+
     mod rumor_list {
-        use gossip::rumor::{Rumor, RumorId, RumorList};
-        use gossip::member::MemberId;
+        use common::wire_message::{WIRE_VERSION_BINARY, WIRE_VERSION_JSON};
+        use rustc_serialize::json;
+
+        use gossip::rumor::{Message, Peer, Protocol, Rumor, RumorId, RumorList};
+        use gossip::member::{Departure, Health, Member, MemberId};
 
         #[test]
         fn add_rumor() {
@@ -336,5 +844,54 @@ mod test {
             let fake_member = MemberId::new_v4();
             assert_eq!(0, rl.heat_for(&fake_member, &rumor_id));
         }
+
+        fn departed_rumors() -> (RumorList, RumorId, RumorId) {
+            let mut rl = RumorList::new();
+            let mut member = Member::new("ruby".to_string(),
+                                         "10.0.0.1".to_string(),
+                                         "10.0.0.1:9634".to_string(),
+                                         false);
+            member.health = Health::Departed;
+            let member_rumor = Rumor::member(member);
+            let member_rumor_id = member_rumor.id;
+            rl.add_rumor(member_rumor);
+            let departure = Rumor::departure(Departure {
+                member_id: MemberId::new_v4(),
+                signer: "acme-20160504220722".to_string(),
+                signature: vec![1, 2, 3],
+            });
+            let departure_id = departure.id;
+            rl.add_rumor(departure);
+            rl.increment_heat_for(&MemberId::new_v4(), &departure_id);
+            (rl, member_rumor_id, departure_id)
+        }
+
+        #[test]
+        fn downgrade_for_json_leaves_out_departures() {
+            let (mut rl, member_rumor_id, departure_id) = departed_rumors();
+            rl.downgrade_for(WIRE_VERSION_JSON);
+            assert!(rl.rumors.get(&departure_id).is_none());
+            assert!(rl.heat.values().all(|rumor_heat| !rumor_heat.contains_key(&departure_id)));
+            match rl.rumors.get(&member_rumor_id).unwrap().payload {
+                Message::Member(ref member) => assert_eq!(member.health, Health::Confirmed),
+                ref payload => panic!("downgraded to {:?}", payload),
+            }
+
+            let peer = Peer::new(MemberId::new_v4(), "10.0.0.2:9634".to_string());
+            let mut ping = Protocol::Ping(peer, departed_rumors().0);
+            ping.downgrade_for(WIRE_VERSION_JSON);
+            assert!(!json::encode(&ping).unwrap().contains("Depart"));
+        }
+
+        #[test]
+        fn downgrade_for_binary_keeps_departures() {
+            let (mut rl, member_rumor_id, departure_id) = departed_rumors();
+            rl.downgrade_for(WIRE_VERSION_BINARY);
+            assert!(rl.rumors.get(&departure_id).is_some());
+            match rl.rumors.get(&member_rumor_id).unwrap().payload {
+                Message::Member(ref member) => assert_eq!(member.health, Health::Departed),
+                ref payload => panic!("downgraded to {:?}", payload),
+            }
+        }
     }
 }
@@ -13,17 +13,19 @@
 //! * An inbound listener, which receives SWIM Protocol messages.
 //! * An outbound distributor, which initiates outbound pings to members
 //! * A failure detector, which tracks outbound connections and times them out
+//! * A synchronizer, which trades full state with a random member now and then; see `sync`
 
 use threadpool::ThreadPool;
 
 use std::thread;
-use std::ops::Deref;
 use std::time::Duration;
 use std::sync::{Arc, RwLock};
 use std::net;
+use std::path::Path;
 
 use common::gossip_file::GossipFileList;
-use hcore::crypto::{default_cache_key_path, SymKey};
+use common::wire_message;
+use hcore::crypto::default_cache_key_path;
 use hcore::service::ServiceGroup;
 use utp::{UtpListener, UtpSocket};
 
@@ -31,6 +33,9 @@ use gossip::client::Client;
 use gossip::member::{Member, MemberList, Health};
 use gossip::rumor::{Peer, Protocol, Rumor, RumorList, Message};
 use gossip::detector::Detector;
+use gossip::keyring::Keyring;
+use gossip::snapshot::Snapshot;
+use gossip::sync::Digest;
 use election::ElectionList;
 use census::{Census, CensusEntry, CensusList};
 use error::Result;
@@ -41,6 +46,8 @@ static LOGKEY: &'static str = "GS";
 static OUTBOUND_INTERVAL: u64 = 200;
 /// How many outbound threads do we allow?
 static OUTBOUND_MAX_THREADS: usize = 5;
+/// How often do we trade full state with a random member, in seconds
+static SYNC_INTERVAL: u64 = 30;
 /// How many inbound threads do we allow?
 static INBOUND_MAX_THREADS: usize = 5;
 /// The default port for the Gossip protocol
@@ -64,13 +71,16 @@ pub struct Server {
     pub gossip_file_list: Arc<RwLock<GossipFileList>>,
     /// Our 'peer' entry, used to generate SWIM protocol messages.
     pub peer: Peer,
-    /// An optional ring key used to encrypt messages with peers
-    ring_key: Arc<Option<SymKey>>,
+    /// The ring keys used to encrypt and decrypt messages with peers
+    ring_key: Arc<Keyring>,
 }
 
 impl Server {
     /// Creates a new Server. Creates our own entry in the census and membership lists, and writes
     /// a rumor that this server is alive.
+    ///
+    /// If we have a snapshot from a previous run, we take back our old member and census entry
+    /// ids, with their incarnations bumped so the ring takes our new entries over the old ones.
     pub fn new(listen_ip: String,
                listen_port: u16,
                permanent: bool,
@@ -79,7 +89,8 @@ impl Server {
                group: String,
                organization: Option<String>,
                exposes: Option<Vec<String>>,
-               port: Option<String>)
+               port: Option<String>,
+               previous: Option<&Snapshot>)
                -> Server {
 
         let hostname = util::sys::hostname(None).unwrap_or(String::from("unknown"));
@@ -87,12 +98,28 @@ impl Server {
         let peer_listen = listen.clone();
         let peer_listen2 = peer_listen.clone();
 
-        let member = Member::new(hostname, listen_ip, peer_listen2, permanent);
+        let ring_key = Keyring::new(ring_name_with_rev.as_ref().map(|r| &r[..]),
+                                    &default_cache_key_path(None))
+            .unwrap();
+
+        let mut member = Member::new(hostname, listen_ip, peer_listen2, permanent);
+        member.ring_key = ring_key.encrypt_key().map(|k| k.name_with_rev());
 
         let service_group = format!("{}.{}", service, group);
+        let previous = previous.and_then(|s| if s.is_for(&service_group) { Some(s) } else { None });
+        if let Some(snapshot) = previous {
+            member.id = snapshot.member.id.clone();
+            member.incarnation = snapshot.member.incarnation.clone();
+            member.incarnation.increment();
+        }
         let mut ce = CensusEntry::new(service.clone(), group.clone(), member.id.clone());
         ce.exposes = exposes;
         ce.port = port;
+        if let Some(snapshot) = previous {
+            ce.id = snapshot.census_entry.id.clone();
+            ce.incarnation = snapshot.census_entry.incarnation.clone();
+            ce.incarnation.increment();
+        }
         let my_id = member.id.clone();
         let leader_id = member.id.clone();
         outputln!("Supervisor {}", member);
@@ -100,11 +127,6 @@ impl Server {
 
         let census_list = CensusList::new(Census::new(ce.clone()));
 
-        let ring_key = match ring_name_with_rev {
-            Some(rnwr) => Some(SymKey::get_pair_for(&rnwr, &default_cache_key_path(None)).unwrap()),
-            None => None,
-        };
-
         let server = Server {
             listen: listen,
             member_list: Arc::new(RwLock::new(MemberList::new(member.clone()))),
@@ -137,6 +159,35 @@ impl Server {
         server
     }
 
+    /// Merges a snapshot from a previous run into our lists. Should be called before we join
+    /// the ring; anything the ring tells us afterwards supersedes what we restored.
+    pub fn restore(&self, snapshot: Snapshot) {
+        let mut ml = self.member_list.write().unwrap();
+        let mut cl = self.census_list.write().unwrap();
+        let mut el = self.election_list.write().unwrap();
+        let mut gfl = self.gossip_file_list.write().unwrap();
+        outputln!("Restoring {} members and {} census entries from the ring snapshot",
+                  snapshot.members.len(),
+                  snapshot.census_entries.len());
+        snapshot.restore(&mut ml, &mut cl, &mut el, &mut gfl);
+    }
+
+    /// Writes a snapshot of our lists to the given path.
+    ///
+    /// # Errors
+    ///
+    /// * If the snapshot cannot be written
+    pub fn snapshot<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let snapshot = {
+            let ml = self.member_list.read().unwrap();
+            let cl = self.census_list.read().unwrap();
+            let el = self.election_list.read().unwrap();
+            let gfl = self.gossip_file_list.read().unwrap();
+            Snapshot::take(&ml, &cl, &el, &gfl)
+        };
+        snapshot.save(path)
+    }
+
     /// Starts the inbound gossip listener.
     ///
     /// # Errors
@@ -185,6 +236,20 @@ impl Server {
             .spawn(move || failure_detector(key, my_peer, ml, rl, detector));
     }
 
+    /// Starts the synchronizer.
+    pub fn start_sync(&self) {
+        outputln!("Starting gossip synchronizer");
+        let key = self.ring_key.clone();
+        let my_peer = self.peer.clone();
+        let ml = self.member_list.clone();
+        let cl = self.census_list.clone();
+        let el = self.election_list.clone();
+        let gfl = self.gossip_file_list.clone();
+        let _t = thread::Builder::new()
+            .name("sync".to_string())
+            .spawn(move || sync(key, my_peer, ml, cl, el, gfl));
+    }
+
     /// Sends blocking SWIM requests to our initial gossip peers.
     ///
     /// # Errors
@@ -214,7 +279,7 @@ impl Server {
         let mut initialized = false;
         for to in peer_listeners {
             outputln!("Joining gossip peer at {}", to);
-            let mut c = match Client::new(&to[..], self.ring_key.deref().as_ref()) {
+            let mut c = match Client::new(&to[..], &self.ring_key) {
                 Ok(c) => c,
                 Err(e) => {
                     debug!("Error creating gossip client - {:?}", e);
@@ -247,7 +312,7 @@ impl Server {
 ///
 /// New requests are handled by passing them to `receive`.
 pub fn inbound(listener: UtpListener,
-               ring_key: Arc<Option<SymKey>>,
+               ring_key: Arc<Keyring>,
                my_peer: Peer,
                member_list: Arc<RwLock<MemberList>>,
                rumor_list: Arc<RwLock<RumorList>>,
@@ -308,9 +373,17 @@ pub fn inbound(listener: UtpListener,
 /// ## PingReq(Peer, RumorList)
 /// * Create a connection to the requested Peer
 /// * Forward along the RumorList to that Peer as a Proxy Ping.
+///
+/// ## Sync(Peer, Digest)
+/// * Respond with a SyncAck of every entry the Peer is missing or has an older copy of, and our
+/// own Digest.
+///
+/// ## SyncAck(Peer, RumorList, Digest)
+/// * Process the RumorList.
+/// * Inject every entry the Peer is missing or has an older copy of back into it.
 fn receive(socket: UtpSocket,
            src: net::SocketAddr,
-           ring_key: Arc<Option<SymKey>>,
+           ring_key: Arc<Keyring>,
            my_peer: Peer,
            member_list: Arc<RwLock<MemberList>>,
            rumor_list: Arc<RwLock<RumorList>>,
@@ -318,7 +391,7 @@ fn receive(socket: UtpSocket,
            detector: Arc<RwLock<Detector>>,
            election_list: Arc<RwLock<ElectionList>>,
            gossip_file_list: Arc<RwLock<GossipFileList>>) {
-    let mut client = Client::from_socket(socket, ring_key.deref().as_ref());
+    let mut client = Client::from_socket(socket, &ring_key);
     let msg = match client.recv_message() {
         Ok(msg) => msg,
         Err(e) => {
@@ -346,7 +419,7 @@ fn receive(socket: UtpSocket,
             };
 
             // Create a client for that peer
-            let mut c = match Client::new(&respond_to[..], ring_key.deref().as_ref()) {
+            let mut c = match Client::new(&respond_to[..], &ring_key) {
                 Ok(c) => c,
                 Err(e) => {
                     debug!("Failed to create a gossip client for {:?}; aborting: {}",
@@ -355,6 +428,11 @@ fn receive(socket: UtpSocket,
                     return;
                 }
             };
+            // Proxies and the peers they proxy for may not share a wire version, so proxied
+            // acks go as JSON, which everyone reads
+            if from_peer.proxy_through.is_none() && from_peer.proxy_to.is_none() {
+                c.set_wire_version(wire_message::negotiate(from_peer.wire_version));
+            }
 
             // Generate our Ack rumors
             let ack_rumors = {
@@ -398,7 +476,7 @@ fn receive(socket: UtpSocket,
             if from_peer.proxy_to.is_some() {
                 debug!("Proxy Ack for {:?}", from_peer);
                 let forward_to = from_peer.proxy_to.take().unwrap();
-                let mut c = match Client::new(&forward_to[..], ring_key.deref().as_ref()) {
+                let mut c = match Client::new(&forward_to[..], &ring_key) {
                     Ok(c) => c,
                     Err(e) => {
                         debug!("Failed to create a gossip client to forward for {:?}; aborting: \
@@ -437,7 +515,7 @@ fn receive(socket: UtpSocket,
                     return;
                 }
             };
-            let mut c = match Client::new(&proxy_to[..], ring_key.deref().as_ref()) {
+            let mut c = match Client::new(&proxy_to[..], &ring_key) {
                 Ok(c) => c,
                 Err(e) => {
                     debug!("Failed to create a gossip connection for sending ping-req to {} for \
@@ -465,6 +543,66 @@ fn receive(socket: UtpSocket,
                            election_list,
                            gossip_file_list);
         }
+        Protocol::Sync(from_peer, remote_digest) => {
+            debug!("Sync from {:?}", from_peer);
+            let (sync_rumors, digest) = {
+                let ml = member_list.read().unwrap();
+                let cl = census_list.read().unwrap();
+                let el = election_list.read().unwrap();
+                let gfl = gossip_file_list.read().unwrap();
+                (remote_digest.missing_rumors(&ml, &cl, &el, &gfl),
+                 Digest::take(&ml, &cl, &el, &gfl))
+            };
+            let mut c = match Client::new(&from_peer.listening_on[..], &ring_key) {
+                Ok(c) => c,
+                Err(e) => {
+                    debug!("Failed to create a gossip client for {:?}; aborting: {}",
+                           from_peer,
+                           e);
+                    return;
+                }
+            };
+            c.set_wire_version(wire_message::negotiate(from_peer.wire_version));
+            match c.sync_ack(my_peer, sync_rumors, digest) {
+                Ok(_) => {}
+                Err(e) => debug!("Failed to send SyncAck to {:?}: {:?}", from_peer, e),
+            }
+        }
+        Protocol::SyncAck(from_peer, remote_rumor_list, remote_digest) => {
+            debug!("SyncAck from {:?} with {} entries",
+                   from_peer,
+                   remote_rumor_list.len());
+            process_rumors(remote_rumor_list,
+                           rumor_list,
+                           member_list.clone(),
+                           census_list.clone(),
+                           election_list.clone(),
+                           gossip_file_list.clone());
+            let sync_rumors = {
+                let ml = member_list.read().unwrap();
+                let cl = census_list.read().unwrap();
+                let el = election_list.read().unwrap();
+                let gfl = gossip_file_list.read().unwrap();
+                remote_digest.missing_rumors(&ml, &cl, &el, &gfl)
+            };
+            if sync_rumors.is_empty() {
+                return;
+            }
+            let mut c = match Client::new(&from_peer.listening_on[..], &ring_key) {
+                Ok(c) => c,
+                Err(e) => {
+                    debug!("Failed to create a gossip client for {:?}; aborting: {}",
+                           from_peer,
+                           e);
+                    return;
+                }
+            };
+            c.set_wire_version(wire_message::negotiate(from_peer.wire_version));
+            match c.inject(sync_rumors) {
+                Ok(_) => {}
+                Err(e) => debug!("Failed to send sync rumors to {:?}: {:?}", from_peer, e),
+            }
+        }
     }
 }
 
@@ -499,6 +637,10 @@ pub fn process_rumors(remote_rumors: RumorList,
             }
             Message::CensusEntry(ce) => {
                 debug!("Processing Census Entry {:#?}", ce);
+                if member_list.read().unwrap().is_departed(&ce.member_id) {
+                    debug!("Ignoring Census Entry for departed member {}", ce.member_id);
+                    continue;
+                }
                 let processed = {
                     let mut cl = census_list.write().unwrap();
                     cl.process(ce.clone())
@@ -542,6 +684,30 @@ pub fn process_rumors(remote_rumors: RumorList,
                     rl.add_rumor(Rumor::gossip_file(gossip_file));
                 }
             }
+            Message::Departure(departure) => {
+                debug!("Processing Departure {:#?}", departure);
+                if let Err(e) = departure.verify(&default_cache_key_path(None)) {
+                    outputln!("Rejecting departure for member {}: {}",
+                              departure.member_id,
+                              e);
+                    continue;
+                }
+                let processed = {
+                    let mut ml = member_list.write().unwrap();
+                    if departure.member_id == ml.my_id {
+                        outputln!("We have been departed from the ring by {}; restart the \
+                                   supervisor to rejoin",
+                                  departure.signer);
+                        false
+                    } else {
+                        ml.depart(&departure.member_id)
+                    }
+                };
+                if processed {
+                    let mut rl = rumor_list.write().unwrap();
+                    rl.add_rumor(Rumor::departure(departure));
+                }
+            }
             Message::Blank => {}
         }
     }
@@ -553,7 +719,7 @@ pub fn process_rumors(remote_rumors: RumorList,
 ///
 /// Like inbound, it is backed by a thread pool - if we have more than OUTBOUND_MAX_THREADS running
 /// at once, we delay the next outbound message until a thread is free.
-pub fn outbound(ring_key: Arc<Option<SymKey>>,
+pub fn outbound(ring_key: Arc<Keyring>,
                 my_peer: Peer,
                 member_list: Arc<RwLock<MemberList>>,
                 rumor_list: Arc<RwLock<RumorList>>,
@@ -587,6 +753,9 @@ pub fn outbound(ring_key: Arc<Option<SymKey>>,
         let isolated = {
             member_list.read().unwrap().isolated(&my_peer.member_id)
         };
+        if member.health == Health::Departed {
+            continue;
+        }
         if member.health == Health::Confirmed && !member.permanent && !isolated {
             continue;
         }
@@ -615,7 +784,7 @@ pub fn outbound(ring_key: Arc<Option<SymKey>>,
 }
 
 /// Send an outbound Ping. If we fail to send, we initiate a PingReq.
-pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
+pub fn send_outbound(ring_key: Arc<Keyring>,
                      my_peer: Peer,
                      member: Member,
                      rumor_list: Arc<RwLock<RumorList>>,
@@ -626,7 +795,7 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
         d.start(member.id.clone());
     }
 
-    let mut c = match Client::new(&member.gossip_listener[..], ring_key.deref().as_ref()) {
+    let mut c = match Client::new(&member.gossip_listener[..], &ring_key) {
         Ok(c) => c,
         Err(e) => {
             debug!("Failed to create a gossip connection for {}; sending ping-req: {}",
@@ -641,6 +810,7 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
             return;
         }
     };
+    c.set_wire_version(wire_message::negotiate(member.wire_version));
 
     let ping_rumors = {
         let rl = rumor_list.read().unwrap();
@@ -675,7 +845,7 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
 
 /// Send a PingReq for a failed Ping. We pick targets from the Member List, and then send a PingReq
 /// to each of them, with our information filled in.
-pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
+pub fn send_pingreq(ring_key: Arc<Keyring>,
                     my_peer: Peer,
                     member: Member,
                     rumor_list: Arc<RwLock<RumorList>>,
@@ -699,8 +869,7 @@ pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
         debug!("Sending pingreq to {} through {}",
                member.id,
                pingreq_member.id);
-        let mut c = match Client::new(&pingreq_member.gossip_listener[..],
-                                      ring_key.deref().as_ref()) {
+        let mut c = match Client::new(&pingreq_member.gossip_listener[..], &ring_key) {
             Ok(c) => c,
             Err(e) => {
                 debug!("Failed to create a gossip connection for {}; aborting ping-req request: \
@@ -710,6 +879,7 @@ pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
                 continue;
             }
         };
+        c.set_wire_version(wire_message::negotiate(pingreq_member.wire_version));
 
         let mut proxy_peer = my_peer.clone();
         proxy_peer.proxy_through = Some(pingreq_member.gossip_listener.clone());
@@ -726,10 +896,55 @@ pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
     }
 }
 
+/// The synchronizer. Every SYNC_INTERVAL in seconds, it sends our digest to a random live member,
+/// which starts a push-pull exchange of everything either of us is missing. The rest of the
+/// exchange happens in `receive`.
+pub fn sync(ring_key: Arc<Keyring>,
+            my_peer: Peer,
+            member_list: Arc<RwLock<MemberList>>,
+            census_list: Arc<RwLock<CensusList>>,
+            election_list: Arc<RwLock<ElectionList>>,
+            gossip_file_list: Arc<RwLock<GossipFileList>>) {
+    loop {
+        thread::sleep(Duration::from_secs(SYNC_INTERVAL));
+
+        let member = {
+            let ml = member_list.read().unwrap();
+            match ml.sync_target(&my_peer.member_id) {
+                Some(member) => member,
+                None => continue,
+            }
+        };
+        let digest = {
+            let ml = member_list.read().unwrap();
+            let cl = census_list.read().unwrap();
+            let el = election_list.read().unwrap();
+            let gfl = gossip_file_list.read().unwrap();
+            Digest::take(&ml, &cl, &el, &gfl)
+        };
+
+        let mut c = match Client::new(&member.gossip_listener[..], &ring_key) {
+            Ok(c) => c,
+            Err(e) => {
+                debug!("Failed to create a gossip connection to sync with {}: {}",
+                       member.id,
+                       e);
+                continue;
+            }
+        };
+        c.set_wire_version(wire_message::negotiate(member.wire_version));
+        debug!("Syncing with {}", member.id);
+        match c.sync(my_peer.clone(), digest) {
+            Ok(_) => {}
+            Err(e) => debug!("Failed to sync with {}: {:?}", member.id, e),
+        }
+    }
+}
+
 /// The failure detector. Every 100ms, we check for any failed for confirmed timeouts within the
 /// detector. If we find a timeout, we update our rumor and the members entry. Additionally, if we
 /// mark a member as Suspect through a rumor we were passed, we set up its entry in the detector.
-pub fn failure_detector(ring_key: Arc<Option<SymKey>>,
+pub fn failure_detector(ring_key: Arc<Keyring>,
                         my_peer: Peer,
                         member_list: Arc<RwLock<MemberList>>,
                         rumor_list: Arc<RwLock<RumorList>>,
@@ -0,0 +1,252 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! Snapshots of the gossip ring state.
+//!
+//! The supervisor periodically writes its member list, census, finished elections and gossip
+//! files to a file in the service's data directory. When it boots again, it reloads that file:
+//! it takes back its old member and census entry ids (with a higher incarnation, so it wins over
+//! anything the ring remembers about its previous life), and merges the rest of the entries
+//! through the same `process` functions used for gossip. Anything we have already heard about
+//! from the live ring with a newer incarnation, term or version wins over the snapshot.
+
+use std::fs::{self, File};
+use std::io::{ErrorKind, Read, Write};
+use std::path::Path;
+
+use rustc_serialize::json;
+
+use census::{CensusEntry, CensusList};
+use common::gossip_file::{GossipFile, GossipFileList};
+use election::{Election, ElectionList, ElectionStatus};
+use error::{Result, SupError};
+use gossip::member::{Health, Member, MemberList};
+
+/// The name of the snapshot file in the service's data directory
+pub const SNAPSHOT_FILENAME: &'static str = "ring.json";
+
+#[derive(Debug, RustcEncodable, RustcDecodable)]
+pub struct Snapshot {
+    /// Our own member entry
+    pub member: Member,
+    /// Our own census entry
+    pub census_entry: CensusEntry,
+    pub members: Vec<Member>,
+    pub census_entries: Vec<CensusEntry>,
+    pub elections: Vec<Election>,
+    pub gossip_files: Vec<GossipFile>,
+}
+
+impl Snapshot {
+    /// Take a snapshot of the current ring state.
+    pub fn take(member_list: &MemberList,
+                census_list: &CensusList,
+                election_list: &ElectionList,
+                gossip_file_list: &GossipFileList)
+                -> Snapshot {
+        let me = census_list.me().clone();
+        let member = member_list.get(&member_list.my_id).unwrap().clone();
+        let members = member_list.values()
+            .filter(|m| m.id != member.id)
+            .cloned()
+            .collect();
+        let mut census_entries = Vec::new();
+        for (_sg, census) in census_list.iter() {
+            for (_id, ce) in census.iter() {
+                if ce.id != me.id {
+                    census_entries.push(ce.clone());
+                }
+            }
+        }
+        let elections = election_list.values()
+            .filter(|e| e.status == ElectionStatus::Finished)
+            .cloned()
+            .collect();
+        let gossip_files = gossip_file_list.files().cloned().collect();
+        Snapshot {
+            member: member,
+            census_entry: me,
+            members: members,
+            census_entries: census_entries,
+            elections: elections,
+            gossip_files: gossip_files,
+        }
+    }
+
+    /// Load a snapshot from disk. Returns `None` if there is no snapshot at the given path.
+    ///
+    /// # Errors
+    ///
+    /// * If the file cannot be read
+    /// * If the file is not a valid snapshot
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Option<Snapshot>> {
+        let mut file = match File::open(path.as_ref()) {
+            Ok(file) => file,
+            Err(ref e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(SupError::from(e)),
+        };
+        let mut body = String::new();
+        try!(file.read_to_string(&mut body));
+        let snapshot: Snapshot = try!(json::decode(&body));
+        Ok(Some(snapshot))
+    }
+
+    /// Write the snapshot to disk. We write to a temporary file first and then rename it into
+    /// place, so a crash mid-write never leaves us with a truncated snapshot.
+    ///
+    /// # Errors
+    ///
+    /// * If the snapshot cannot be encoded
+    /// * If the file cannot be written
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+        let body = try!(json::encode(self));
+        {
+            let mut file = try!(File::create(&tmp_path));
+            try!(file.write_all(body.as_bytes()));
+            try!(file.sync_all());
+        }
+        try!(fs::rename(&tmp_path, path));
+        Ok(())
+    }
+
+    /// Returns true if this snapshot was taken by the given service group.
+    pub fn is_for(&self, service_group: &str) -> bool {
+        self.census_entry.service_group() == service_group
+    }
+
+    /// Merge the snapshot into the live ring state. Our own entries are skipped, as are members
+    /// that were already confirmed dead and census entries that belong to confirmed or departed
+    /// members; departed members themselves are kept, so we never re-add them. Everything else
+    /// goes through the normal `process` functions, so entries that are stale relative to what
+    /// we already know are ignored.
+    pub fn restore(self,
+                   member_list: &mut MemberList,
+                   census_list: &mut CensusList,
+                   election_list: &mut ElectionList,
+                   gossip_file_list: &mut GossipFileList) {
+        let my_id = member_list.my_id.clone();
+        for member in self.members.into_iter() {
+            if member.id == my_id || member.health == Health::Confirmed {
+                continue;
+            }
+            member_list.process(member);
+        }
+        let my_ce_id = census_list.me().id.clone();
+        for ce in self.census_entries.into_iter() {
+            if ce.id == my_ce_id || ce.member_id == my_id || ce.confirmed {
+                continue;
+            }
+            if member_list.get(&ce.member_id).is_none() || member_list.is_departed(&ce.member_id) {
+                continue;
+            }
+            census_list.process(ce);
+        }
+        for election in self.elections.into_iter() {
+            if election.term >= election_list.current_term_for(&election) &&
+               !election_list.contains_key(&election.service_group()) {
+                election_list.elections.insert(election.service_group(), election);
+            }
+        }
+        for gf in self.gossip_files.into_iter() {
+            gossip_file_list.process(gf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tempdir::TempDir;
+
+    use census::{Census, CensusEntry, CensusList};
+    use common::gossip_file::GossipFileList;
+    use election::{Election, ElectionList, ElectionStatus};
+    use gossip::member::{Health, Member, MemberList};
+    use gossip::snapshot::{Snapshot, SNAPSHOT_FILENAME};
+    use hcore::service::ServiceGroup;
+
+    fn member(name: &str) -> Member {
+        Member::new(String::from(name),
+                    String::from("192.168.1.1"),
+                    String::from("192.168.1.1:9634"),
+                    false)
+    }
+
+    fn ring(me: &Member) -> (MemberList, CensusList, ElectionList, GossipFileList) {
+        let ce = CensusEntry::new("redis", "default", me.id.clone());
+        (MemberList::new(me.clone()),
+         CensusList::new(Census::new(ce)),
+         ElectionList::new(String::from("redis.default"), me.id.clone()),
+         GossipFileList::new(ServiceGroup::new("redis", "default", None)))
+    }
+
+    fn populated() -> (Member, MemberList, CensusList, ElectionList, GossipFileList) {
+        let me = member("me");
+        let (mut ml, mut cl, mut el, gfl) = ring(&me);
+        let peer = member("peer");
+        let mut dead = member("dead");
+        dead.health = Health::Confirmed;
+        cl.process(CensusEntry::new("redis", "default", peer.id.clone()));
+        cl.process(CensusEntry::new("redis", "default", dead.id.clone()));
+        ml.process(peer);
+        ml.process(dead);
+        let mut election = Election::new(String::from("redis"),
+                                         String::from("default"),
+                                         me.id.clone(),
+                                         0,
+                                         3);
+        election.status = ElectionStatus::Finished;
+        el.process(election);
+        (me, ml, cl, el, gfl)
+    }
+
+    #[test]
+    fn save_and_load() {
+        let tmp = TempDir::new("ring-snapshot").unwrap();
+        let path = tmp.path().join(SNAPSHOT_FILENAME);
+        assert!(Snapshot::load(&path).unwrap().is_none());
+
+        let (me, ml, cl, el, gfl) = populated();
+        Snapshot::take(&ml, &cl, &el, &gfl).save(&path).unwrap();
+        let snapshot = Snapshot::load(&path).unwrap().unwrap();
+        assert_eq!(snapshot.member.id, me.id);
+        assert_eq!(snapshot.census_entry.member_id, me.id);
+        assert!(snapshot.is_for("redis.default"));
+        assert_eq!(snapshot.members.len(), 2);
+        assert_eq!(snapshot.census_entries.len(), 2);
+        assert_eq!(snapshot.elections.len(), 1);
+        assert_eq!(snapshot.elections[0].term, 3);
+    }
+
+    #[test]
+    fn restore_skips_our_own_and_dead_entries() {
+        let (_me, ml, cl, el, gfl) = populated();
+        let snapshot = Snapshot::take(&ml, &cl, &el, &gfl);
+
+        let (mut ml2, mut cl2, mut el2, mut gfl2) = ring(&member("restarted"));
+        snapshot.restore(&mut ml2, &mut cl2, &mut el2, &mut gfl2);
+        assert_eq!(ml2.len(), 2);
+        assert_eq!(cl2.local_census().len(), 2);
+        assert_eq!(el2.election().unwrap().term, 3);
+    }
+
+    #[test]
+    fn restore_ignores_stale_entries() {
+        let (_me, ml, cl, el, gfl) = populated();
+        let snapshot = Snapshot::take(&ml, &cl, &el, &gfl);
+        let peer = snapshot.members.iter().find(|m| m.hostname == "peer").unwrap().clone();
+
+        let (mut ml2, mut cl2, mut el2, mut gfl2) = ring(&member("restarted"));
+        let mut live_peer = peer.clone();
+        live_peer.incarnation.increment();
+        live_peer.health = Health::Suspect;
+        ml2.process(live_peer);
+        snapshot.restore(&mut ml2, &mut cl2, &mut el2, &mut gfl2);
+        assert_eq!(ml2.get(&peer.id).unwrap().health, Health::Suspect);
+    }
+}
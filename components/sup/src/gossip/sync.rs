@@ -0,0 +1,551 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! Push-pull anti-entropy.
+//!
+//! Rumors go cold once they have been shared enough times, so a member that was partitioned away
+//! or restarted while a rumor was hot can miss it for good. To catch up, every so often a
+//! supervisor picks a random live member and the two trade their full state:
+//!
+//! 1. We send a `Sync` with a `Digest` of everything we know: the incarnation and health of every
+//!    member, the incarnation of every census entry, the term of every election and the version
+//!    of every gossip file.
+//! 2. They answer with a `SyncAck` holding every entry of theirs that we are missing or have an
+//!    older copy of, and their own digest.
+//! 3. We process those entries like any other rumors, then send an `Inject` with every entry of
+//!    ours that they are missing or have an older copy of.
+//!
+//! Only the entries that differ are sent, and anything that changes on either side becomes a hot
+//! rumor, so it spreads to the rest of the ring as usual.
+
+use std::collections::HashMap;
+
+use common;
+use common::gossip_file::GossipFileList;
+use common::message::gossip as proto;
+use common::wire_message::{Protobuf, WIRE_VERSION_BINARY};
+use hcore::service::ServiceGroup;
+
+use census::{CensusEntryId, CensusList};
+use election::{Election, ElectionList, ElectionStatus};
+use gossip::member::{Health, Member, MemberId, MemberList};
+use gossip::rumor::{id_from_proto, Rumor, RumorList};
+
+/// The first wire version whose supervisors understand `Sync`; we never send it to older ones
+pub const SYNC_WIRE_VERSION: u16 = WIRE_VERSION_BINARY;
+
+/// What we know about a member
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct MemberDigest {
+    pub id: MemberId,
+    pub incarnation: u64,
+    pub health: Health,
+}
+
+impl MemberDigest {
+    // At the same incarnation, health only gets worse; departures can't be taken back at all.
+    fn newer_than(&self, theirs: &MemberDigest) -> bool {
+        if self.incarnation != theirs.incarnation {
+            return self.incarnation > theirs.incarnation;
+        }
+        if self.health == Health::Departed {
+            return false;
+        }
+        health_rank(&self.health) > health_rank(&theirs.health)
+    }
+}
+
+/// What we know about a census entry
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct CensusEntryDigest {
+    pub id: CensusEntryId,
+    pub incarnation: u64,
+}
+
+/// What we know about the election in a service group
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct ElectionDigest {
+    pub service_group: String,
+    pub term: u32,
+    pub status: ElectionStatus,
+    pub leader_id: MemberId,
+    pub suitability: u32,
+    pub votes: u64,
+}
+
+impl ElectionDigest {
+    // Within a term, elections merge towards the same result, so any difference is worth
+    // sending; an older term is never worth sending.
+    fn newer_than(&self, theirs: &ElectionDigest) -> bool {
+        if self.term != theirs.term {
+            return self.term > theirs.term;
+        }
+        self != theirs
+    }
+}
+
+/// What we know about a gossip file
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct GossipFileDigest {
+    pub service_group: ServiceGroup,
+    pub file_name: String,
+    pub version: u64,
+}
+
+/// A summary of everything a member knows, small enough to send on every sync.
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct Digest {
+    pub members: Vec<MemberDigest>,
+    pub census_entries: Vec<CensusEntryDigest>,
+    pub elections: Vec<ElectionDigest>,
+    pub gossip_files: Vec<GossipFileDigest>,
+}
+
+impl Digest {
+    /// Take a digest of our current state.
+    pub fn take(member_list: &MemberList,
+                census_list: &CensusList,
+                election_list: &ElectionList,
+                gossip_file_list: &GossipFileList)
+                -> Digest {
+        let members = member_list.values().map(|m| member_digest(m)).collect();
+        let mut census_entries = Vec::new();
+        for (_sg, census) in census_list.iter() {
+            for (_id, ce) in census.iter() {
+                census_entries.push(CensusEntryDigest {
+                    id: ce.id.clone(),
+                    incarnation: *ce.incarnation.time(),
+                });
+            }
+        }
+        let elections = election_list.values().map(|e| election_digest(e)).collect();
+        let gossip_files = gossip_file_list.files()
+            .map(|gf| {
+                GossipFileDigest {
+                    service_group: gf.service_group.clone(),
+                    file_name: gf.file_name.clone(),
+                    version: gf.version_number(),
+                }
+            })
+            .collect();
+        Digest {
+            members: members,
+            census_entries: census_entries,
+            elections: elections,
+            gossip_files: gossip_files,
+        }
+    }
+
+    /// Rumors for every entry we have that the member who took this digest is missing, or has an
+    /// older copy of.
+    pub fn missing_rumors(&self,
+                          member_list: &MemberList,
+                          census_list: &CensusList,
+                          election_list: &ElectionList,
+                          gossip_file_list: &GossipFileList)
+                          -> RumorList {
+        let mut rumors = RumorList::new();
+
+        let members: HashMap<&MemberId, &MemberDigest> =
+            self.members.iter().map(|d| (&d.id, d)).collect();
+        for member in member_list.values() {
+            let newer = match members.get(&member.id) {
+                Some(theirs) => member_digest(member).newer_than(theirs),
+                None => true,
+            };
+            if newer {
+                rumors.add_rumor(Rumor::member(member.clone()));
+            }
+        }
+
+        let census_entries: HashMap<&CensusEntryId, u64> =
+            self.census_entries.iter().map(|d| (&d.id, d.incarnation)).collect();
+        for (_sg, census) in census_list.iter() {
+            for (_id, ce) in census.iter() {
+                let newer = match census_entries.get(&ce.id) {
+                    Some(theirs) => *ce.incarnation.time() > *theirs,
+                    None => true,
+                };
+                if newer {
+                    rumors.add_rumor(Rumor::census_entry(ce.clone()));
+                }
+            }
+        }
+
+        let elections: HashMap<&str, &ElectionDigest> =
+            self.elections.iter().map(|d| (&d.service_group[..], d)).collect();
+        for election in election_list.values() {
+            let ours = election_digest(election);
+            let newer = match elections.get(&ours.service_group[..]) {
+                Some(theirs) => ours.newer_than(theirs),
+                None => true,
+            };
+            if newer {
+                rumors.add_rumor(Rumor::election(election.clone()));
+            }
+        }
+
+        let gossip_files: HashMap<(&ServiceGroup, &str), u64> = self.gossip_files
+            .iter()
+            .map(|d| ((&d.service_group, &d.file_name[..]), d.version))
+            .collect();
+        for gf in gossip_file_list.files() {
+            let newer = match gossip_files.get(&(&gf.service_group, &gf.file_name[..])) {
+                Some(theirs) => gf.version_number() > *theirs,
+                None => true,
+            };
+            if newer {
+                rumors.add_rumor(Rumor::gossip_file(gf.clone()));
+            }
+        }
+
+        rumors
+    }
+}
+
+fn member_digest(member: &Member) -> MemberDigest {
+    MemberDigest {
+        id: member.id.clone(),
+        incarnation: *member.incarnation.time(),
+        health: member.health.clone(),
+    }
+}
+
+fn election_digest(election: &Election) -> ElectionDigest {
+    ElectionDigest {
+        service_group: election.service_group(),
+        term: election.term,
+        status: election.status.clone(),
+        leader_id: election.leader_id.clone(),
+        suitability: election.suitability,
+        votes: election.votes.len() as u64,
+    }
+}
+
+fn health_rank(health: &Health) -> u8 {
+    match *health {
+        Health::Alive => 0,
+        Health::Suspect => 1,
+        Health::Confirmed => 2,
+        Health::Departed => 3,
+    }
+}
+
+impl Protobuf for MemberDigest {
+    type Message = proto::MemberDigest;
+
+    fn to_proto(&self) -> proto::MemberDigest {
+        let mut digest = proto::MemberDigest::new();
+        digest.set_id(self.id.as_bytes().to_vec());
+        digest.set_incarnation(self.incarnation);
+        digest.set_health(self.health.to_proto());
+        digest
+    }
+
+    fn from_proto(digest: proto::MemberDigest) -> common::Result<MemberDigest> {
+        Ok(MemberDigest {
+            id: try!(id_from_proto(digest.get_id())),
+            incarnation: digest.get_incarnation(),
+            health: Health::from_proto(digest.get_health()),
+        })
+    }
+}
+
+impl Protobuf for CensusEntryDigest {
+    type Message = proto::CensusEntryDigest;
+
+    fn to_proto(&self) -> proto::CensusEntryDigest {
+        let mut digest = proto::CensusEntryDigest::new();
+        digest.set_id(self.id.as_bytes().to_vec());
+        digest.set_incarnation(self.incarnation);
+        digest
+    }
+
+    fn from_proto(digest: proto::CensusEntryDigest) -> common::Result<CensusEntryDigest> {
+        Ok(CensusEntryDigest {
+            id: try!(id_from_proto(digest.get_id())),
+            incarnation: digest.get_incarnation(),
+        })
+    }
+}
+
+impl Protobuf for ElectionDigest {
+    type Message = proto::ElectionDigest;
+
+    fn to_proto(&self) -> proto::ElectionDigest {
+        let mut digest = proto::ElectionDigest::new();
+        digest.set_service_group(self.service_group.clone());
+        digest.set_term(self.term);
+        digest.set_status(self.status.to_proto());
+        digest.set_leader_id(self.leader_id.as_bytes().to_vec());
+        digest.set_suitability(self.suitability);
+        digest.set_votes(self.votes);
+        digest
+    }
+
+    fn from_proto(mut digest: proto::ElectionDigest) -> common::Result<ElectionDigest> {
+        Ok(ElectionDigest {
+            service_group: digest.take_service_group(),
+            term: digest.get_term(),
+            status: ElectionStatus::from_proto(digest.get_status()),
+            leader_id: try!(id_from_proto(digest.get_leader_id())),
+            suitability: digest.get_suitability(),
+            votes: digest.get_votes(),
+        })
+    }
+}
+
+impl Protobuf for GossipFileDigest {
+    type Message = proto::GossipFileDigest;
+
+    fn to_proto(&self) -> proto::GossipFileDigest {
+        let mut digest = proto::GossipFileDigest::new();
+        digest.set_service_group(self.service_group.to_proto());
+        digest.set_file_name(self.file_name.clone());
+        digest.set_version(self.version);
+        digest
+    }
+
+    fn from_proto(mut digest: proto::GossipFileDigest) -> common::Result<GossipFileDigest> {
+        Ok(GossipFileDigest {
+            service_group: try!(ServiceGroup::from_proto(digest.take_service_group())),
+            file_name: digest.take_file_name(),
+            version: digest.get_version(),
+        })
+    }
+}
+
+impl Protobuf for Digest {
+    type Message = proto::Digest;
+
+    fn to_proto(&self) -> proto::Digest {
+        let mut digest = proto::Digest::new();
+        for member in self.members.iter() {
+            digest.mut_members().push(member.to_proto());
+        }
+        for ce in self.census_entries.iter() {
+            digest.mut_census_entries().push(ce.to_proto());
+        }
+        for election in self.elections.iter() {
+            digest.mut_elections().push(election.to_proto());
+        }
+        for gf in self.gossip_files.iter() {
+            digest.mut_gossip_files().push(gf.to_proto());
+        }
+        digest
+    }
+
+    fn from_proto(mut digest: proto::Digest) -> common::Result<Digest> {
+        let mut members = Vec::new();
+        for member in digest.take_members().into_iter() {
+            members.push(try!(MemberDigest::from_proto(member)));
+        }
+        let mut census_entries = Vec::new();
+        for ce in digest.take_census_entries().into_iter() {
+            census_entries.push(try!(CensusEntryDigest::from_proto(ce)));
+        }
+        let mut elections = Vec::new();
+        for election in digest.take_elections().into_iter() {
+            elections.push(try!(ElectionDigest::from_proto(election)));
+        }
+        let mut gossip_files = Vec::new();
+        for gf in digest.take_gossip_files().into_iter() {
+            gossip_files.push(try!(GossipFileDigest::from_proto(gf)));
+        }
+        Ok(Digest {
+            members: members,
+            census_entries: census_entries,
+            elections: elections,
+            gossip_files: gossip_files,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, RwLock};
+
+    use census::{Census, CensusEntry, CensusList};
+    use common::gossip_file::{GossipFile, GossipFileList};
+    use election::{Election, ElectionList, ElectionStatus};
+    use gossip::member::{Health, Member, MemberList};
+    use gossip::rumor::RumorList;
+    use gossip::server::process_rumors;
+    use gossip::sync::Digest;
+    use hcore::service::ServiceGroup;
+
+    struct Ring {
+        ml: Arc<RwLock<MemberList>>,
+        rl: Arc<RwLock<RumorList>>,
+        cl: Arc<RwLock<CensusList>>,
+        el: Arc<RwLock<ElectionList>>,
+        gfl: Arc<RwLock<GossipFileList>>,
+    }
+
+    impl Ring {
+        fn new(me: &Member) -> Ring {
+            Ring::with_census_entry(me, CensusEntry::new("redis", "default", me.id.clone()))
+        }
+
+        fn with_census_entry(me: &Member, ce: CensusEntry) -> Ring {
+            Ring {
+                ml: Arc::new(RwLock::new(MemberList::new(me.clone()))),
+                rl: Arc::new(RwLock::new(RumorList::new())),
+                cl: Arc::new(RwLock::new(CensusList::new(Census::new(ce)))),
+                el: Arc::new(RwLock::new(ElectionList::new(String::from("redis.default"),
+                                                           me.id.clone()))),
+                gfl: Arc::new(RwLock::new(GossipFileList::new(ServiceGroup::new("redis",
+                                                                                "default",
+                                                                                None)))),
+            }
+        }
+
+        fn digest(&self) -> Digest {
+            Digest::take(&self.ml.read().unwrap(),
+                         &self.cl.read().unwrap(),
+                         &self.el.read().unwrap(),
+                         &self.gfl.read().unwrap())
+        }
+
+        // The rumors we would send to the member that took `digest`.
+        fn missing_rumors(&self, digest: &Digest) -> RumorList {
+            digest.missing_rumors(&self.ml.read().unwrap(),
+                                  &self.cl.read().unwrap(),
+                                  &self.el.read().unwrap(),
+                                  &self.gfl.read().unwrap())
+        }
+
+        fn process(&self, rumors: RumorList) {
+            process_rumors(rumors,
+                           self.rl.clone(),
+                           self.ml.clone(),
+                           self.cl.clone(),
+                           self.el.clone(),
+                           self.gfl.clone());
+        }
+
+        // One full push-pull exchange, started by us.
+        fn sync_with(&self, other: &Ring) {
+            let ours = self.digest();
+            let for_us = other.missing_rumors(&ours);
+            let theirs = other.digest();
+            self.process(for_us);
+            let for_them = self.missing_rumors(&theirs);
+            other.process(for_them);
+        }
+    }
+
+    fn member(name: &str) -> Member {
+        Member::new(String::from(name),
+                    String::from("192.168.1.1"),
+                    String::from("192.168.1.1:9634"),
+                    false)
+    }
+
+    // The same supervisor's view of the ring, twice.
+    fn twins(me: &Member) -> (Ring, Ring) {
+        let ce = CensusEntry::new("redis", "default", me.id.clone());
+        (Ring::with_census_entry(me, ce.clone()), Ring::with_census_entry(me, ce))
+    }
+
+    fn election(me: &Member, term: u32) -> Election {
+        let mut election = Election::new(String::from("redis"),
+                                         String::from("default"),
+                                         me.id.clone(),
+                                         0,
+                                         term);
+        election.status = ElectionStatus::Finished;
+        election
+    }
+
+    #[test]
+    fn identical_rings_send_nothing() {
+        let me = member("me");
+        let (a, b) = twins(&me);
+        assert_eq!(a.missing_rumors(&b.digest()).len(), 0);
+    }
+
+    #[test]
+    fn does_not_send_older_entries() {
+        let me = member("me");
+        let (a, b) = twins(&me);
+        let mut newer = me.clone();
+        newer.incarnation.increment();
+        b.ml.write().unwrap().process(newer);
+        b.el.write().unwrap().process(election(&me, 2));
+        a.el.write().unwrap().process(election(&me, 1));
+
+        assert_eq!(a.missing_rumors(&b.digest()).len(), 0);
+        assert_eq!(b.missing_rumors(&a.digest()).len(), 2);
+    }
+
+    #[test]
+    fn rejoined_member_converges() {
+        let leader = member("leader");
+        let peer = member("peer");
+        let rejoined = member("rejoined");
+        let ring = Ring::new(&leader);
+        let rejoiner = Ring::new(&rejoined);
+
+        // The ring knows about the rejoined member, but has since moved on without it: the
+        // peer's census entry changed, an election finished and new configuration was published,
+        // and the rumors have all gone cold.
+        let mut peer_ce = CensusEntry::new("redis", "default", peer.id.clone());
+        rejoiner.ml.write().unwrap().process(peer.clone());
+        rejoiner.cl.write().unwrap().process(peer_ce.clone());
+        peer_ce.incarnation.increment();
+        peer_ce.leader(true);
+        ring.ml.write().unwrap().process(peer.clone());
+        ring.ml.write().unwrap().process(rejoined.clone());
+        ring.cl.write().unwrap().process(peer_ce.clone());
+        ring.el.write().unwrap().process(election(&leader, 4));
+        let gf = GossipFile::from_body(ServiceGroup::new("redis", "default", None),
+                                       "port = 6380\n".as_bytes().to_vec(),
+                                       7)
+            .unwrap();
+        ring.gfl.write().unwrap().process(gf);
+
+        // Meanwhile the rejoined member heard about a member the ring missed.
+        let stray = member("stray");
+        rejoiner.ml.write().unwrap().process(stray.clone());
+
+        rejoiner.sync_with(&ring);
+
+        {
+            let ml = rejoiner.ml.read().unwrap();
+            assert!(ml.get(&leader.id).is_some());
+            let cl = rejoiner.cl.read().unwrap();
+            assert!(cl.local_census().get(&peer_ce.id).unwrap().leader);
+            let el = rejoiner.el.read().unwrap();
+            assert_eq!(el.election().unwrap().term, 4);
+            assert_eq!(el.election().unwrap().leader_id, leader.id);
+            let gfl = rejoiner.gfl.read().unwrap();
+            assert_eq!(gfl.config_version(), Some(7));
+        }
+        assert!(ring.ml.read().unwrap().get(&stray.id).is_some());
+
+        // What changed is hot again, so it spreads through the rest of the ring
+        assert!(rejoiner.rl.read().unwrap().contains_key(&peer_ce.id));
+
+        // Once converged, neither side has anything to send
+        assert_eq!(ring.missing_rumors(&rejoiner.digest()).len(), 0);
+        assert_eq!(rejoiner.missing_rumors(&ring.digest()).len(), 0);
+    }
+
+    #[test]
+    fn health_only_gets_worse_within_an_incarnation() {
+        let me = member("me");
+        let mut peer = member("peer");
+        let (a, b) = twins(&me);
+        a.ml.write().unwrap().process(peer.clone());
+        peer.health = Health::Confirmed;
+        b.ml.write().unwrap().process(peer.clone());
+
+        assert_eq!(a.missing_rumors(&b.digest()).len(), 0);
+        assert_eq!(b.missing_rumors(&a.digest()).len(), 1);
+    }
+}
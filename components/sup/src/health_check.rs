@@ -5,9 +5,13 @@
 // the Software until such time that the Software is made available under an
 // open source license such as the Apache 2.0 License.
 
+use std::collections::{vec_deque, VecDeque};
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+use common::message::gossip as proto;
+use time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable)]
 pub enum Status {
     Ok,
     Warning,
@@ -15,6 +19,26 @@ pub enum Status {
     Unknown,
 }
 
+impl Status {
+    pub fn to_proto(&self) -> proto::HealthCheck {
+        match *self {
+            Status::Ok => proto::HealthCheck::OK,
+            Status::Warning => proto::HealthCheck::WARNING,
+            Status::Critical => proto::HealthCheck::CRITICAL,
+            Status::Unknown => proto::HealthCheck::UNKNOWN,
+        }
+    }
+
+    pub fn from_proto(status: proto::HealthCheck) -> Status {
+        match status {
+            proto::HealthCheck::OK => Status::Ok,
+            proto::HealthCheck::WARNING => Status::Warning,
+            proto::HealthCheck::CRITICAL => Status::Critical,
+            proto::HealthCheck::UNKNOWN => Status::Unknown,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CheckResult {
     pub status: Status,
@@ -57,3 +81,89 @@ impl Display for CheckResult {
         write!(f, "{} - {}", status_code, self.output)
     }
 }
+
+/// The most recent health check results, oldest first.
+#[derive(Debug)]
+pub struct History {
+    results: VecDeque<CheckResult>,
+    size: usize,
+    /// How long the most recent timed check took
+    last_duration: Option<Duration>,
+    /// How many results we have recorded, including the ones we have dropped
+    total: u64,
+}
+
+impl History {
+    /// Create a history that keeps the last `size` results.
+    pub fn new(size: usize) -> Self {
+        History {
+            results: VecDeque::with_capacity(size),
+            size: size,
+            last_duration: None,
+            total: 0,
+        }
+    }
+
+    /// Record a result, dropping the oldest one if we are full.
+    pub fn push(&mut self, result: CheckResult) {
+        if self.results.len() >= self.size {
+            self.results.pop_front();
+        }
+        self.results.push_back(result);
+        self.total += 1;
+    }
+
+    /// Record a result, along with how long the check took to run.
+    pub fn record(&mut self, result: CheckResult, duration: Duration) {
+        self.last_duration = Some(duration);
+        self.push(result);
+    }
+
+    /// The most recent result, if we have one.
+    pub fn last(&self) -> Option<&CheckResult> {
+        self.results.back()
+    }
+
+    /// How long the most recent timed check took, if we have timed one.
+    pub fn last_duration(&self) -> Option<Duration> {
+        self.last_duration
+    }
+
+    /// How many results we have recorded since we started.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn iter(&self) -> vec_deque::Iter<CheckResult> {
+        self.results.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::Duration;
+
+    use super::{CheckResult, History};
+
+    #[test]
+    fn history_keeps_the_most_recent_results() {
+        let mut history = History::new(2);
+        assert!(history.last().is_none());
+        history.push(CheckResult::ok(String::from("one")));
+        history.push(CheckResult::critical(String::from("two")));
+        history.push(CheckResult::warning(String::from("three")));
+        let outputs: Vec<&str> = history.iter().map(|r| &r.output[..]).collect();
+        assert_eq!(outputs, vec!["two", "three"]);
+        assert_eq!(history.last().unwrap().output, "three");
+        assert_eq!(history.total(), 3);
+    }
+
+    #[test]
+    fn history_records_durations() {
+        let mut history = History::new(2);
+        assert!(history.last_duration().is_none());
+        history.record(CheckResult::ok(String::from("one")), Duration::milliseconds(250));
+        assert_eq!(history.last_duration(), Some(Duration::milliseconds(250)));
+        assert_eq!(history.total(), 1);
+    }
+}
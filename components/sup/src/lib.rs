@@ -265,8 +265,16 @@ pub mod topology;
 pub mod state_machine;
 pub mod sidecar;
 pub mod health_check;
+pub mod limits;
+pub mod metrics;
+pub mod service_log;
+pub mod watch;
+pub mod dns;
 pub mod config;
+pub mod config_history;
+pub mod schema;
 pub mod service_config;
+pub mod templating;
 pub mod census;
 pub mod gossip;
 pub mod election;
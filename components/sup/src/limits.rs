@@ -0,0 +1,681 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! Resource limits for supervised services.
+//!
+//! Memory and CPU shares are applied through a cgroup named `habitat/<service>`, when a cgroup v1
+//! or v2 filesystem is mounted at `/sys/fs/cgroup`. Without one, the memory limit falls back to an
+//! address space rlimit and CPU shares are not enforced. The supervisor sets the cgroup up before
+//! it spawns the service; the service's process sets its own rlimits and joins the cgroup before
+//! it execs, so the service never runs unlimited.
+//!
+//! The same cgroup is used to report how much memory and CPU the service is using. When there is
+//! no cgroup we fall back to what `/proc` tells us about the service's main process.
+
+use std::ascii::AsciiExt;
+use std::ffi::CString;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::ptr;
+
+use libc::{self, c_int, c_void, pid_t};
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "LM";
+/// Where the cgroup filesystem is mounted
+pub const CGROUP_ROOT: &'static str = "/sys/fs/cgroup";
+/// Where the proc filesystem is mounted
+pub const PROC_ROOT: &'static str = "/proc";
+/// The cgroup every service's cgroup lives under
+const CGROUP_PARENT: &'static str = "habitat";
+
+// Resource numbers from Linux's sys/resource.h
+const RLIMIT_NPROC: c_int = 6;
+const RLIMIT_NOFILE: c_int = 7;
+const RLIMIT_AS: c_int = 9;
+
+#[allow(non_camel_case_types)]
+#[repr(C)]
+struct rlimit64 {
+    rlim_cur: u64,
+    rlim_max: u64,
+}
+
+// Functions from Linux libc.
+extern "C" {
+    fn prlimit64(pid: pid_t,
+                 resource: c_int,
+                 new_limit: *const rlimit64,
+                 old_limit: *mut rlimit64)
+                 -> c_int;
+}
+
+/// The resource limits for a service. Every limit is optional.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// The most memory the service may use, in bytes
+    pub memory: Option<u64>,
+    /// The service's share of CPU time, relative to other services; 1024 is the system default
+    pub cpu_shares: Option<u64>,
+    /// The most files the service may have open at once
+    pub nofile: Option<u64>,
+    /// The most processes the service's user may run at once
+    pub nproc: Option<u64>,
+}
+
+impl Limits {
+    /// Returns true if no limits are set.
+    pub fn is_empty(&self) -> bool {
+        self.memory.is_none() && self.cpu_shares.is_none() && self.nofile.is_none() &&
+        self.nproc.is_none()
+    }
+
+    /// Each limit set here, or in `other` where it isn't set here.
+    pub fn or(&self, other: &Limits) -> Limits {
+        Limits {
+            memory: self.memory.or(other.memory),
+            cpu_shares: self.cpu_shares.or(other.cpu_shares),
+            nofile: self.nofile.or(other.nofile),
+            nproc: self.nproc.or(other.nproc),
+        }
+    }
+
+    /// Set up the cgroup for a service we are about to spawn, and return it along with what the
+    /// service's process has to do to itself before it execs. Failing to set up the cgroup is
+    /// logged, and the service runs without it.
+    pub fn prepare(&self, service: &str) -> (Option<Cgroup>, ChildLimits) {
+        let cgroup = if self.memory.is_none() && self.cpu_shares.is_none() {
+            None
+        } else {
+            match Cgroup::create(Path::new(CGROUP_ROOT), service, self) {
+                Ok(Some(cgroup)) => Some(cgroup),
+                Ok(None) => {
+                    if self.cpu_shares.is_some() {
+                        outputln!(preamble service,
+                                  "No cgroup filesystem at {}; CPU shares will not be enforced",
+                                  CGROUP_ROOT);
+                    }
+                    None
+                }
+                Err(e) => {
+                    outputln!(preamble service, "Failed to set up a cgroup: {}", e);
+                    None
+                }
+            }
+        };
+        if self.memory.is_some() && cgroup.as_ref().map_or(true, |c| c.memory.is_none()) {
+            outputln!(preamble service, "Limiting memory with an address space rlimit");
+        }
+        let child = self.for_child(cgroup.as_ref());
+        (cgroup, child)
+    }
+
+    /// The rlimits the service sets, and the cgroup it joins, before it execs.
+    fn for_child(&self, cgroup: Option<&Cgroup>) -> ChildLimits {
+        let mut rlimits = Vec::new();
+        if let Some(nofile) = self.nofile {
+            rlimits.push((RLIMIT_NOFILE, nofile));
+        }
+        if let Some(nproc) = self.nproc {
+            rlimits.push((RLIMIT_NPROC, nproc));
+        }
+        if let Some(memory) = self.memory {
+            if cgroup.map_or(true, |c| c.memory.is_none()) {
+                rlimits.push((RLIMIT_AS, memory));
+            }
+        }
+        let procs = cgroup.map_or(Vec::new(), |c| {
+            c.dirs()
+                .iter()
+                .map(|dir| dir.join("cgroup.procs"))
+                .filter_map(|path| CString::new(path.as_os_str().as_bytes()).ok())
+                .collect()
+        });
+        ChildLimits {
+            rlimits: rlimits,
+            procs: procs,
+        }
+    }
+}
+
+/// What a service's process does to itself between fork and exec: set its rlimits and join its
+/// cgroup. Everything is worked out up front, so that applying it only makes system calls.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ChildLimits {
+    rlimits: Vec<(c_int, u64)>,
+    /// The `cgroup.procs` file of every controller the service joins
+    procs: Vec<CString>,
+}
+
+impl ChildLimits {
+    /// Apply the limits to the calling process. Only call this in the child, from
+    /// `CommandExt::before_exec`; it must not allocate.
+    ///
+    /// # Errors
+    ///
+    /// * If an rlimit can't be set, or the cgroup can't be joined
+    pub fn apply(&self) -> io::Result<()> {
+        for &(resource, value) in self.rlimits.iter() {
+            try!(set_rlimit(0, resource, value));
+        }
+        if self.procs.is_empty() {
+            return Ok(());
+        }
+        let mut buf = [0u8; 20];
+        let pid = format_pid(unsafe { libc::getpid() } as u32, &mut buf);
+        for path in self.procs.iter() {
+            let fd = unsafe { libc::open(path.as_ptr(), libc::O_WRONLY) };
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let rc = unsafe { libc::write(fd, pid.as_ptr() as *const c_void, pid.len()) };
+            let err = io::Error::last_os_error();
+            unsafe { libc::close(fd) };
+            if rc < 0 {
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Limits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(memory) = self.memory {
+            parts.push(format!("memory {}", format_bytes(memory)));
+        }
+        if let Some(shares) = self.cpu_shares {
+            parts.push(format!("{} cpu shares", shares));
+        }
+        if let Some(nofile) = self.nofile {
+            parts.push(format!("{} open files", nofile));
+        }
+        if let Some(nproc) = self.nproc {
+            parts.push(format!("{} processes", nproc));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CgroupVersion {
+    V1,
+    V2,
+}
+
+/// The cgroup a service runs in. With cgroup v2 the memory and cpu controllers share one
+/// directory; with v1 each controller has its own hierarchy, and may not be mounted at all.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Cgroup {
+    pub version: CgroupVersion,
+    /// The service's directory in the memory controller
+    pub memory: Option<PathBuf>,
+    /// The service's directory in the cpu controller
+    pub cpu: Option<PathBuf>,
+}
+
+impl Cgroup {
+    /// Create the cgroup for a service under the cgroup filesystem at `root`, or reuse it if it
+    /// exists, and set its limits. The service joins it itself, before it execs. Returns `None`
+    /// if there is no cgroup filesystem at `root`.
+    ///
+    /// # Errors
+    ///
+    /// * If the cgroup can't be created or limited
+    pub fn create(root: &Path, service: &str, limits: &Limits) -> Result<Option<Cgroup>> {
+        let cgroup = if root.join("cgroup.controllers").is_file() {
+            try!(Cgroup::create_v2(root, service))
+        } else if root.join("memory").is_dir() || root.join("cpu").is_dir() {
+            try!(Cgroup::create_v1(root, service))
+        } else {
+            return Ok(None);
+        };
+        try!(cgroup.set_limits(limits));
+        Ok(Some(cgroup))
+    }
+
+    fn create_v2(root: &Path, service: &str) -> Result<Cgroup> {
+        // A cgroup can only use the controllers its parent has enabled for its children
+        let parent = root.join(CGROUP_PARENT);
+        for dir in [root, parent.as_path()].iter() {
+            try!(fs::create_dir_all(dir));
+            try!(write_file(dir.join("cgroup.subtree_control"), "+memory"));
+            try!(write_file(dir.join("cgroup.subtree_control"), "+cpu"));
+        }
+        let dir = parent.join(service);
+        try!(fs::create_dir_all(&dir));
+        Ok(Cgroup {
+            version: CgroupVersion::V2,
+            memory: Some(dir.clone()),
+            cpu: Some(dir),
+        })
+    }
+
+    fn create_v1(root: &Path, service: &str) -> Result<Cgroup> {
+        let mut cgroup = Cgroup {
+            version: CgroupVersion::V1,
+            memory: None,
+            cpu: None,
+        };
+        if root.join("memory").is_dir() {
+            let dir = root.join("memory").join(CGROUP_PARENT).join(service);
+            try!(fs::create_dir_all(&dir));
+            cgroup.memory = Some(dir);
+        }
+        if root.join("cpu").is_dir() {
+            let dir = root.join("cpu").join(CGROUP_PARENT).join(service);
+            try!(fs::create_dir_all(&dir));
+            cgroup.cpu = Some(dir);
+        }
+        Ok(cgroup)
+    }
+
+    fn set_limits(&self, limits: &Limits) -> Result<()> {
+        if let (Some(bytes), Some(dir)) = (limits.memory, self.memory.as_ref()) {
+            let file = match self.version {
+                CgroupVersion::V1 => "memory.limit_in_bytes",
+                CgroupVersion::V2 => "memory.max",
+            };
+            try!(write_file(dir.join(file), &bytes.to_string()));
+        }
+        if let (Some(shares), Some(dir)) = (limits.cpu_shares, self.cpu.as_ref()) {
+            match self.version {
+                CgroupVersion::V1 => try!(write_file(dir.join("cpu.shares"), &shares.to_string())),
+                CgroupVersion::V2 => {
+                    try!(write_file(dir.join("cpu.weight"),
+                                    &shares_to_weight(shares).to_string()))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn dirs(&self) -> Vec<&PathBuf> {
+        let mut dirs: Vec<&PathBuf> = self.memory.iter().chain(self.cpu.iter()).collect();
+        dirs.dedup();
+        dirs
+    }
+
+    /// The memory in use by every process in the cgroup, in bytes
+    pub fn memory_usage(&self) -> Option<u64> {
+        let file = match self.version {
+            CgroupVersion::V1 => "memory.usage_in_bytes",
+            CgroupVersion::V2 => "memory.current",
+        };
+        self.memory.as_ref().and_then(|dir| read_u64(dir.join(file)))
+    }
+
+    /// The CPU time used by every process in the cgroup, in microseconds
+    pub fn cpu_usage(&self) -> Option<u64> {
+        let dir = match self.cpu {
+            Some(ref dir) => dir,
+            None => return None,
+        };
+        match self.version {
+            CgroupVersion::V1 => read_u64(dir.join("cpuacct.usage")).map(|ns| ns / 1000),
+            CgroupVersion::V2 => {
+                read_file(dir.join("cpu.stat")).and_then(|stat| {
+                    stat.lines()
+                        .filter_map(|line| {
+                            let mut words = line.split_whitespace();
+                            match (words.next(), words.next()) {
+                                (Some("usage_usec"), Some(usecs)) => usecs.parse().ok(),
+                                _ => None,
+                            }
+                        })
+                        .next()
+                })
+            }
+        }
+    }
+
+    /// How many processes are in the cgroup
+    pub fn processes(&self) -> Option<u64> {
+        self.dirs().first().and_then(|dir| read_file(dir.join("cgroup.procs"))).map(|procs| {
+            procs.lines().filter(|l| !l.trim().is_empty()).count() as u64
+        })
+    }
+}
+
+/// How much of the system a running service is using. Anything we couldn't find out is `None`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Memory in use, in bytes
+    pub memory: Option<u64>,
+    /// CPU time used, in microseconds
+    pub cpu: Option<u64>,
+    /// Files the service's main process has open
+    pub open_files: Option<u64>,
+    /// Processes in the service's cgroup
+    pub processes: Option<u64>,
+}
+
+impl Usage {
+    /// Read the usage of the service whose main process is `pid`.
+    pub fn read(pid: u32, cgroup: Option<&Cgroup>) -> Usage {
+        Usage::read_from(Path::new(PROC_ROOT), pid, cgroup)
+    }
+
+    fn read_from(proc_root: &Path, pid: u32, cgroup: Option<&Cgroup>) -> Usage {
+        let proc_dir = proc_root.join(pid.to_string());
+        let mut usage = Usage::default();
+        if let Some(cgroup) = cgroup {
+            usage.memory = cgroup.memory_usage();
+            usage.cpu = cgroup.cpu_usage();
+            usage.processes = cgroup.processes();
+        }
+        if usage.memory.is_none() {
+            usage.memory = read_file(proc_dir.join("status")).and_then(|status| vm_rss(&status));
+        }
+        usage.open_files = fs::read_dir(proc_dir.join("fd")).ok().map(|fds| fds.count() as u64);
+        usage
+    }
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(memory) = self.memory {
+            parts.push(format!("memory {}", format_bytes(memory)));
+        }
+        if let Some(cpu) = self.cpu {
+            parts.push(format!("cpu {}.{:02}s", cpu / 1000000, (cpu % 1000000) / 10000));
+        }
+        if let Some(open_files) = self.open_files {
+            parts.push(format!("{} open files", open_files));
+        }
+        if let Some(processes) = self.processes {
+            parts.push(format!("{} processes", processes));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/// Parse a number of bytes, with an optional K, M, G or T suffix for powers of 1024.
+///
+/// # Errors
+///
+/// * If the string is not a number of bytes
+pub fn parse_bytes(s: &str) -> Result<u64> {
+    let s = s.trim();
+    let (digits, multiplier) = match s.chars().last().map(|c| c.to_ascii_uppercase()) {
+        Some('K') => (&s[..s.len() - 1], 1024),
+        Some('M') => (&s[..s.len() - 1], 1024 * 1024),
+        Some('G') => (&s[..s.len() - 1], 1024 * 1024 * 1024),
+        Some('T') => (&s[..s.len() - 1], 1024 * 1024 * 1024 * 1024),
+        _ => (s, 1),
+    };
+    match digits.parse::<u64>() {
+        Ok(n) => {
+            match n.checked_mul(multiplier) {
+                Some(bytes) => Ok(bytes),
+                None => Err(sup_error!(Error::InvalidLimit(s.to_string()))),
+            }
+        }
+        Err(_) => Err(sup_error!(Error::InvalidLimit(s.to_string()))),
+    }
+}
+
+/// Convert cgroup v1 CPU shares (2 to 262144, default 1024) to a cgroup v2 CPU weight (1 to
+/// 10000, default 100).
+fn shares_to_weight(shares: u64) -> u64 {
+    let shares = if shares < 2 {
+        2
+    } else if shares > 262144 {
+        262144
+    } else {
+        shares
+    };
+    1 + ((shares - 2) * 9999) / 262142
+}
+
+fn format_bytes(bytes: u64) -> String {
+    format!("{:.1}MiB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+/// Find the resident set size, in bytes, in the contents of `/proc/<pid>/status`
+fn vm_rss(status: &str) -> Option<u64> {
+    status.lines()
+        .filter(|line| line.starts_with("VmRSS:"))
+        .filter_map(|line| line.split_whitespace().nth(1))
+        .filter_map(|kb| kb.parse::<u64>().ok())
+        .map(|kb| kb * 1024)
+        .next()
+}
+
+/// Write `pid` in decimal into `buf`, without allocating, and return the digits.
+fn format_pid(mut pid: u32, buf: &mut [u8; 20]) -> &[u8] {
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (pid % 10) as u8;
+        pid /= 10;
+        if pid == 0 {
+            break;
+        }
+    }
+    &buf[start..]
+}
+
+/// Set a resource limit on `pid`, or on the calling process if `pid` is 0.
+fn set_rlimit(pid: u32, resource: c_int, value: u64) -> io::Result<()> {
+    let limit = rlimit64 {
+        rlim_cur: value,
+        rlim_max: value,
+    };
+    let rc = unsafe { prlimit64(pid as pid_t, resource, &limit, ptr::null_mut()) };
+    if rc == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+fn write_file<P: AsRef<Path>>(path: P, content: &str) -> Result<()> {
+    let mut file = try!(File::create(path.as_ref()));
+    try!(file.write_all(content.as_bytes()));
+    Ok(())
+}
+
+fn read_file<P: AsRef<Path>>(path: P) -> Option<String> {
+    let mut body = String::new();
+    match File::open(path.as_ref()).and_then(|mut f| f.read_to_string(&mut body)) {
+        Ok(_) => Some(body),
+        Err(_) => None,
+    }
+}
+
+fn read_u64<P: AsRef<Path>>(path: P) -> Option<u64> {
+    read_file(path).and_then(|body| body.trim().parse().ok())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::{Read, Write};
+    use std::path::Path;
+
+    use tempdir::TempDir;
+
+    use super::{format_pid, parse_bytes, shares_to_weight, vm_rss, Cgroup, CgroupVersion,
+                Limits, Usage, RLIMIT_AS, RLIMIT_NOFILE};
+
+    fn write(path: &Path, content: &str) {
+        let mut file = File::create(path).unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+    }
+
+    fn read(path: &Path) -> String {
+        let mut body = String::new();
+        let mut file = File::open(path).unwrap();
+        file.read_to_string(&mut body).unwrap();
+        body
+    }
+
+    fn limits() -> Limits {
+        Limits {
+            memory: Some(512 * 1024 * 1024),
+            cpu_shares: Some(512),
+            nofile: None,
+            nproc: None,
+        }
+    }
+
+    #[test]
+    fn parse_bytes_with_suffixes() {
+        assert_eq!(parse_bytes("100").unwrap(), 100);
+        assert_eq!(parse_bytes("4k").unwrap(), 4096);
+        assert_eq!(parse_bytes("512M").unwrap(), 512 * 1024 * 1024);
+        assert_eq!(parse_bytes("2G").unwrap(), 2 * 1024 * 1024 * 1024);
+        assert!(parse_bytes("lots").is_err());
+        assert!(parse_bytes("-1M").is_err());
+        assert!(parse_bytes("99999999999T").is_err());
+    }
+
+    #[test]
+    fn shares_to_weight_matches_defaults() {
+        assert_eq!(shares_to_weight(2), 1);
+        assert_eq!(shares_to_weight(1024), 39);
+        assert_eq!(shares_to_weight(262144), 10000);
+        assert_eq!(shares_to_weight(0), 1);
+        assert_eq!(shares_to_weight(1000000), 10000);
+    }
+
+    #[test]
+    fn limits_is_empty() {
+        assert!(Limits::default().is_empty());
+        assert!(!limits().is_empty());
+    }
+
+    #[test]
+    fn no_cgroup_filesystem() {
+        let root = TempDir::new("cgroup").unwrap();
+        assert!(Cgroup::create(root.path(), "redis", &limits()).unwrap().is_none());
+    }
+
+    #[test]
+    fn create_v1() {
+        let root = TempDir::new("cgroup").unwrap();
+        fs::create_dir(root.path().join("memory")).unwrap();
+        fs::create_dir(root.path().join("cpu")).unwrap();
+        let cgroup = Cgroup::create(root.path(), "redis", &limits()).unwrap().unwrap();
+        assert_eq!(cgroup.version, CgroupVersion::V1);
+        let memory = root.path().join("memory/habitat/redis");
+        let cpu = root.path().join("cpu/habitat/redis");
+        assert_eq!(cgroup.memory, Some(memory.clone()));
+        assert_eq!(cgroup.cpu, Some(cpu.clone()));
+        assert_eq!(read(&memory.join("memory.limit_in_bytes")), "536870912");
+        assert_eq!(read(&cpu.join("cpu.shares")), "512");
+
+        write(&memory.join("cgroup.procs"), "42\n43\n");
+        write(&memory.join("memory.usage_in_bytes"), "1048576\n");
+        write(&cpu.join("cpuacct.usage"), "2500000000\n");
+        assert_eq!(cgroup.memory_usage(), Some(1048576));
+        assert_eq!(cgroup.cpu_usage(), Some(2500000));
+        assert_eq!(cgroup.processes(), Some(2));
+    }
+
+    #[test]
+    fn create_v1_without_memory_controller() {
+        let root = TempDir::new("cgroup").unwrap();
+        fs::create_dir(root.path().join("cpu")).unwrap();
+        let cgroup = Cgroup::create(root.path(), "redis", &limits()).unwrap().unwrap();
+        assert!(cgroup.memory.is_none());
+        assert!(cgroup.cpu.is_some());
+        assert_eq!(cgroup.memory_usage(), None);
+    }
+
+    #[test]
+    fn create_v2() {
+        let root = TempDir::new("cgroup").unwrap();
+        write(&root.path().join("cgroup.controllers"), "cpu memory\n");
+        let cgroup = Cgroup::create(root.path(), "redis", &limits()).unwrap().unwrap();
+        assert_eq!(cgroup.version, CgroupVersion::V2);
+        let dir = root.path().join("habitat/redis");
+        assert_eq!(cgroup.memory, Some(dir.clone()));
+        assert_eq!(cgroup.cpu, Some(dir.clone()));
+        assert_eq!(read(&dir.join("memory.max")), "536870912");
+        assert_eq!(read(&dir.join("cpu.weight")), "20");
+
+        write(&dir.join("cgroup.procs"), "42\n");
+        write(&dir.join("memory.current"), "2097152\n");
+        write(&dir.join("cpu.stat"),
+              "usage_usec 1500000\nuser_usec 1000000\nsystem_usec 500000\n");
+        assert_eq!(cgroup.memory_usage(), Some(2097152));
+        assert_eq!(cgroup.cpu_usage(), Some(1500000));
+        assert_eq!(cgroup.processes(), Some(1));
+    }
+
+    #[test]
+    fn child_joins_every_controller() {
+        let root = TempDir::new("cgroup").unwrap();
+        fs::create_dir(root.path().join("memory")).unwrap();
+        fs::create_dir(root.path().join("cpu")).unwrap();
+        let mut limits = limits();
+        limits.nofile = Some(4096);
+        let cgroup = Cgroup::create(root.path(), "redis", &limits).unwrap().unwrap();
+        let child = limits.for_child(Some(&cgroup));
+        assert_eq!(child.rlimits, vec![(RLIMIT_NOFILE, 4096)]);
+        let procs: Vec<String> =
+            child.procs.iter().map(|p| p.to_string_lossy().into_owned()).collect();
+        let memory = root.path().join("memory/habitat/redis/cgroup.procs");
+        let cpu = root.path().join("cpu/habitat/redis/cgroup.procs");
+        assert_eq!(procs,
+                   vec![memory.to_string_lossy().into_owned(),
+                        cpu.to_string_lossy().into_owned()]);
+    }
+
+    #[test]
+    fn child_limits_memory_without_a_cgroup() {
+        let child = limits().for_child(None);
+        assert_eq!(child.rlimits, vec![(RLIMIT_AS, 512 * 1024 * 1024)]);
+        assert!(child.procs.is_empty());
+    }
+
+    #[test]
+    fn limits_or() {
+        let flags = Limits { nofile: Some(1024), ..Limits::default() };
+        let merged = flags.or(&limits());
+        assert_eq!(merged.nofile, Some(1024));
+        assert_eq!(merged.memory, Some(512 * 1024 * 1024));
+        assert_eq!(merged.nproc, None);
+    }
+
+    #[test]
+    fn format_pid_digits() {
+        let mut buf = [0u8; 20];
+        assert_eq!(format_pid(0, &mut buf), b"0");
+        assert_eq!(format_pid(42, &mut buf), b"42");
+        assert_eq!(format_pid(4294967295, &mut buf), b"4294967295");
+    }
+
+    #[test]
+    fn usage_falls_back_to_proc() {
+        let proc_root = TempDir::new("proc").unwrap();
+        let proc_dir = proc_root.path().join("42");
+        fs::create_dir_all(proc_dir.join("fd")).unwrap();
+        write(&proc_dir.join("fd/0"), "");
+        write(&proc_dir.join("fd/1"), "");
+        write(&proc_dir.join("status"),
+              "Name:\tredis-server\nVmPeak:\t  9000 kB\nVmRSS:\t  2048 kB\n");
+        let usage = Usage::read_from(proc_root.path(), 42, None);
+        assert_eq!(usage.memory, Some(2048 * 1024));
+        assert_eq!(usage.open_files, Some(2));
+        assert_eq!(usage.cpu, None);
+        assert_eq!(format!("{}", usage), "memory 2.0MiB, 2 open files");
+    }
+
+    #[test]
+    fn vm_rss_missing() {
+        assert_eq!(vm_rss("Name:\tkthreadd\n"), None);
+    }
+}
@@ -27,11 +27,14 @@ use hcore::env as henv;
 use hcore::fs;
 use hcore::crypto::{default_cache_key_path, SymKey};
 use hcore::crypto::init as crypto_init;
+use hcore::crypto::keys::parse_name_with_rev;
 use hcore::package::PackageIdent;
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
-use sup::config::{Command, Config, UpdateStrategy};
+use sup::config::{Command, Config, RestartPolicy, UpdateStrategy};
+use sup::config_history;
 use sup::error::{Error, Result, SupError};
+use sup::limits::{self, Limits};
 use sup::command::*;
 use sup::topology::Topology;
 use sup::util::parse_ip_port_with_defaults;
@@ -50,10 +53,21 @@ static DEFAULT_GROUP: &'static str = "default";
 static DEFAULT_HTTP_LISTEN_IP: &'static str = "0.0.0.0";
 static DEFAULT_HTTP_LISTEN_PORT: u16 = 9631;
 
+const DEFAULT_DNS_LISTEN_PORT: u16 = 53;
+
 const DEFAULT_GOSSIP_LISTEN_PORT: u16 = 9634;
 
+const DEFAULT_ROLLBACK_WINDOW_SECS: u64 = 120;
+const DEFAULT_RESTART_BACKOFF_MAX_SECS: u64 = 60;
+const DEFAULT_RESTART_MAX: u32 = 0;
+const DEFAULT_RESTART_WINDOW_SECS: u64 = 300;
+const DEFAULT_HEALTH_CHECK_INTERVAL_SECS: u64 = 30;
+const DEFAULT_LOG_MAX_BYTES: u64 = 10 * 1024 * 1024;
+const DEFAULT_LOG_MAX_FILES: usize = 5;
+
 static RING_ENVVAR: &'static str = "HAB_RING";
 static RING_KEY_ENVVAR: &'static str = "HAB_RING_KEY";
+static CONTROL_TOKEN_ENVVAR: &'static str = "HAB_CONTROL_TOKEN";
 
 /// Creates a [Config](config/struct.Config.html) from global args
 /// and subcommand args.
@@ -64,6 +78,34 @@ fn config_from_args(args: &ArgMatches, subcommand: &str, sub_args: &ArgMatches)
     if let Some(ref strategy) = sub_args.value_of("strategy") {
         config.set_update_strategy(UpdateStrategy::from_str(strategy));
     }
+    config.set_rollback_window(value_t!(sub_args, "rollback-window", u64)
+        .unwrap_or(DEFAULT_ROLLBACK_WINDOW_SECS));
+    if let Some(ref policy) = sub_args.value_of("restart-policy") {
+        config.set_restart_policy(RestartPolicy::from_str(policy));
+    }
+    config.set_restart_backoff_max(value_t!(sub_args, "restart-backoff-max", u64)
+        .unwrap_or(DEFAULT_RESTART_BACKOFF_MAX_SECS));
+    config.set_restart_max(value_t!(sub_args, "restart-max", u32).unwrap_or(DEFAULT_RESTART_MAX));
+    config.set_restart_window(value_t!(sub_args, "restart-window", u64)
+        .unwrap_or(DEFAULT_RESTART_WINDOW_SECS));
+    config.set_health_check_interval(value_t!(sub_args, "health-check-interval", u64)
+        .unwrap_or(DEFAULT_HEALTH_CHECK_INTERVAL_SECS));
+    let mut service_limits = Limits::default();
+    if let Some(memory) = sub_args.value_of("limit-memory") {
+        service_limits.memory = Some(try!(limits::parse_bytes(memory)));
+    }
+    service_limits.cpu_shares = value_t!(sub_args, "limit-cpu-shares", u64).ok();
+    service_limits.nofile = value_t!(sub_args, "limit-nofile", u64).ok();
+    service_limits.nproc = value_t!(sub_args, "limit-nproc", u64).ok();
+    config.set_limits(service_limits);
+    match sub_args.value_of("log-max-bytes") {
+        Some(bytes) => config.set_log_max_bytes(try!(limits::parse_bytes(bytes))),
+        None => config.set_log_max_bytes(DEFAULT_LOG_MAX_BYTES),
+    };
+    config.set_log_max_files(value_t!(sub_args, "log-max-files", usize)
+        .unwrap_or(DEFAULT_LOG_MAX_FILES));
+    config.set_config_history(value_t!(sub_args, "config-history", usize)
+        .unwrap_or(config_history::DEFAULT_KEEP));
     if let Some(ref archive) = sub_args.value_of("archive") {
         config.set_archive(archive.to_string());
     }
@@ -151,6 +193,35 @@ fn config_from_args(args: &ArgMatches, subcommand: &str, sub_args: &ArgMatches)
 
     config.set_http_listen_ip(sidecar_ip);
     config.set_http_listen_port(sidecar_port);
+    if sub_args.is_present("listen-dns") {
+        let (dns_ip, dns_port) = try!(parse_ip_port_with_defaults(
+                                        sub_args.value_of("listen-dns"),
+                                        DEFAULT_HTTP_LISTEN_IP,
+                                        DEFAULT_DNS_LISTEN_PORT));
+        debug!("DNS IP = {}", &dns_ip);
+        debug!("DNS port = {}", &dns_port);
+        config.set_dns_listen(dns_ip, dns_port);
+    }
+    match sub_args.value_of("control-token") {
+        Some(token) => {
+            config.set_control_token(token.to_string());
+        }
+        None => {
+            if let Ok(token) = henv::var(CONTROL_TOKEN_ENVVAR) {
+                config.set_control_token(token);
+            }
+        }
+    }
+
+    if let Some(file) = sub_args.value_of("dry-run") {
+        config.set_dry_run(file.to_string());
+    }
+    if let Some(users) = sub_args.values_of("config-trusted-user") {
+        config.set_config_trusted_users(users.map(|s| s.to_string()).collect());
+    }
+    if let Some(users) = sub_args.values_of("control-trusted-user") {
+        config.set_control_trusted_users(users.map(|s| s.to_string()).collect());
+    }
 
     let gossip_peers = match sub_args.values_of("peer") {
         Some(gp) => gp.map(|s| s.to_string()).collect(),
@@ -168,7 +239,7 @@ fn config_from_args(args: &ArgMatches, subcommand: &str, sub_args: &ArgMatches)
     }
     config.set_version_number(value_t!(sub_args, "version-number", u64).unwrap_or(0));
     let ring = match sub_args.value_of("ring") {
-        Some(val) => Some(try!(SymKey::get_latest_pair_for(&val, &default_cache_key_path(None)))),
+        Some(val) => Some(try!(ring_key(val))),
         None => {
             match henv::var(RING_KEY_ENVVAR) {
                 Ok(val) => {
@@ -178,10 +249,7 @@ fn config_from_args(args: &ArgMatches, subcommand: &str, sub_args: &ArgMatches)
                 }
                 Err(_) => {
                     match henv::var(RING_ENVVAR) {
-                        Ok(val) => {
-                            Some(try!(SymKey::get_latest_pair_for(&val,
-                                                                  &default_cache_key_path(None))))
-                        }
+                        Ok(val) => Some(try!(ring_key(&val))),
                         Err(_) => None,
                     }
                 }
@@ -197,6 +265,9 @@ fn config_from_args(args: &ArgMatches, subcommand: &str, sub_args: &ArgMatches)
     if args.value_of("no-color").is_some() {
         sup::output::set_no_color(true);
     }
+    if args.is_present("json-output") {
+        sup::output::set_json(true);
+    }
 
     if let Some(org) = sub_args.value_of("organization") {
         config.set_organization(org.to_string());
@@ -205,6 +276,17 @@ fn config_from_args(args: &ArgMatches, subcommand: &str, sub_args: &ArgMatches)
     Ok(config)
 }
 
+/// Loads a ring key from the key cache: the revision `val` names, if it names one, or else the
+/// newest revision of it.
+fn ring_key(val: &str) -> Result<SymKey> {
+    let cache = default_cache_key_path(None);
+    if parse_name_with_rev(val).is_ok() {
+        Ok(try!(SymKey::get_pair_for(val, &cache)))
+    } else {
+        Ok(try!(SymKey::get_latest_pair_for(val, &cache)))
+    }
+}
+
 type Handler = fn(&Config) -> result::Result<(), sup::error::SupError>;
 
 /// The entrypoint for the Supervisor.
@@ -244,7 +326,7 @@ fn main() {
             .long("strategy")
             .short("s")
             .takes_value(true)
-            .possible_values(&["none", "at-once"])
+            .possible_values(&["none", "at-once", "rolling"])
             .help("The update strategy; [default: none].")
     };
 
@@ -259,6 +341,58 @@ fn main() {
         .arg(arg_group())
         .arg(arg_org())
         .arg(arg_strategy())
+        .arg(Arg::with_name("rollback-window")
+            .long("rollback-window")
+            .value_name("seconds")
+            .help("Roll back a package update that fails within this many seconds; 0 disables \
+                   [default: 120]"))
+        .arg(Arg::with_name("restart-policy")
+            .long("restart-policy")
+            .takes_value(true)
+            .possible_values(&["always", "on-failure", "never"])
+            .help("When to restart the service if it exits; [default: always]"))
+        .arg(Arg::with_name("restart-backoff-max")
+            .long("restart-backoff-max")
+            .value_name("seconds")
+            .help("The longest to wait before restarting a crashing service [default: 60]"))
+        .arg(Arg::with_name("restart-max")
+            .long("restart-max")
+            .value_name("count")
+            .help("Give up after this many restarts within the restart window; 0 means never \
+                   [default: 0]"))
+        .arg(Arg::with_name("restart-window")
+            .long("restart-window")
+            .value_name("seconds")
+            .help("The window over which restarts are counted [default: 300]"))
+        .arg(Arg::with_name("health-check-interval")
+            .long("health-check-interval")
+            .value_name("seconds")
+            .help("How often to run the health check; 0 disables [default: 30]"))
+        .arg(Arg::with_name("limit-memory")
+            .long("limit-memory")
+            .value_name("bytes")
+            .help("The most memory the service may use, with an optional K, M, G or T suffix"))
+        .arg(Arg::with_name("limit-cpu-shares")
+            .long("limit-cpu-shares")
+            .value_name("shares")
+            .help("The service's share of CPU time, relative to the default of 1024"))
+        .arg(Arg::with_name("limit-nofile")
+            .long("limit-nofile")
+            .value_name("count")
+            .help("The most files the service may have open at once"))
+        .arg(Arg::with_name("limit-nproc")
+            .long("limit-nproc")
+            .value_name("count")
+            .help("The most processes the service's user may run at once"))
+        .arg(Arg::with_name("log-max-bytes")
+            .long("log-max-bytes")
+            .value_name("bytes")
+            .help("Rotate the service's log file at this size, with an optional K, M, G or T \
+                   suffix; 0 never rotates it [default: 10M]"))
+        .arg(Arg::with_name("log-max-files")
+            .long("log-max-files")
+            .value_name("count")
+            .help("How many rotated log files to keep [default: 5]"))
         .arg(Arg::with_name("topology")
             .short("t")
             .long("topology")
@@ -273,7 +407,7 @@ fn main() {
             .short("r")
             .long("ring")
             .value_name("ring")
-            .help("Ring key name"))
+            .help("Ring key name, with a revision to use other than the newest"))
         .arg(Arg::with_name("peer")
             .long("peer")
             .value_name("ip:port")
@@ -287,6 +421,32 @@ fn main() {
             .long("listen-http")
             .value_name("ip:port")
             .help("The HTTP API listen address [default: 0.0.0.0:9631]"))
+        .arg(Arg::with_name("listen-dns")
+            .long("listen-dns")
+            .value_name("ip:port")
+            .help("Answer DNS queries for service groups under .hab on this address [port \
+                   default: 53]"))
+        .arg(Arg::with_name("control-token")
+            .long("control-token")
+            .value_name("token")
+            .help("A shared token that authorizes requests to the HTTP control API; also read \
+                   from HAB_CONTROL_TOKEN"))
+        .arg(Arg::with_name("control-trusted-user")
+            .long("control-trusted-user")
+            .value_name("user")
+            .multiple(true)
+            .help("Accept control requests signed by this user; may be given more than once"))
+        .arg(Arg::with_name("config-trusted-user")
+            .long("config-trusted-user")
+            .value_name("user")
+            .multiple(true)
+            .help("Only accept configuration updates signed by this user; may be given more \
+                   than once"))
+        .arg(Arg::with_name("config-history")
+            .long("config-history")
+            .value_name("count")
+            .help("How many rendered configurations to keep for rollback; 0 keeps none \
+                   [default: 10]"))
         .arg(Arg::with_name("permanent-peer")
             .short("I")
             .long("permanent-peer")
@@ -301,7 +461,12 @@ fn main() {
         .arg(Arg::with_name("package")
             .index(1)
             .required(true)
-            .help("Name of package"));
+            .help("Name of package"))
+        .arg(Arg::with_name("dry-run")
+            .long("dry-run")
+            .value_name("file")
+            .help("Show how the configuration files would change with this file, or - for \
+                   stdin, as the gossip configuration"));
     let args = App::new(sup::PROGRAM_NAME.as_str())
         .version(VERSION)
         .setting(AppSettings::VersionlessSubcommands)
@@ -314,6 +479,10 @@ fn main() {
             .long("no-color")
             .global(true)
             .help("Turn ANSI color off :("))
+        .arg(Arg::with_name("json-output")
+            .long("json-output")
+            .global(true)
+            .help("Print output as JSON lines"))
         .subcommand(sub_start)
         .subcommand(sub_bash)
         .subcommand(sub_sh)
@@ -365,7 +534,10 @@ fn shell_bash(_config: &Config) -> Result<()> {
 /// Show the configuration options for a service
 #[allow(dead_code)]
 fn configure(config: &Config) -> Result<()> {
-    try!(configure::display(config));
+    match *config.dry_run() {
+        Some(_) => try!(configure::dry_run(config)),
+        None => try!(configure::display(config)),
+    }
     Ok(())
 }
 
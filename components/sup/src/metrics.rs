@@ -0,0 +1,118 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! Metrics in the Prometheus text exposition format.
+//!
+//! The sidecar's `/metrics` route builds a `Metrics`, adding a family (its name, type and help
+//! text) followed by the samples in that family, and returns it as the body of the response.
+//! Every metric name is prefixed with `hab_sup_`.
+
+use std::fmt::Write;
+
+/// The prefix of every metric we export
+pub const PREFIX: &'static str = "hab_sup_";
+
+/// The content type Prometheus expects for the text format
+pub const CONTENT_TYPE: &'static str = "text/plain; version=0.0.4";
+
+/// The type of a metric family
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Counter,
+    Gauge,
+}
+
+/// A set of metrics, written out in the text format as they are added.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    out: String,
+}
+
+impl Metrics {
+    pub fn new() -> Metrics {
+        Metrics::default()
+    }
+
+    /// Start a family of metrics. The samples that follow, up to the next family, belong to it.
+    pub fn family(&mut self, name: &str, kind: Kind, help: &str) -> &mut Metrics {
+        let kind = match kind {
+            Kind::Counter => "counter",
+            Kind::Gauge => "gauge",
+        };
+        let _ = writeln!(self.out, "# HELP {}{} {}", PREFIX, name, escape_help(help));
+        let _ = writeln!(self.out, "# TYPE {}{} {}", PREFIX, name, kind);
+        self
+    }
+
+    /// Add a sample with the given labels.
+    pub fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: f64) -> &mut Metrics {
+        let _ = write!(self.out, "{}{}", PREFIX, name);
+        if !labels.is_empty() {
+            let labels: Vec<String> = labels.iter()
+                .map(|&(k, v)| format!("{}=\"{}\"", k, escape_label(v)))
+                .collect();
+            let _ = write!(self.out, "{{{}}}", labels.join(","));
+        }
+        let _ = writeln!(self.out, " {}", value);
+        self
+    }
+
+    /// Start a family with a single sample and no labels.
+    pub fn single(&mut self, name: &str, kind: Kind, help: &str, value: f64) -> &mut Metrics {
+        self.family(name, kind, help).sample(name, &[], value)
+    }
+
+    /// The metrics, in the text format.
+    pub fn as_str(&self) -> &str {
+        &self.out
+    }
+}
+
+/// Turn a boolean into a sample value
+pub fn flag(value: bool) -> f64 {
+    if value { 1.0 } else { 0.0 }
+}
+
+fn escape_help(help: &str) -> String {
+    help.replace("\\", "\\\\").replace("\n", "\\n")
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace("\\", "\\\\").replace("\"", "\\\"").replace("\n", "\\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{flag, Kind, Metrics};
+
+    #[test]
+    fn families_and_samples() {
+        let mut metrics = Metrics::new();
+        metrics.family("members", Kind::Gauge, "Members of the ring, by health")
+            .sample("members", &[("health", "alive")], 3.0)
+            .sample("members", &[("health", "suspect")], 1.0);
+        metrics.single("restarts_total", Kind::Counter, "Restarts", 2.0);
+        assert_eq!(metrics.as_str(),
+                   "# HELP hab_sup_members Members of the ring, by health\n# TYPE \
+                    hab_sup_members gauge\nhab_sup_members{health=\"alive\"} \
+                    3\nhab_sup_members{health=\"suspect\"} 1\n# HELP hab_sup_restarts_total \
+                    Restarts\n# TYPE hab_sup_restarts_total counter\nhab_sup_restarts_total 2\n");
+    }
+
+    #[test]
+    fn escapes_label_values() {
+        let mut metrics = Metrics::new();
+        metrics.sample("up", &[("group", "a\"b\\c\nd")], 0.5);
+        assert_eq!(metrics.as_str(), "hab_sup_up{group=\"a\\\"b\\\\c\\nd\"} 0.5\n");
+    }
+
+    #[test]
+    fn flags() {
+        assert_eq!(flag(true), 1.0);
+        assert_eq!(flag(false), 0.0);
+    }
+}
@@ -17,11 +17,17 @@
 //! is turned on, then every line printed is annotated with its preamble, logkey, and precise
 //! location. Without verbose, it prints simply the preamble and logkey. Coloring does what it says
 //! on the tin :)
+//!
+//! There is also a JSON mode, for log shippers: every line is printed as a JSON object with a
+//! timestamp, the preamble, logkey and location, and the content. Lines of service output also
+//! carry the package ident and the stream they were written to.
 
 use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
 use std::fmt;
 
 use ansi_term::Colour::{White, Cyan, Green};
+use rustc_serialize::json;
+use time;
 
 use PROGRAM_NAME;
 
@@ -29,6 +35,7 @@ static mut VERBOSE: AtomicBool = ATOMIC_BOOL_INIT;
 // I am sorry this isn't named the other way; I can't get an atomic initializer that defaults to
 // true. Them's the breaks.
 static mut NO_COLOR: AtomicBool = ATOMIC_BOOL_INIT;
+static mut JSON: AtomicBool = ATOMIC_BOOL_INIT;
 
 /// True if verbose output is on.
 pub fn is_verbose() -> bool {
@@ -60,6 +67,18 @@ pub fn set_no_color(booly: bool) {
     }
 }
 
+/// True if output is printed as JSON lines.
+pub fn is_json() -> bool {
+    unsafe { JSON.load(Ordering::Relaxed) }
+}
+
+/// Turn JSON lines output on or off.
+pub fn set_json(booly: bool) {
+    unsafe {
+        JSON.store(booly, Ordering::Relaxed);
+    }
+}
+
 /// Adds structure to printed output. Stores a preamble, a logkey, line, file, column, and content
 /// to print.
 pub struct StructuredOutput<'a> {
@@ -71,6 +90,25 @@ pub struct StructuredOutput<'a> {
     content: &'a str,
     pub verbose: Option<bool>,
     pub color: Option<bool>,
+    pub json: Option<bool>,
+    /// The package ident of the service that wrote this output, if a service wrote it
+    pub ident: Option<&'a str>,
+    /// The stream the service wrote this output to, if a service wrote it
+    pub stream: Option<&'a str>,
+}
+
+/// A `StructuredOutput` as a JSON line
+#[derive(RustcEncodable)]
+struct JsonOutput<'a> {
+    timestamp: String,
+    preamble: &'a str,
+    logkey: &'a str,
+    file: &'a str,
+    line: u32,
+    column: u32,
+    ident: Option<&'a str>,
+    stream: Option<&'a str>,
+    content: &'a str,
 }
 
 impl<'a> StructuredOutput<'a> {
@@ -91,6 +129,27 @@ impl<'a> StructuredOutput<'a> {
             content: content,
             verbose: None,
             color: None,
+            json: None,
+            ident: None,
+            stream: None,
+        }
+    }
+
+    fn fmt_json(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let out = JsonOutput {
+            timestamp: format!("{}", time::now_utc().rfc3339()),
+            preamble: self.preamble,
+            logkey: self.logkey,
+            file: self.file,
+            line: self.line,
+            column: self.column,
+            ident: self.ident,
+            stream: self.stream,
+            content: self.content,
+        };
+        match json::encode(&out) {
+            Ok(encoded) => write!(f, "{}", encoded),
+            Err(_) => Err(fmt::Error),
         }
     }
 }
@@ -100,6 +159,9 @@ impl<'a> StructuredOutput<'a> {
 // function. Viola!
 impl<'a> fmt::Display for StructuredOutput<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.json.unwrap_or(is_json()) {
+            return self.fmt_json(f);
+        }
         let verbose = self.verbose.unwrap_or(is_verbose());
         let color = self.color.unwrap_or(is_color());
         let preamble_color = if self.preamble == PROGRAM_NAME.as_str() {
@@ -144,6 +206,7 @@ impl<'a> fmt::Display for StructuredOutput<'a> {
 mod tests {
     use super::StructuredOutput;
     use ansi_term::Colour::{White, Cyan};
+    use rustc_serialize::json::Json;
 
     use PROGRAM_NAME;
 
@@ -169,6 +232,24 @@ mod tests {
         assert_eq!(format!("{}", so), "soup(SOT): opeth is amazing");
     }
 
+    #[test]
+    fn format_json() {
+        let mut so = so("redis", "ready to accept \"connections\"");
+        so.json = Some(true);
+        so.ident = Some("core/redis/3.0.7/20160614001713");
+        so.stream = Some("stdout");
+        let line = format!("{}", so);
+        let parsed = Json::from_str(&line).unwrap();
+        let obj = parsed.as_object().unwrap();
+        assert_eq!(obj["preamble"].as_string(), Some("redis"));
+        assert_eq!(obj["logkey"].as_string(), Some("SOT"));
+        assert_eq!(obj["ident"].as_string(), Some("core/redis/3.0.7/20160614001713"));
+        assert_eq!(obj["stream"].as_string(), Some("stdout"));
+        assert_eq!(obj["content"].as_string(), Some("ready to accept \"connections\""));
+        assert!(obj["timestamp"].is_string());
+        assert!(!line.contains("\n"));
+    }
+
     #[test]
     fn format_color() {
         let progname = PROGRAM_NAME.as_str();
@@ -6,18 +6,18 @@
 // open source license such as the Apache 2.0 License.
 
 use std::fmt;
-use std::fs::{self, OpenOptions};
+use std::fs::{self, File, OpenOptions};
+use std::io::BufReader;
 use std::io::prelude::*;
 use std::os::unix::fs::OpenOptionsExt;
 use std::path::PathBuf;
-use std::process::{Command, Stdio};
-
-use handlebars::Handlebars;
+use std::process::{Command, ExitStatus, Stdio};
 
 use error::{Error, Result};
 use package::Package;
-use service_config::{ServiceConfig, never_escape_fn};
-use util::convert;
+use service_config::ServiceConfig;
+use templating;
+use util::{convert, reaper, users};
 
 static LOGKEY: &'static str = "PH";
 
@@ -28,6 +28,10 @@ pub enum HookType {
     FileUpdated,
     Run,
     Init,
+    PostStop,
+    Suitability,
+    Promote,
+    Demote,
 }
 
 impl fmt::Display for HookType {
@@ -38,6 +42,10 @@ impl fmt::Display for HookType {
             &HookType::FileUpdated => write!(f, "file_updated"),
             &HookType::Reconfigure => write!(f, "reconfigure"),
             &HookType::Run => write!(f, "run"),
+            &HookType::PostStop => write!(f, "post_stop"),
+            &HookType::Suitability => write!(f, "suitability"),
+            &HookType::Promote => write!(f, "promote"),
+            &HookType::Demote => write!(f, "demote"),
         }
     }
 }
@@ -47,26 +55,76 @@ pub struct Hook {
     pub htype: HookType,
     pub template: PathBuf,
     pub path: PathBuf,
+    /// The user the hook runs as
+    pub user: String,
+    /// The group the hook runs as
+    pub group: String,
 }
 
 impl Hook {
-    pub fn new(htype: HookType, template: PathBuf, path: PathBuf) -> Self {
+    pub fn new(htype: HookType,
+               template: PathBuf,
+               path: PathBuf,
+               user: String,
+               group: String)
+               -> Self {
         Hook {
             htype: htype,
             template: template,
             path: path,
+            user: user,
+            group: group,
         }
     }
 
     pub fn run(&self, context: Option<&ServiceConfig>) -> Result<String> {
         try!(self.compile(context));
-        let mut child = try!(Command::new(&self.path)
-            .stdin(Stdio::null())
+        self.exec()
+    }
+
+    /// Compile and run a hook, returning everything it wrote to stdout rather than its status.
+    pub fn run_for_output(&self, context: Option<&ServiceConfig>) -> Result<String> {
+        try!(self.compile(context));
+        self.exec_for_output()
+    }
+
+    /// Run a hook that has already been compiled, as the hook's user and group.
+    pub fn exec(&self) -> Result<String> {
+        let (exit_status, _) = try!(self.exec_captured());
+        if exit_status.success() {
+            Ok(String::from("Finished"))
+        } else {
+            Err(sup_error!(Error::HookFailed(self.htype.clone(),
+                                             exit_status.code().unwrap_or(-1),
+                                             String::from("Failed"))))
+        }
+    }
+
+    /// Run a hook that has already been compiled, like `exec`. Returns everything the hook wrote
+    /// to stdout, which is also included in the error if the hook fails.
+    pub fn exec_for_output(&self) -> Result<String> {
+        let (exit_status, output) = try!(self.exec_captured());
+        if exit_status.success() {
+            Ok(output)
+        } else {
+            Err(sup_error!(Error::HookFailed(self.htype.clone(),
+                                             exit_status.code().unwrap_or(-1),
+                                             output)))
+        }
+    }
+
+    /// Run the hook, logging its output as it goes, and return its exit status along with
+    /// everything it wrote to stdout.
+    fn exec_captured(&self) -> Result<(ExitStatus, String)> {
+        let mut cmd = Command::new(&self.path);
+        cmd.stdin(Stdio::null())
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn());
+            .stderr(Stdio::piped());
+        try!(users::run_as(&mut cmd, &self.user, &self.group));
+        let (mut child, _waiting) = try!(reaper::spawn(&mut cmd));
+        let mut output: Vec<u8> = Vec::new();
         {
-            let mut c_stdout = match child.stdout {
+            let c_stdout = match child.stdout {
                 Some(ref mut s) => s,
                 None => {
                     return Err(sup_error!(Error::HookFailed(self.htype.clone(),
@@ -75,46 +133,36 @@ impl Hook {
                 }
             };
             let preamble_str = format!("{}", &self.htype);
-            let mut line = output_format!(preamble & preamble_str, "");
-            loop {
-                let mut buf = [0u8; 1]; // Our byte buffer
-                let len = try!(c_stdout.read(&mut buf));
-                match len {
-                    0 => {
-                        // 0 == EOF, so stop writing and finish progress
-                        break;
-                    }
-                    _ => {
-                        // Write the buffer to the BufWriter on the Heap
-                        let buf_string = String::from_utf8_lossy(&buf[0..len]);
-                        line.push_str(&buf_string);
-                        if line.contains("\n") {
-                            print!("{}", line);
-                            line = output_format!(preamble & preamble_str, "");
-                        }
-                    }
+            let mut reader = BufReader::new(c_stdout);
+            let mut line = Vec::new();
+            while try!(reader.read_until(b'\n', &mut line)) > 0 {
+                output.extend_from_slice(&line);
+                {
+                    let content = String::from_utf8_lossy(&line);
+                    outputln!(preamble & preamble_str,
+                              "{}",
+                              content.trim_right_matches(|c| c == '\n' || c == '\r'));
                 }
+                line.clear();
             }
         }
         let exit_status = try!(child.wait());
-        if exit_status.success() {
-            Ok(String::from("Finished"))
-        } else {
-            Err(sup_error!(Error::HookFailed(self.htype.clone(),
-                                             exit_status.code().unwrap_or(-1),
-                                             String::from("Failed"))))
-        }
+        Ok((exit_status, String::from_utf8_lossy(&output).into_owned()))
     }
 
+    /// Render the hook from its template. The hook is only rewritten, and handed to its user,
+    /// when it has changed; hooks like health_check are compiled every time they run.
     pub fn compile(&self, context: Option<&ServiceConfig>) -> Result<()> {
         if let Some(ctx) = context {
             debug!("Rendering hook {:?}", self);
-            let mut handlebars = Handlebars::new();
-            handlebars.register_escape_fn(never_escape_fn);
+            let mut handlebars = templating::handlebars();
             try!(handlebars.register_template_file("hook", &self.template));
             let toml = try!(ctx.to_toml());
             let svc_data = convert::toml_to_json(toml);
             let data = try!(handlebars.render("hook", &svc_data));
+            if self.is_current(data.as_bytes()) {
+                return Ok(());
+            }
             let mut file = try!(OpenOptions::new()
                 .write(true)
                 .truncate(true)
@@ -123,10 +171,26 @@ impl Hook {
                 .mode(0o770)
                 .open(&self.path));
             try!(write!(&mut file, "{}", data));
-            Ok(())
         } else {
+            let mut template = Vec::new();
+            try!(try!(File::open(&self.template)).read_to_end(&mut template));
+            if self.is_current(&template) {
+                return Ok(());
+            }
             try!(fs::copy(&self.template, &self.path));
-            Ok(())
+        }
+        users::give_to(&self.path, &self.user, &self.group)
+    }
+
+    /// Whether the compiled hook on disk already holds `data`, and belongs to the hook's user.
+    fn is_current(&self, data: &[u8]) -> bool {
+        let mut current = Vec::new();
+        match File::open(&self.path) {
+            Ok(mut file) => {
+                file.read_to_end(&mut current).is_ok() && current == data &&
+                users::owned_by(&self.path, &self.user, &self.group)
+            }
+            Err(_) => false,
         }
     }
 }
@@ -138,6 +202,10 @@ pub struct HookTable<'a> {
     pub reconfigure_hook: Option<Hook>,
     pub file_updated_hook: Option<Hook>,
     pub run_hook: Option<Hook>,
+    pub post_stop_hook: Option<Hook>,
+    pub suitability_hook: Option<Hook>,
+    pub promote_hook: Option<Hook>,
+    pub demote_hook: Option<Hook>,
 }
 
 impl<'a> HookTable<'a> {
@@ -149,6 +217,10 @@ impl<'a> HookTable<'a> {
             reconfigure_hook: None,
             file_updated_hook: None,
             run_hook: None,
+            post_stop_hook: None,
+            suitability_hook: None,
+            promote_hook: None,
+            demote_hook: None,
         }
     }
 
@@ -162,6 +234,10 @@ impl<'a> HookTable<'a> {
                     self.reconfigure_hook = self.load_hook(HookType::Reconfigure);
                     self.health_check_hook = self.load_hook(HookType::HealthCheck);
                     self.run_hook = self.load_hook(HookType::Run);
+                    self.post_stop_hook = self.load_hook(HookType::PostStop);
+                    self.suitability_hook = self.load_hook(HookType::Suitability);
+                    self.promote_hook = self.load_hook(HookType::Promote);
+                    self.demote_hook = self.load_hook(HookType::Demote);
                 }
             }
             Err(_) => {}
@@ -173,7 +249,13 @@ impl<'a> HookTable<'a> {
         let template = self.package.hook_template_path(&hook_type);
         let concrete = self.package.hook_path(&hook_type);
         match fs::metadata(&template) {
-            Ok(_) => Some(Hook::new(hook_type, template, concrete)),
+            Ok(_) => {
+                Some(Hook::new(hook_type,
+                               template,
+                               concrete,
+                               self.package.svc_user(),
+                               self.package.svc_group()))
+            }
             Err(_) => None,
         }
     }
@@ -8,7 +8,7 @@
 pub mod hooks;
 pub mod updater;
 
-pub use self::updater::{PackageUpdater, PackageUpdaterActor, UpdaterMessage};
+pub use self::updater::{PackageUpdater, PackageUpdaterActor, UpdateWatch, UpdaterMessage};
 pub use self::hooks::HookType;
 
 use std;
@@ -17,6 +17,7 @@ use std::fmt;
 use std::fs::File;
 use std::os::unix;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::string::ToString;
 use std::io::prelude::*;
 
@@ -29,6 +30,7 @@ use health_check::{self, CheckResult};
 use service_config::ServiceConfig;
 use supervisor::Supervisor;
 use util::path::busybox_paths;
+use util::signals::Signal;
 
 static LOGKEY: &'static str = "PK";
 const INIT_FILENAME: &'static str = "init";
@@ -36,8 +38,14 @@ const HEALTHCHECK_FILENAME: &'static str = "health_check";
 const FILEUPDATED_FILENAME: &'static str = "file_updated";
 const RECONFIGURE_FILENAME: &'static str = "reconfigure";
 const RUN_FILENAME: &'static str = "run";
-const SERVICE_PATH_OWNER: &'static str = "hab";
-const SERVICE_PATH_GROUP: &'static str = "hab";
+const POST_STOP_FILENAME: &'static str = "post_stop";
+const SUITABILITY_FILENAME: &'static str = "suitability";
+const PROMOTE_FILENAME: &'static str = "promote";
+const DEMOTE_FILENAME: &'static str = "demote";
+/// How long we wait for a service to stop if the package doesn't say
+const DEFAULT_SHUTDOWN_TIMEOUT_SECS: u64 = 8;
+const DEFAULT_SVC_USER: &'static str = "hab";
+const DEFAULT_SVC_GROUP: &'static str = "hab";
 
 #[derive(Debug, Clone)]
 pub struct Package {
@@ -90,6 +98,71 @@ impl Package {
         }
     }
 
+    /// The signal to send the service when we want it to stop. Defaults to `SIGTERM` if the
+    /// package doesn't declare one, or declares one we don't understand.
+    pub fn shutdown_signal(&self) -> Signal {
+        match self.pkg_install.shutdown_signal() {
+            Ok(Some(name)) => {
+                match Signal::from_str(&name) {
+                    Ok(signal) => signal,
+                    Err(e) => {
+                        outputln!("{}; using SIGTERM", e);
+                        Signal::SIGTERM
+                    }
+                }
+            }
+            Ok(None) => Signal::SIGTERM,
+            Err(e) => {
+                outputln!("Error reading the shutdown signal: {}; using SIGTERM", e);
+                Signal::SIGTERM
+            }
+        }
+    }
+
+    /// How many seconds to wait for the service to stop before we kill it.
+    pub fn shutdown_timeout(&self) -> u64 {
+        match self.pkg_install.shutdown_timeout() {
+            Ok(Some(secs)) => secs,
+            Ok(None) => DEFAULT_SHUTDOWN_TIMEOUT_SECS,
+            Err(e) => {
+                outputln!("Error reading the shutdown timeout: {}; using {} seconds",
+                          e,
+                          DEFAULT_SHUTDOWN_TIMEOUT_SECS);
+                DEFAULT_SHUTDOWN_TIMEOUT_SECS
+            }
+        }
+    }
+
+    /// The user the service and its hooks run as. Defaults to `hab` if the package doesn't
+    /// declare one.
+    pub fn svc_user(&self) -> String {
+        match self.pkg_install.svc_user() {
+            Ok(Some(user)) => user,
+            Ok(None) => String::from(DEFAULT_SVC_USER),
+            Err(e) => {
+                outputln!("Error reading the service user: {}; using {}",
+                          e,
+                          DEFAULT_SVC_USER);
+                String::from(DEFAULT_SVC_USER)
+            }
+        }
+    }
+
+    /// The group the service and its hooks run as. Defaults to `hab` if the package doesn't
+    /// declare one.
+    pub fn svc_group(&self) -> String {
+        match self.pkg_install.svc_group() {
+            Ok(Some(group)) => group,
+            Ok(None) => String::from(DEFAULT_SVC_GROUP),
+            Err(e) => {
+                outputln!("Error reading the service group: {}; using {}",
+                          e,
+                          DEFAULT_SVC_GROUP);
+                String::from(DEFAULT_SVC_GROUP)
+            }
+        }
+    }
+
     /// Returns a string with the full run path for this package. This path is composed of any
     /// binary paths specified by this package, or its TDEPS, plus a path to a BusyBox,
     /// plus the existing value of the PATH variable.
@@ -120,6 +193,10 @@ impl Package {
             HookType::FileUpdated => base.join(FILEUPDATED_FILENAME),
             HookType::Reconfigure => base.join(RECONFIGURE_FILENAME),
             HookType::Run => base.join(RUN_FILENAME),
+            HookType::PostStop => base.join(POST_STOP_FILENAME),
+            HookType::Suitability => base.join(SUITABILITY_FILENAME),
+            HookType::Promote => base.join(PROMOTE_FILENAME),
+            HookType::Demote => base.join(DEMOTE_FILENAME),
         }
     }
 
@@ -131,6 +208,10 @@ impl Package {
             HookType::FileUpdated => base.join(FILEUPDATED_FILENAME),
             HookType::Reconfigure => base.join(RECONFIGURE_FILENAME),
             HookType::Run => base.join(RUN_FILENAME),
+            HookType::PostStop => base.join(POST_STOP_FILENAME),
+            HookType::Suitability => base.join(SUITABILITY_FILENAME),
+            HookType::Promote => base.join(PROMOTE_FILENAME),
+            HookType::Demote => base.join(DEMOTE_FILENAME),
         }
     }
 
@@ -144,9 +225,10 @@ impl Package {
         self.pkg_install.svc_path()
     }
 
-    /// Create the service path for this package.
+    /// Create the service path for this package. The data, files and var directories belong to
+    /// the service user, since that is who the service runs as.
     pub fn create_svc_path(&self) -> Result<()> {
-        let runas = format!("{}:{}", SERVICE_PATH_OWNER, SERVICE_PATH_GROUP);
+        let runas = format!("{}:{}", self.svc_user(), self.svc_group());
         debug!("Creating svc paths");
         try!(std::fs::create_dir_all(self.pkg_install.svc_config_path()));
         try!(std::fs::create_dir_all(self.pkg_install.svc_data_path()));
@@ -166,9 +248,13 @@ impl Package {
         Ok(())
     }
 
-    /// Copy the "run" file to the svc path.
+    /// Copy the "run" file to the svc path. The post_stop hook is rendered alongside it, so the
+    /// supervisor can run it whenever the service stops.
     pub fn copy_run(&self, context: &ServiceConfig) -> Result<()> {
         debug!("Copying the run file");
+        if let Some(hook) = self.hooks().post_stop_hook {
+            try!(hook.compile(Some(context)));
+        }
         let svc_run = self.pkg_install.svc_path().join(RUN_FILENAME);
         if let Some(hook) = self.hooks().run_hook {
             try!(hook.compile(Some(context)));
@@ -272,6 +358,37 @@ impl Package {
         }
     }
 
+    /// Run promote hook if present; called when we become the leader
+    pub fn promote(&self, context: &ServiceConfig) -> Result<()> {
+        if let Some(hook) = self.hooks().promote_hook {
+            try!(hook.run(Some(context)));
+        }
+        Ok(())
+    }
+
+    /// Run demote hook if present; called when we stop being the leader
+    pub fn demote(&self, context: &ServiceConfig) -> Result<()> {
+        if let Some(hook) = self.hooks().demote_hook {
+            try!(hook.run(Some(context)));
+        }
+        Ok(())
+    }
+
+    /// Run the suitability hook if present, and return the number it printed. The last line of
+    /// output is used, so the hook is free to log before it.
+    pub fn suitability(&self, context: &ServiceConfig) -> Result<Option<u32>> {
+        if let Some(hook) = self.hooks().suitability_hook {
+            let output = try!(hook.run_for_output(Some(context)));
+            let last_line = output.lines().filter(|l| !l.trim().is_empty()).last().unwrap_or("");
+            match last_line.trim().parse::<u32>() {
+                Ok(suitability) => Ok(Some(suitability)),
+                Err(_) => Err(sup_error!(Error::InvalidSuitability(last_line.to_string()))),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
     pub fn health_check(&self,
                         config: &ServiceConfig,
                         supervisor: &Supervisor)
@@ -335,3 +452,31 @@ impl fmt::Display for Package {
         write!(f, "{}", self.ident())
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use hcore::package::{PackageIdent, PackageInstall};
+
+    use super::Package;
+
+    /// A package that isn't installed anywhere, for tests that only need one to exist.
+    pub fn gen_pkg() -> Package {
+        let pkg_install = PackageInstall::new_from_parts(
+            PackageIdent::from_str("neurosis/sovereign/2000/20160222201258").unwrap(),
+            PathBuf::from("/"),
+            PathBuf::from("/fakeo"),
+            PathBuf::from("/fakeo/here"));
+        Package {
+            origin: String::from("neurosis"),
+            name: String::from("sovereign"),
+            version: String::from("2000"),
+            release: String::from("20160222201258"),
+            deps: Vec::new(),
+            tdeps: Vec::new(),
+            pkg_install: pkg_install,
+        }
+    }
+}
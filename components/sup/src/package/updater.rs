@@ -13,13 +13,20 @@ use depot_client::Client;
 use hcore::crypto::default_cache_key_path;
 use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
 use hcore::package::PackageIdent;
+use time::{Duration, SteadyTime};
 use wonder;
 use wonder::actor::{GenServer, InitResult, HandleResult, ActorSender, ActorResult};
 
 use error::SupError;
+use health_check::{CheckResult, Status};
 use package::Package;
 
+static LOGKEY: &'static str = "PU";
 const TIMEOUT_MS: u64 = 60_000;
+/// How many times may the service exit during the rollback window before we roll back?
+const ROLLBACK_MAX_EXITS: u64 = 3;
+/// How many consecutive critical health checks during the rollback window before we roll back?
+const ROLLBACK_MAX_CRITICAL_CHECKS: u32 = 3;
 
 pub type PackageUpdaterActor = wonder::actor::Actor<UpdaterMessage>;
 
@@ -40,12 +47,73 @@ impl PackageUpdater {
     pub fn run(actor: &PackageUpdaterActor) -> ActorResult<()> {
         actor.cast(UpdaterMessage::Run)
     }
+
+    /// Tell a package updater to never apply the given release again; used when an update failed
+    /// and was rolled back.
+    pub fn reject(actor: &PackageUpdaterActor, ident: PackageIdent) -> ActorResult<()> {
+        actor.cast(UpdaterMessage::Reject(ident))
+    }
+}
+
+/// Watches a freshly updated package for a window of time, so that we can restore the previous
+/// release if the new one crashes in a loop or keeps failing its health check.
+#[derive(Debug)]
+pub struct UpdateWatch {
+    /// The last known-good package we were running before the update
+    pub previous: Package,
+    /// The release we updated to
+    pub updated: PackageIdent,
+    started: SteadyTime,
+    window: Duration,
+    exits_at_start: u64,
+    critical_checks: u32,
+}
+
+impl UpdateWatch {
+    /// Start watching an update. `exit_count` is the supervisors current count of unexpected
+    /// service exits.
+    pub fn new(previous: Package,
+               updated: PackageIdent,
+               window_secs: u64,
+               exit_count: u64)
+               -> Self {
+        UpdateWatch {
+            previous: previous,
+            updated: updated,
+            started: SteadyTime::now(),
+            window: Duration::seconds(window_secs as i64),
+            exits_at_start: exit_count,
+            critical_checks: 0,
+        }
+    }
+
+    /// Record the result of a health check run during the window.
+    pub fn record_check(&mut self, result: &CheckResult) {
+        match result.status {
+            Status::Critical => self.critical_checks += 1,
+            Status::Ok | Status::Warning => self.critical_checks = 0,
+            Status::Unknown => {}
+        }
+    }
+
+    /// Returns true if the update should be rolled back.
+    pub fn failed(&self, exit_count: u64) -> bool {
+        exit_count - self.exits_at_start >= ROLLBACK_MAX_EXITS ||
+        self.critical_checks >= ROLLBACK_MAX_CRITICAL_CHECKS
+    }
+
+    /// Returns true once the window has passed.
+    pub fn expired(&self) -> bool {
+        SteadyTime::now() - self.started > self.window
+    }
 }
 
 pub struct UpdaterState {
     pub depot: String,
     pub package: Arc<RwLock<Package>>,
     pub status: UpdaterStatus,
+    /// Releases that were rolled back, and must never be applied again
+    pub rejected: Vec<PackageIdent>,
 }
 
 impl UpdaterState {
@@ -54,6 +122,7 @@ impl UpdaterState {
             depot: depot,
             package: package,
             status: UpdaterStatus::Stopped,
+            rejected: Vec::new(),
         }
     }
 }
@@ -61,6 +130,7 @@ impl UpdaterState {
 #[derive(Debug)]
 pub enum UpdaterMessage {
     Ok,
+    Reject(PackageIdent),
     Run,
     Stop,
     Update(Package),
@@ -101,7 +171,10 @@ impl GenServer for PackageUpdater {
         match depot_client.show_package(ident) {
             Ok(remote) => {
                 let latest_ident: PackageIdent = remote.get_ident().clone().into();
-                if &latest_ident > package.ident() {
+                if state.rejected.contains(&latest_ident) {
+                    debug!("Package {} was rolled back; not updating", latest_ident);
+                    HandleResult::NoReply(Some(TIMEOUT_MS))
+                } else if &latest_ident > package.ident() {
                     let mut progress = ProgressBar::default();
                     match depot_client.fetch_package(latest_ident.clone(),
                                                      &Path::new(FS_ROOT_PATH)
@@ -143,6 +216,14 @@ impl GenServer for PackageUpdater {
                    -> HandleResult<Self::T> {
         match msg {
             UpdaterMessage::Run => HandleResult::NoReply(Some(TIMEOUT_MS)),
+            UpdaterMessage::Reject(ident) => {
+                outputln!("Refusing to update to {} again", ident);
+                state.rejected.push(ident);
+                match state.status {
+                    UpdaterStatus::Running => HandleResult::NoReply(Some(TIMEOUT_MS)),
+                    UpdaterStatus::Stopped => HandleResult::NoReply(None),
+                }
+            }
             _ => {
                 match state.status {
                     UpdaterStatus::Running => HandleResult::NoReply(Some(TIMEOUT_MS)),
@@ -152,3 +233,55 @@ impl GenServer for PackageUpdater {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+
+    use health_check::CheckResult;
+    use package::test::gen_pkg;
+    use super::UpdateWatch;
+
+    fn gen_watch() -> UpdateWatch {
+        let updated = PackageIdent::from_str("neurosis/sovereign/2001/20160301000000").unwrap();
+        UpdateWatch::new(gen_pkg(), updated, 120, 2)
+    }
+
+    #[test]
+    fn failed_after_repeated_exits() {
+        let watch = gen_watch();
+        assert_eq!(watch.failed(2), false);
+        assert_eq!(watch.failed(4), false);
+        assert_eq!(watch.failed(5), true);
+    }
+
+    #[test]
+    fn failed_after_consecutive_critical_checks() {
+        let mut watch = gen_watch();
+        watch.record_check(&CheckResult::critical(String::from("down")));
+        watch.record_check(&CheckResult::critical(String::from("down")));
+        assert_eq!(watch.failed(2), false);
+        watch.record_check(&CheckResult::critical(String::from("down")));
+        assert_eq!(watch.failed(2), true);
+    }
+
+    #[test]
+    fn healthy_check_resets_critical_count() {
+        let mut watch = gen_watch();
+        watch.record_check(&CheckResult::critical(String::from("down")));
+        watch.record_check(&CheckResult::critical(String::from("down")));
+        watch.record_check(&CheckResult::ok(String::from("up")));
+        watch.record_check(&CheckResult::critical(String::from("down")));
+        assert_eq!(watch.failed(2), false);
+    }
+
+    #[test]
+    fn expired() {
+        let updated = PackageIdent::from_str("neurosis/sovereign/2001/20160301000000").unwrap();
+        let watch = UpdateWatch::new(gen_pkg(), updated, 0, 0);
+        assert_eq!(watch.expired(), true);
+        assert_eq!(gen_watch().expired(), false);
+    }
+}
@@ -0,0 +1,354 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! Checks configuration against the schema a package ships for its `default.toml` keys.
+//!
+//! The schema lives in `schema.toml` next to `default.toml`. Every key with a rule gets a table
+//! with a `type`; tables without one just hold the rules for nested keys:
+//!
+//! ```toml
+//! [port]
+//! type = "integer"
+//! required = true
+//! min = 1
+//! max = 65535
+//!
+//! [tuning.loglevel]
+//! type = "string"
+//! allowed = ["debug", "info", "warn"]
+//! ```
+//!
+//! The types are `string`, `integer`, `float` (which takes integers too), `boolean`, `array`,
+//! `table` and `any`. Keys without a rule may hold anything.
+
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+use toml;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "SH";
+
+/// The file name of a package's schema
+pub const SCHEMA_TOML: &'static str = "schema.toml";
+
+/// The type a key must have
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Type {
+    String,
+    Integer,
+    Float,
+    Boolean,
+    Array,
+    Table,
+    Any,
+}
+
+impl Type {
+    fn from_str(kind: &str) -> Option<Type> {
+        match kind {
+            "string" => Some(Type::String),
+            "integer" => Some(Type::Integer),
+            "float" => Some(Type::Float),
+            "boolean" => Some(Type::Boolean),
+            "array" => Some(Type::Array),
+            "table" => Some(Type::Table),
+            "any" => Some(Type::Any),
+            _ => None,
+        }
+    }
+
+    fn matches(&self, value: &toml::Value) -> bool {
+        match (*self, value) {
+            (Type::Any, _) => true,
+            (Type::String, &toml::Value::String(_)) => true,
+            (Type::Integer, &toml::Value::Integer(_)) => true,
+            (Type::Float, &toml::Value::Float(_)) => true,
+            (Type::Float, &toml::Value::Integer(_)) => true,
+            (Type::Boolean, &toml::Value::Boolean(_)) => true,
+            (Type::Array, &toml::Value::Array(_)) => true,
+            (Type::Table, &toml::Value::Table(_)) => true,
+            _ => false,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match *self {
+            Type::String => "a string",
+            Type::Integer => "an integer",
+            Type::Float => "a number",
+            Type::Boolean => "a boolean",
+            Type::Array => "an array",
+            Type::Table => "a table",
+            Type::Any => "anything",
+        }
+    }
+}
+
+/// What one key must look like
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    /// The dotted path to the key, like `tuning.loglevel`
+    pub key: String,
+    pub kind: Type,
+    pub required: bool,
+    /// If not empty, the only values the key may have
+    pub allowed: Vec<toml::Value>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl Rule {
+    fn from_table(key: String, table: &toml::Table) -> Result<Rule> {
+        let kind = match table.get("type").and_then(|kind| kind.as_str()) {
+            Some(kind) => {
+                match Type::from_str(kind) {
+                    Some(kind) => kind,
+                    None => {
+                        return Err(sup_error!(Error::InvalidSchema(format!("{} has an unknown \
+                                                                            type {}",
+                                                                           key,
+                                                                           kind))))
+                    }
+                }
+            }
+            None => return Err(sup_error!(Error::InvalidSchema(format!("{} has no type", key)))),
+        };
+        let required = match table.get("required") {
+            Some(&toml::Value::Boolean(required)) => required,
+            Some(_) => {
+                return Err(sup_error!(Error::InvalidSchema(format!("required for {} must be \
+                                                                    true or false",
+                                                                   key))))
+            }
+            None => false,
+        };
+        let allowed = match table.get("allowed") {
+            Some(&toml::Value::Array(ref allowed)) => allowed.clone(),
+            Some(_) => {
+                return Err(sup_error!(Error::InvalidSchema(format!("allowed for {} must be an \
+                                                                    array",
+                                                                   key))))
+            }
+            None => Vec::new(),
+        };
+        let min = try!(bound(&key, "min", table.get("min")));
+        let max = try!(bound(&key, "max", table.get("max")));
+        Ok(Rule {
+            key: key,
+            kind: kind,
+            required: required,
+            allowed: allowed,
+            min: min,
+            max: max,
+        })
+    }
+
+    /// The problem with the value of this rule's key in `cfg`, if there is one.
+    fn check(&self, cfg: &toml::Table) -> Option<String> {
+        let value = match lookup(cfg, &self.key) {
+            Some(value) => value,
+            None => {
+                if self.required {
+                    return Some(format!("{} is required", self.key));
+                }
+                return None;
+            }
+        };
+        if !self.kind.matches(value) {
+            return Some(format!("{} must be {}", self.key, self.kind.name()));
+        }
+        if !self.allowed.is_empty() && !self.allowed.contains(value) {
+            let allowed: Vec<String> = self.allowed.iter().map(|v| v.to_string()).collect();
+            return Some(format!("{} must be one of {}", self.key, allowed.join(", ")));
+        }
+        let number = match *value {
+            toml::Value::Integer(n) => Some(n as f64),
+            toml::Value::Float(n) => Some(n),
+            _ => None,
+        };
+        if let Some(number) = number {
+            if let Some(min) = self.min {
+                if number < min {
+                    return Some(format!("{} must be at least {}", self.key, min));
+                }
+            }
+            if let Some(max) = self.max {
+                if number > max {
+                    return Some(format!("{} must be at most {}", self.key, max));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The rules for a package's configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schema {
+    rules: Vec<Rule>,
+}
+
+impl Schema {
+    /// Load the schema at `path`, if there is one.
+    ///
+    /// # Failures
+    ///
+    /// * If the schema can't be read, isn't TOML, or has a bad rule
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Option<Schema>> {
+        let mut file = match File::open(path.as_ref()) {
+            Ok(file) => file,
+            Err(e) => {
+                debug!("No schema at {}: {}", path.as_ref().display(), e);
+                return Ok(None);
+            }
+        };
+        let mut schema = String::new();
+        try!(file.read_to_string(&mut schema));
+        Ok(Some(try!(Schema::from_str(&schema))))
+    }
+
+    /// Parse a schema.
+    ///
+    /// # Failures
+    ///
+    /// * If the schema isn't TOML, or has a bad rule
+    pub fn from_str(schema: &str) -> Result<Schema> {
+        let mut parser = toml::Parser::new(schema);
+        let table = try!(parser.parse().ok_or(sup_error!(Error::TomlParser(parser.errors))));
+        let mut rules = Vec::new();
+        try!(collect_rules("", &table, &mut rules));
+        Ok(Schema { rules: rules })
+    }
+
+    /// Every problem with `cfg`, in the order of the schema's rules.
+    pub fn validate(&self, cfg: &toml::Table) -> Vec<String> {
+        self.rules.iter().filter_map(|rule| rule.check(cfg)).collect()
+    }
+}
+
+fn collect_rules(prefix: &str, table: &toml::Table, rules: &mut Vec<Rule>) -> Result<()> {
+    for (key, value) in table.iter() {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match *value {
+            toml::Value::Table(ref t) => {
+                if t.contains_key("type") {
+                    rules.push(try!(Rule::from_table(path, t)));
+                } else {
+                    try!(collect_rules(&path, t, rules));
+                }
+            }
+            _ => {
+                return Err(sup_error!(Error::InvalidSchema(format!("{} must be a table", path))))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn bound(key: &str, name: &str, value: Option<&toml::Value>) -> Result<Option<f64>> {
+    match value {
+        Some(&toml::Value::Integer(n)) => Ok(Some(n as f64)),
+        Some(&toml::Value::Float(n)) => Ok(Some(n)),
+        Some(_) => {
+            Err(sup_error!(Error::InvalidSchema(format!("{} for {} must be a number", name, key))))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Find the value at a dotted path in a table.
+fn lookup<'a>(table: &'a toml::Table, path: &str) -> Option<&'a toml::Value> {
+    let mut parts = path.split('.');
+    let mut value = match parts.next().and_then(|part| table.get(part)) {
+        Some(value) => value,
+        None => return None,
+    };
+    for part in parts {
+        value = match value.as_table().and_then(|t| t.get(part)) {
+            Some(value) => value,
+            None => return None,
+        };
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod test {
+    use toml;
+
+    use super::Schema;
+
+    fn cfg(toml: &str) -> toml::Table {
+        toml::Parser::new(toml).parse().unwrap()
+    }
+
+    fn schema() -> Schema {
+        Schema::from_str(r#"
+            [port]
+            type = "integer"
+            required = true
+            min = 1
+            max = 65535
+
+            [ratio]
+            type = "float"
+
+            [tuning.loglevel]
+            type = "string"
+            allowed = ["debug", "info", "warn"]
+            "#)
+            .unwrap()
+    }
+
+    #[test]
+    fn accepts_valid_config() {
+        let cfg = cfg("port = 80\nratio = 1\nextra = \"anything\"\n[tuning]\nloglevel = \"info\"");
+        assert!(schema().validate(&cfg).is_empty());
+    }
+
+    #[test]
+    fn requires_keys() {
+        assert_eq!(schema().validate(&cfg("ratio = 0.5")),
+                   vec!["port is required".to_string()]);
+    }
+
+    #[test]
+    fn checks_types() {
+        assert_eq!(schema().validate(&cfg("port = \"80\"\nratio = true")),
+                   vec!["port must be an integer".to_string(),
+                        "ratio must be a number".to_string()]);
+    }
+
+    #[test]
+    fn checks_bounds() {
+        assert_eq!(schema().validate(&cfg("port = 0")),
+                   vec!["port must be at least 1".to_string()]);
+        assert_eq!(schema().validate(&cfg("port = 70000")),
+                   vec!["port must be at most 65535".to_string()]);
+    }
+
+    #[test]
+    fn checks_allowed_values_of_nested_keys() {
+        let problems = schema().validate(&cfg("port = 80\n[tuning]\nloglevel = \"trace\""));
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("tuning.loglevel must be one of"));
+    }
+
+    #[test]
+    fn rejects_bad_schemas() {
+        assert!(Schema::from_str("[port]\ntype = \"number\"").is_err());
+        assert!(Schema::from_str("[port]\nrequired = true").is_err());
+        assert!(Schema::from_str("port = \"integer\"").is_err());
+    }
+}
@@ -12,21 +12,26 @@ use std::collections::HashMap;
 use std::env;
 use std::fs::File;
 use std::io::prelude::*;
+use std::str::FromStr;
 
 use ansi_term::Colour::Purple;
 use rustc_serialize::Encodable;
 use toml;
-use handlebars::{Handlebars, JsonRender};
+use handlebars::JsonRender;
 
 use common::gossip_file::GOSSIP_TOML;
-use census::{Census, CensusList};
+use census::{Census, CensusEntry, CensusList};
 use config::Config;
 use error::{Error, Result};
 use hcore::package::PackageInstall;
 use hcore::crypto;
+use limits::{self, Limits};
 use package::Package;
+use schema::{Schema, SCHEMA_TOML};
+use templating;
 use util;
 use util::convert;
+use util::signals::Signal;
 use VERSION;
 
 static LOGKEY: &'static str = "SC";
@@ -49,10 +54,6 @@ pub struct ServiceConfig {
     pub needs_write: bool,
 }
 
-pub fn never_escape_fn(data: &str) -> String {
-    String::from(data)
-}
-
 impl ServiceConfig {
     /// Takes a new package and a new census list, and returns a ServiceConfig. This function can
     /// fail, and indeed, we want it to - it causes the program to crash if we can not render the
@@ -132,6 +133,22 @@ impl ServiceConfig {
         }
     }
 
+    /// The signal to stop the service with, if the configuration sets `hab.shutdown_signal`.
+    pub fn shutdown_signal(&self) -> Option<Signal> {
+        self.cfg.shutdown_signal()
+    }
+
+    /// How many seconds to wait for the service to stop, if the configuration sets
+    /// `hab.shutdown_timeout`.
+    pub fn shutdown_timeout(&self) -> Option<u64> {
+        self.cfg.shutdown_timeout()
+    }
+
+    /// The resource limits the configuration sets in `hab.limits`.
+    pub fn limits(&self) -> Limits {
+        self.cfg.limits()
+    }
+
     /// Write the configuration to `config.toml`, and render the templated configuration files.
     pub fn write(&mut self, pkg: &Package) -> Result<bool> {
         let pi = &pkg.pkg_install;
@@ -141,26 +158,8 @@ impl ServiceConfig {
             try!(write!(&mut last_toml, "{}", toml::encode_str(&final_toml)));
         }
 
-        debug!("Registering configuration templates");
-        let mut handlebars = Handlebars::new();
-        // By default, handlebars escapes HTML. We don't want that.
-        handlebars.register_escape_fn(never_escape_fn);
-
-        // Register all the templates; this makes them available as partials!
-        // I suspect this will be useful, but I think we'll want to make this
-        // more explicit... in a minute, we render all the config files anyway.
-        let config_files = try!(pkg.config_files());
-        for config in config_files.iter() {
-            let path = pi.installed_path().join("config").join(config);
-            debug!("Config template {} at {:?}", config, &path);
-            try!(handlebars.register_template_file(config, &path));
-        }
-
-        let final_data = convert::toml_to_json(final_toml);
         let mut should_restart = false;
-        for config in config_files {
-            debug!("Rendering template {}", &config);
-            let template_data = try!(handlebars.render(&config, &final_data));
+        for (config, template_data) in try!(render_templates(pkg, final_toml)) {
             let file_hash = try!(crypto::hash::hash_string(&template_data));
             let filename = pi.svc_config_path().join(&config).to_string_lossy().into_owned();
             if self.config_hash.contains_key(&filename) {
@@ -189,6 +188,67 @@ impl ServiceConfig {
     }
 }
 
+/// Render the package's configuration templates as they would be with `gossip` as the gossip
+/// layer, without writing anything. Everything outside of `cfg` comes from the `config.toml` the
+/// supervisor last wrote. Returns each configuration file's name with its contents.
+///
+/// # Failures
+///
+/// * If the service has no `config.toml`, because it has never run here
+/// * If `gossip` doesn't match the package's schema
+/// * If a template fails to render
+pub fn dry_run(pkg: &Package, gossip: toml::Table) -> Result<Vec<(String, String)>> {
+    let last_path = pkg.pkg_install.svc_path().join("config.toml");
+    let mut last = String::new();
+    match File::open(&last_path) {
+        Ok(mut file) => try!(file.read_to_string(&mut last)),
+        Err(_) => {
+            return Err(sup_error!(Error::FileNotFound(last_path.to_string_lossy().into_owned())))
+        }
+    };
+    let mut toml_parser = toml::Parser::new(&last);
+    let mut final_toml = try!(toml_parser.parse()
+        .ok_or(sup_error!(Error::TomlParser(toml_parser.errors))));
+
+    let mut cfg = try!(Cfg::load_layers(pkg));
+    cfg.gossip = Some(toml::Value::Table(gossip));
+    for (layer, problems) in try!(cfg.enforce_schema(pkg)) {
+        if layer == GOSSIP_TOML {
+            return Err(sup_error!(Error::InvalidConfig(problems)));
+        }
+        outputln!("Ignoring configuration from {}: {}", layer, problems);
+    }
+    final_toml.insert(String::from("cfg"), cfg.to_toml());
+    render_templates(pkg, toml::Value::Table(final_toml))
+}
+
+/// Render every configuration template in the package against `final_toml`, returning each
+/// file's name with its contents.
+fn render_templates(pkg: &Package, final_toml: toml::Value) -> Result<Vec<(String, String)>> {
+    let pi = &pkg.pkg_install;
+    debug!("Registering configuration templates");
+    let mut handlebars = templating::handlebars();
+
+    // Register all the templates; this makes them available as partials!
+    // I suspect this will be useful, but I think we'll want to make this
+    // more explicit... in a minute, we render all the config files anyway.
+    let config_files = try!(pkg.config_files());
+    for config in config_files.iter() {
+        let path = pi.installed_path().join("config").join(config);
+        debug!("Config template {} at {:?}", config, &path);
+        try!(handlebars.register_template_file(config, &path));
+    }
+
+    let final_data = convert::toml_to_json(final_toml);
+    let mut rendered = Vec::new();
+    for config in config_files {
+        debug!("Rendering template {}", &config);
+        let template_data = try!(handlebars.render(&config, &final_data));
+        rendered.push((config, template_data));
+    }
+    Ok(rendered)
+}
+
 #[derive(Debug, RustcEncodable)]
 struct Bind {
     toml: toml::Table,
@@ -264,13 +324,39 @@ fn service_entry(census: &Census) -> toml::Table {
     let service = toml::Value::String(census.service.clone());
     let group = toml::Value::String(census.group.clone());
     let ident = toml::Value::String(census.service_group());
-    let me = toml::encode(census.me());
-    let leader = census.get_leader().map(|ce| toml::encode(ce));
+    let me = member_entry(census.me());
+    let leader = census.get_leader().map(|ce| member_entry(ce));
+    let mut entries: Vec<&CensusEntry> = census.values().collect();
+    entries.sort_by_key(|ce| ce.member_id.simple().to_string());
     let mut members: Vec<toml::Value> = Vec::new();
     let mut member_id = toml::Table::new();
-    for (sg, ce) in census.iter() {
-        members.push(toml::encode(ce));
-        member_id.insert(format!("{}", sg), toml::encode(ce));
+    let mut alive: Vec<toml::Value> = Vec::new();
+    let mut suspect: Vec<toml::Value> = Vec::new();
+    let mut confirmed: Vec<toml::Value> = Vec::new();
+    let mut followers: Vec<toml::Value> = Vec::new();
+    let mut healthy: Vec<toml::Value> = Vec::new();
+    let mut unhealthy: Vec<toml::Value> = Vec::new();
+    for ce in entries.iter() {
+        let entry = member_entry(ce);
+        members.push(entry.clone());
+        member_id.insert(format!("{}", ce.id), entry.clone());
+        if ce.alive {
+            alive.push(entry.clone());
+        }
+        if ce.suspect {
+            suspect.push(entry.clone());
+        }
+        if ce.confirmed {
+            confirmed.push(entry.clone());
+        }
+        if ce.follower && ce.alive {
+            followers.push(entry.clone());
+        }
+        if ce.is_healthy() {
+            healthy.push(entry);
+        } else {
+            unhealthy.push(entry);
+        }
     }
     let mut result = toml::Table::new();
     result.insert("service".to_string(), service);
@@ -280,11 +366,31 @@ fn service_entry(census: &Census) -> toml::Table {
     if let Some(l) = leader {
         result.insert("leader".to_string(), l);
     }
+    if let Some(first) = healthy.first() {
+        result.insert("first".to_string(), first.clone());
+    }
     result.insert("members".to_string(), toml::Value::Array(members));
     result.insert("member_id".to_string(), toml::Value::Table(member_id));
+    result.insert("alive_members".to_string(), toml::Value::Array(alive));
+    result.insert("suspect_members".to_string(), toml::Value::Array(suspect));
+    result.insert("confirmed_members".to_string(), toml::Value::Array(confirmed));
+    result.insert("followers".to_string(), toml::Value::Array(followers));
+    result.insert("healthy_members".to_string(), toml::Value::Array(healthy));
+    result.insert("unhealthy_members".to_string(), toml::Value::Array(unhealthy));
     result
 }
 
+/// A census entry as templates see it; the entry itself, plus whether it is healthy.
+fn member_entry(ce: &CensusEntry) -> toml::Value {
+    match toml::encode(ce) {
+        toml::Value::Table(mut table) => {
+            table.insert("healthy".to_string(), toml::Value::Boolean(ce.is_healthy()));
+            toml::Value::Table(table)
+        }
+        value => value,
+    }
+}
+
 #[derive(Debug, RustcEncodable)]
 struct Cfg {
     default: Option<toml::Value>,
@@ -314,8 +420,40 @@ fn toml_merge(left: &toml::Table, right: &toml::Table) -> toml::Table {
     final_map
 }
 
+// Merges a layer on top of `merged` if that brings in no new schema problems. Otherwise, drops
+// the layer and returns its problems.
+fn enforce_layer(schema: &Schema,
+                 merged: &mut toml::Table,
+                 layer: &mut Option<toml::Value>)
+                 -> Option<String> {
+    let candidate = match *layer {
+        Some(toml::Value::Table(ref right)) => toml_merge(merged, right),
+        _ => return None,
+    };
+    let before = schema.validate(merged);
+    let problems: Vec<String> = schema.validate(&candidate)
+        .into_iter()
+        .filter(|problem| !before.contains(problem))
+        .collect();
+    if problems.is_empty() {
+        *merged = candidate;
+        None
+    } else {
+        *layer = None;
+        Some(problems.join(", "))
+    }
+}
+
 impl Cfg {
     fn new(pkg: &Package) -> Result<Cfg> {
+        let mut cfg = try!(Cfg::load_layers(pkg));
+        for (layer, problems) in try!(cfg.enforce_schema(pkg)) {
+            outputln!("Rejecting configuration from {}: {}", layer, problems);
+        }
+        Ok(cfg)
+    }
+
+    fn load_layers(pkg: &Package) -> Result<Cfg> {
         let mut cfg = Cfg {
             default: None,
             user: None,
@@ -329,6 +467,36 @@ impl Cfg {
         Ok(cfg)
     }
 
+    /// Check the layers against the package's schema, if it has one. Each layer is checked on top
+    /// of the layers beneath it, and a layer that brings in new problems is dropped. Returns the
+    /// dropped layers, with their problems.
+    fn enforce_schema(&mut self, pkg: &Package) -> Result<Vec<(&'static str, String)>> {
+        let schema = match try!(Schema::load(pkg.path().join(SCHEMA_TOML))) {
+            Some(schema) => schema,
+            None => return Ok(Vec::new()),
+        };
+        let mut merged = toml::Table::new();
+        if let Some(toml::Value::Table(ref default)) = self.default {
+            merged = toml_merge(&merged, default);
+        }
+        let mut rejected = Vec::new();
+        if let Some(problems) = enforce_layer(&schema, &mut merged, &mut self.user) {
+            rejected.push(("user.toml", problems));
+        }
+        if let Some(problems) = enforce_layer(&schema, &mut merged, &mut self.gossip) {
+            rejected.push((GOSSIP_TOML, problems));
+        }
+        if let Some(problems) = enforce_layer(&schema, &mut merged, &mut self.environment) {
+            rejected.push(("the environment", problems));
+        }
+        let problems = schema.validate(&merged);
+        if !problems.is_empty() {
+            outputln!("Configuration does not match the package's schema: {}",
+                      problems.join(", "));
+        }
+        Ok(rejected)
+    }
+
     fn to_toml(&self) -> toml::Value {
         let mut left = toml::Table::new();
         if let Some(toml::Value::Table(ref right)) = self.default {
@@ -346,6 +514,59 @@ impl Cfg {
         toml::Value::Table(left)
     }
 
+    /// A setting from the `hab` table of the merged configuration, where a service can override
+    /// how the supervisor runs it.
+    fn hab_setting(&self, name: &str) -> Option<toml::Value> {
+        self.to_toml().lookup(&format!("hab.{}", name)).cloned()
+    }
+
+    fn shutdown_signal(&self) -> Option<Signal> {
+        match self.hab_setting("shutdown_signal") {
+            Some(toml::Value::String(name)) => {
+                match Signal::from_str(&name) {
+                    Ok(signal) => Some(signal),
+                    Err(e) => {
+                        outputln!("Ignoring hab.shutdown_signal: {}", e);
+                        None
+                    }
+                }
+            }
+            Some(_) => {
+                outputln!("Ignoring hab.shutdown_signal: it must be a string");
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn shutdown_timeout(&self) -> Option<u64> {
+        match self.hab_setting("shutdown_timeout") {
+            Some(toml::Value::Integer(secs)) if secs >= 0 => Some(secs as u64),
+            Some(_) => {
+                outputln!("Ignoring hab.shutdown_timeout: it must be a number of seconds");
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn limits(&self) -> Limits {
+        let table = match self.hab_setting("limits") {
+            Some(toml::Value::Table(table)) => table,
+            Some(_) => {
+                outputln!("Ignoring hab.limits: it must be a table");
+                return Limits::default();
+            }
+            None => return Limits::default(),
+        };
+        Limits {
+            memory: limit(&table, "memory"),
+            cpu_shares: limit(&table, "cpu_shares"),
+            nofile: limit(&table, "nofile"),
+            nproc: limit(&table, "nproc"),
+        }
+    }
+
     fn load_default(&mut self, pkg: &Package) -> Result<()> {
         // Default
         let mut file = match File::open(pkg.path().join("default.toml")) {
@@ -445,6 +666,27 @@ impl Cfg {
     }
 }
 
+/// A limit from the `hab.limits` table; a number, or a string with a suffix like `512M`.
+fn limit(table: &toml::Table, name: &str) -> Option<u64> {
+    match table.get(name) {
+        Some(&toml::Value::Integer(n)) if n >= 0 => Some(n as u64),
+        Some(&toml::Value::String(ref s)) => {
+            match limits::parse_bytes(s) {
+                Ok(n) => Some(n),
+                Err(e) => {
+                    outputln!("Ignoring hab.limits.{}: {}", name, e);
+                    None
+                }
+            }
+        }
+        Some(_) => {
+            outputln!("Ignoring hab.limits.{}: it must be a number", name);
+            None
+        }
+        None => None,
+    }
+}
+
 #[derive(Debug, RustcEncodable)]
 pub struct Pkg {
     pub origin: String,
@@ -592,36 +834,15 @@ impl Hab {
 
 #[cfg(test)]
 mod test {
-    use std::path::PathBuf;
-    use std::str::FromStr;
-
     use regex::Regex;
 
     use census::{CensusEntry, Census, CensusList};
     use config::Config;
     use gossip::member::MemberId;
-    use hcore::package::{PackageIdent, PackageInstall};
-    use package::Package;
+    use package::test::gen_pkg;
     use service_config::ServiceConfig;
     use VERSION;
 
-    fn gen_pkg() -> Package {
-        let pkg_install = PackageInstall::new_from_parts(
-            PackageIdent::from_str("neurosis/sovereign/2000/20160222201258").unwrap(),
-            PathBuf::from("/"),
-            PathBuf::from("/fakeo"),
-            PathBuf::from("/fakeo/here"));
-        Package {
-            origin: String::from("neurosis"),
-            name: String::from("sovereign"),
-            version: String::from("2000"),
-            release: String::from("20160222201258"),
-            deps: Vec::new(),
-            tdeps: Vec::new(),
-            pkg_install: pkg_install,
-        }
-    }
-
     fn gen_census_list() -> CensusList {
         let ce = CensusEntry::new("redis", "default", MemberId::new_v4());
         let c = Census::new(ce);
@@ -659,6 +880,72 @@ mod test {
         assert!(re.is_match(&ip));
     }
 
+    mod bind {
+        use census::{Census, CensusEntry};
+        use config::Config;
+        use gossip::member::MemberId;
+        use health_check::Status;
+        use service_config::ServiceConfig;
+        use toml;
+        use package::test::gen_pkg;
+        use super::gen_census_list;
+
+        fn bound_toml() -> toml::Value {
+            let mut leader = CensusEntry::new("db", "default", MemberId::new_v4());
+            leader.leader = true;
+            leader.health_check(Some(Status::Ok));
+            let mut sick = CensusEntry::new("db", "default", MemberId::new_v4());
+            sick.follower = true;
+            sick.health_check(Some(Status::Critical));
+            let mut suspect = CensusEntry::new("db", "default", MemberId::new_v4());
+            suspect.follower = true;
+            suspect.alive = false;
+            suspect.suspect = true;
+            let mut census = Census::new(leader);
+            census.add(sick);
+            census.add(suspect);
+            let mut cl = gen_census_list();
+            cl.insert(census);
+            let sc = ServiceConfig::new(&Config::default(),
+                                        &gen_pkg(),
+                                        &cl,
+                                        vec![String::from("database:db.default")])
+                .unwrap();
+            sc.to_toml().unwrap()
+        }
+
+        fn count(toml: &toml::Value, path: &str) -> usize {
+            toml.lookup(path).unwrap().as_slice().unwrap().len()
+        }
+
+        #[test]
+        fn splits_members_by_state() {
+            let toml = bound_toml();
+            assert_eq!(count(&toml, "bind.database.members"), 3);
+            assert_eq!(count(&toml, "bind.database.alive_members"), 2);
+            assert_eq!(count(&toml, "bind.database.suspect_members"), 1);
+            assert_eq!(count(&toml, "bind.database.confirmed_members"), 0);
+            assert_eq!(count(&toml, "bind.database.followers"), 1);
+            assert_eq!(count(&toml, "bind.database.healthy_members"), 1);
+            assert_eq!(count(&toml, "bind.database.unhealthy_members"), 2);
+        }
+
+        #[test]
+        fn first_is_a_healthy_member() {
+            let toml = bound_toml();
+            let first = toml.lookup("bind.database.first").unwrap();
+            assert_eq!(first.lookup("healthy").unwrap().as_bool(), Some(true));
+            assert_eq!(first.lookup("leader").unwrap().as_bool(), Some(true));
+        }
+
+        #[test]
+        fn leader_is_marked_healthy() {
+            let toml = bound_toml();
+            let healthy = toml.lookup("bind.database.leader.healthy").unwrap();
+            assert_eq!(healthy.as_bool(), Some(true));
+        }
+    }
+
     mod sys {
         use config::Config;
         use service_config::Sys;
@@ -706,4 +993,98 @@ mod test {
             assert_eq!(version, VERSION);
         }
     }
+
+    mod cfg {
+        use toml;
+
+        use service_config::Cfg;
+        use util::signals::Signal;
+
+        fn cfg(default: &str, user: &str) -> Cfg {
+            Cfg {
+                default: Some(toml::Value::Table(toml::Parser::new(default).parse().unwrap())),
+                user: Some(toml::Value::Table(toml::Parser::new(user).parse().unwrap())),
+                gossip: None,
+                environment: None,
+            }
+        }
+
+        #[test]
+        fn shutdown_settings_are_optional() {
+            let c = cfg("port = 80", "");
+            assert_eq!(c.shutdown_signal(), None);
+            assert_eq!(c.shutdown_timeout(), None);
+        }
+
+        #[test]
+        fn shutdown_settings_come_from_the_hab_table() {
+            let c = cfg("[hab]\nshutdown_signal = \"TERM\"\nshutdown_timeout = 8",
+                        "[hab]\nshutdown_signal = \"QUIT\"\nshutdown_timeout = 30");
+            assert_eq!(c.shutdown_signal(), Some(Signal::SIGQUIT));
+            assert_eq!(c.shutdown_timeout(), Some(30));
+        }
+
+        #[test]
+        fn limits_come_from_the_hab_table() {
+            let c = cfg("[hab.limits]\nmemory = \"512M\"\nnofile = 4096\nnproc = \"lots\"",
+                        "");
+            let limits = c.limits();
+            assert_eq!(limits.memory, Some(512 * 1024 * 1024));
+            assert_eq!(limits.nofile, Some(4096));
+            assert_eq!(limits.nproc, None);
+            assert_eq!(limits.cpu_shares, None);
+            assert!(cfg("port = 80", "").limits().is_empty());
+        }
+
+        #[test]
+        fn bad_shutdown_settings_are_ignored() {
+            let c = cfg("[hab]\nshutdown_signal = \"NOPE\"\nshutdown_timeout = -1", "");
+            assert_eq!(c.shutdown_signal(), None);
+            assert_eq!(c.shutdown_timeout(), None);
+        }
+    }
+
+    mod schema {
+        use toml;
+
+        use schema::Schema;
+        use service_config::enforce_layer;
+
+        fn layer(toml: &str) -> Option<toml::Value> {
+            Some(toml::Value::Table(toml::Parser::new(toml).parse().unwrap()))
+        }
+
+        fn schema() -> Schema {
+            Schema::from_str("[port]\ntype = \"integer\"\nrequired = true\n\n\
+                              [name]\ntype = \"string\"\nrequired = true")
+                .unwrap()
+        }
+
+        #[test]
+        fn merges_a_valid_layer() {
+            let mut merged = toml::Parser::new("port = 80").parse().unwrap();
+            let mut gossip = layer("port = 8080");
+            assert_eq!(enforce_layer(&schema(), &mut merged, &mut gossip), None);
+            assert!(gossip.is_some());
+            assert_eq!(merged.get("port").unwrap().as_integer(), Some(8080));
+        }
+
+        #[test]
+        fn drops_a_layer_with_new_problems() {
+            let mut merged = toml::Parser::new("port = 80\nname = \"web\"").parse().unwrap();
+            let mut gossip = layer("port = \"eighty\"");
+            assert_eq!(enforce_layer(&schema(), &mut merged, &mut gossip),
+                       Some("port must be an integer".to_string()));
+            assert!(gossip.is_none());
+            assert_eq!(merged.get("port").unwrap().as_integer(), Some(80));
+        }
+
+        #[test]
+        fn does_not_blame_a_layer_for_old_problems() {
+            let mut merged = toml::Parser::new("port = 80").parse().unwrap();
+            let mut user = layer("port = 8080");
+            assert_eq!(enforce_layer(&schema(), &mut merged, &mut user), None);
+            assert!(user.is_some());
+        }
+    }
 }
@@ -0,0 +1,219 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! Captures the output of a supervised service to log files.
+//!
+//! Every line the service writes is tagged with a timestamp, the package ident and the stream it
+//! came from, and appended to `logs/service.log` in the service's var directory. Lines are
+//! written as JSON objects when JSON output is on, and as plain text otherwise.
+//!
+//! Once the file would grow past the size limit it is rotated: `service.log.1` becomes
+//! `service.log.2` and so on, `service.log` becomes `service.log.1`, and anything past the file
+//! count limit is dropped.
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use rustc_serialize::json;
+use time;
+
+use error::Result;
+use output;
+
+/// The name of the log file in the service's log directory
+pub const LOG_FILENAME: &'static str = "service.log";
+
+/// The stream a line of output was written to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+impl fmt::Display for Stream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Stream::Stdout => write!(f, "stdout"),
+            Stream::Stderr => write!(f, "stderr"),
+        }
+    }
+}
+
+#[derive(RustcEncodable)]
+struct LogLine<'a> {
+    timestamp: String,
+    ident: &'a str,
+    stream: String,
+    content: &'a str,
+}
+
+/// A rotating log file for a service's output.
+#[derive(Debug)]
+pub struct ServiceLog {
+    dir: PathBuf,
+    /// Rotate once the file would grow past this many bytes; 0 never rotates
+    max_bytes: u64,
+    /// How many rotated files to keep
+    max_files: usize,
+    file: Option<File>,
+    size: u64,
+}
+
+impl ServiceLog {
+    pub fn new<P: Into<PathBuf>>(dir: P, max_bytes: u64, max_files: usize) -> ServiceLog {
+        ServiceLog {
+            dir: dir.into(),
+            max_bytes: max_bytes,
+            max_files: max_files,
+            file: None,
+            size: 0,
+        }
+    }
+
+    /// The path to the current log file
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(LOG_FILENAME)
+    }
+
+    fn rotated_path(&self, n: usize) -> PathBuf {
+        self.dir.join(format!("{}.{}", LOG_FILENAME, n))
+    }
+
+    /// Append a line of output, rotating the file first if the line would take it past the size
+    /// limit.
+    ///
+    /// # Errors
+    ///
+    /// * If the log directory or file can't be created
+    /// * If the file can't be rotated or written
+    pub fn write_line(&mut self, stream: Stream, ident: &str, content: &str) -> Result<()> {
+        let line = try!(format_line(stream, ident, content, output::is_json()));
+        if self.file.is_none() {
+            try!(self.open());
+        }
+        if self.max_bytes > 0 && self.size > 0 &&
+           self.size + line.len() as u64 > self.max_bytes {
+            try!(self.rotate());
+        }
+        if let Some(ref mut file) = self.file {
+            try!(file.write_all(line.as_bytes()));
+        }
+        self.size += line.len() as u64;
+        Ok(())
+    }
+
+    fn open(&mut self) -> Result<()> {
+        try!(fs::create_dir_all(&self.dir));
+        let file = try!(OpenOptions::new().append(true).create(true).open(self.path()));
+        self.size = try!(file.metadata()).len();
+        self.file = Some(file);
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> Result<()> {
+        self.file = None;
+        if self.max_files == 0 {
+            try!(fs::remove_file(self.path()));
+        } else {
+            for n in (1..self.max_files).rev() {
+                let from = self.rotated_path(n);
+                if from.is_file() {
+                    try!(fs::rename(&from, self.rotated_path(n + 1)));
+                }
+            }
+            try!(fs::rename(self.path(), self.rotated_path(1)));
+        }
+        self.open()
+    }
+}
+
+/// Format a line of output for the log file, as JSON or as plain text.
+fn format_line(stream: Stream, ident: &str, content: &str, as_json: bool) -> Result<String> {
+    let timestamp = format!("{}", time::now_utc().rfc3339());
+    let content = content.trim_right_matches(|c| c == '\n' || c == '\r');
+    if as_json {
+        let line = LogLine {
+            timestamp: timestamp,
+            ident: ident,
+            stream: stream.to_string(),
+            content: content,
+        };
+        Ok(format!("{}\n", try!(json::encode(&line))))
+    } else {
+        Ok(format!("{} {} {}: {}\n", timestamp, ident, stream, content))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::Read;
+    use std::path::Path;
+
+    use rustc_serialize::json::Json;
+    use tempdir::TempDir;
+
+    use super::{format_line, ServiceLog, Stream};
+
+    fn read(path: &Path) -> String {
+        let mut body = String::new();
+        File::open(path).unwrap().read_to_string(&mut body).unwrap();
+        body
+    }
+
+    #[test]
+    fn format_text_line() {
+        let line = format_line(Stream::Stderr, "core/redis", "oh no\n", false).unwrap();
+        assert!(line.ends_with(" core/redis stderr: oh no\n"));
+    }
+
+    #[test]
+    fn format_json_line() {
+        let line = format_line(Stream::Stdout, "core/redis", "ready\r\n", true).unwrap();
+        assert!(line.ends_with("}\n"));
+        let parsed = Json::from_str(line.trim()).unwrap();
+        let obj = parsed.as_object().unwrap();
+        assert_eq!(obj["ident"].as_string(), Some("core/redis"));
+        assert_eq!(obj["stream"].as_string(), Some("stdout"));
+        assert_eq!(obj["content"].as_string(), Some("ready"));
+        assert!(obj["timestamp"].is_string());
+    }
+
+    #[test]
+    fn writes_and_appends() {
+        let tmp = TempDir::new("service-log").unwrap();
+        let dir = tmp.path().join("logs");
+        {
+            let mut log = ServiceLog::new(&dir, 0, 3);
+            log.write_line(Stream::Stdout, "core/redis", "one").unwrap();
+        }
+        let mut log = ServiceLog::new(&dir, 0, 3);
+        log.write_line(Stream::Stderr, "core/redis", "two").unwrap();
+        let body = read(&log.path());
+        assert_eq!(body.lines().count(), 2);
+        assert!(body.lines().next().unwrap().ends_with("stdout: one"));
+        assert!(body.lines().nth(1).unwrap().ends_with("stderr: two"));
+    }
+
+    #[test]
+    fn rotates_by_size_and_count() {
+        let tmp = TempDir::new("service-log").unwrap();
+        let line_len = format_line(Stream::Stdout, "core/redis", "0", false).unwrap().len() as u64;
+        // Room for two lines per file
+        let mut log = ServiceLog::new(tmp.path(), line_len * 2, 2);
+        for n in 0..7 {
+            log.write_line(Stream::Stdout, "core/redis", &n.to_string()).unwrap();
+        }
+        assert!(read(&log.path()).ends_with(": 6\n"));
+        assert_eq!(read(&log.path()).lines().count(), 1);
+        assert!(read(&tmp.path().join("service.log.1")).ends_with(": 5\n"));
+        assert!(read(&tmp.path().join("service.log.2")).ends_with(": 3\n"));
+        assert!(!tmp.path().join("service.log.3").exists());
+    }
+}
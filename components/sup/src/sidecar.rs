@@ -11,39 +11,92 @@
 //! Supports:
 //!
 //! * /config: Returns the current configuration of the service
+//! * /config/history: Returns the configurations the service has been rendered with, newest first
+//! * /config/history/:id: Returns one of them, with the gossip file and every rendered file
 //! * /health: Returns the current health of the service
 //! * /status: Returns the current status of the service, from the supervisors point of view
+//! * /metrics: Returns metrics about the service, the gossip ring and the census, for Prometheus
+//! * /census: Returns the census of every service group
+//! * /election: Returns the elections of every service group
+//! * /files: Returns the files uploaded to every service group
+//! * /watch: Streams changes to the census, elections, files and configuration as server-sent
+//!   events
+//!
+//! The census, election, files and config routes are blocking queries: they return the index of
+//! what they describe in the `X-Hab-Index` header, and given that index back as `?index=N`, hold
+//! the request until it changes, or for `?wait=30s`.
+//!
+//! And the control API, which asks the main loop to act on the service:
+//!
+//! * POST /control/restart: Restarts the service
+//! * POST /control/stop: Stops the service, until it is started through the control API again
+//! * POST /control/start: Starts a stopped service
+//! * POST /control/reconfigure: Re-renders the configuration and runs the reconfigure hook
+//! * POST /control/health: Runs the health check now, rather than when it is next due
+//!
+//! Control requests must carry the shared control token in an `Authorization: Bearer` header, or
+//! have a body encrypted with a trusted user's key to the service key, the same way files are
+//! uploaded. The encrypted body is the name of the action, the time of the request in seconds
+//! since the epoch, and a nonce, on separate lines. Each nonce is accepted once.
 
 use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Read, Write};
 use std::net::SocketAddrV4;
-use std::sync::{Arc, RwLock};
+use std::path::Path;
+use std::result;
+use std::str::{self, FromStr};
+use std::sync::{Arc, Mutex, RwLock};
+use std::sync::mpsc::Sender;
+use std::time::Duration;
 
+use hcore::crypto::{default_cache_key_path, BoxKeyPair};
+use hcore::crypto::keys::parse_name_with_rev;
+use hcore::service::ServiceGroup;
 use rustc_serialize::json;
 use iron::prelude::*;
+use iron::response::{ResponseBody, WriteBody};
 use iron::status;
 use router::Router;
+use time::{self, SteadyTime};
+use urlencoded::UrlEncodedQuery;
 use wonder;
 use wonder::actor::{GenServer, InitResult, HandleResult, StopReason, ActorSender};
 
-use error::{Error, SupError};
+use error::{Error, Result, SupError};
 use health_check;
+use metrics::{flag, Kind, Metrics, CONTENT_TYPE};
 use package::Package;
 use common::gossip_file::{GossipFileList, FileWriteRetry};
+use config_history::{ConfigSet, History};
 use service_config::ServiceConfig;
-use gossip::member::{MemberList, MemberId};
-use gossip::rumor::RumorList;
-use gossip::detector::Detector;
+use gossip::member::{Health, MemberList, MemberId};
+use gossip::rumor::{Message, RumorList};
+use gossip::detector::{self, Detector};
 use census::{CensusList, CensusEntry, CensusEntryId, Census};
-use election::{Election, ElectionList};
+use election::{Election, ElectionList, ElectionStatus};
 use supervisor::Supervisor;
+use watch::{self, Indexes, Topic, Waiter, Watcher};
 
 static LOGKEY: &'static str = "SI";
 const GET_HEALTH: &'static str = "/health";
 const GET_CONFIG: &'static str = "/config";
+const GET_CONFIG_HISTORY: &'static str = "/config/history";
+const GET_CONFIG_VERSION: &'static str = "/config/version";
 const GET_STATUS: &'static str = "/status";
 const GET_GOSSIP: &'static str = "/gossip";
 const GET_CENSUS: &'static str = "/census";
 const GET_ELECTION: &'static str = "/election";
+const GET_METRICS: &'static str = "/metrics";
+const GET_FILES: &'static str = "/files";
+const GET_WATCH: &'static str = "/watch";
+const POST_CONTROL: &'static str = "/control";
+/// How old a signed control request may be, in seconds, before we refuse it
+const CONTROL_REQUEST_MAX_AGE_SECS: i64 = 300;
+/// The header that carries the index of a blocking query's topic
+const INDEX_HEADER: &'static str = "X-Hab-Index";
+/// How often an event stream with nothing to send writes a comment, so dead clients are noticed
+const WATCH_KEEPALIVE_SECS: u64 = 15;
 
 pub type SidecarActor = wonder::actor::Actor<SidecarMessage>;
 
@@ -63,6 +116,14 @@ pub struct SidecarState {
     pub election_list: Arc<RwLock<ElectionList>>,
     pub supervisor: Arc<RwLock<Supervisor>>,
     pub gossip_file_list: Arc<RwLock<GossipFileList>>,
+    /// Where we send the requests made through the control API
+    pub control: Arc<Mutex<Sender<ControlRequest>>>,
+    /// What authorizes control requests
+    pub control_auth: Arc<ControlAuth>,
+    /// The results of the health checks the main loop runs
+    pub health_history: Arc<RwLock<health_check::History>>,
+    /// Where the main loop records the changes blocking queries and event streams wait on
+    pub watcher: Arc<Watcher>,
 }
 
 #[derive(Debug)]
@@ -71,6 +132,118 @@ pub enum SidecarMessage {
     Stop,
 }
 
+/// Something the control API asks the main loop to do to the service
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlRequest {
+    Restart,
+    Stop,
+    Start,
+    Reconfigure,
+    HealthCheck,
+}
+
+impl ControlRequest {
+    /// Every control request, in the order we register their routes
+    pub fn all() -> [ControlRequest; 5] {
+        [ControlRequest::Restart,
+         ControlRequest::Stop,
+         ControlRequest::Start,
+         ControlRequest::Reconfigure,
+         ControlRequest::HealthCheck]
+    }
+}
+
+impl fmt::Display for ControlRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let action = match *self {
+            ControlRequest::Restart => "restart",
+            ControlRequest::Stop => "stop",
+            ControlRequest::Start => "start",
+            ControlRequest::Reconfigure => "reconfigure",
+            ControlRequest::HealthCheck => "health",
+        };
+        write!(f, "{}", action)
+    }
+}
+
+impl FromStr for ControlRequest {
+    type Err = String;
+
+    fn from_str(action: &str) -> result::Result<ControlRequest, String> {
+        match action {
+            "restart" => Ok(ControlRequest::Restart),
+            "stop" => Ok(ControlRequest::Stop),
+            "start" => Ok(ControlRequest::Start),
+            "reconfigure" => Ok(ControlRequest::Reconfigure),
+            "health" => Ok(ControlRequest::HealthCheck),
+            _ => Err(format!("unknown control action {}", action)),
+        }
+    }
+}
+
+/// What authorizes requests to the control API
+pub struct ControlAuth {
+    /// The shared token, if there is one
+    token: Option<String>,
+    /// The users whose signed requests we accept; with none, only the token is accepted
+    trusted_users: Vec<String>,
+    /// The name of our service key, which signed requests must be encrypted to. Only a service
+    /// group in an organization has one.
+    service_key: Option<String>,
+    /// The nonces of the signed requests we have accepted, with the time they were signed. A
+    /// nonce is kept for as long as its request is recent enough to accept, so it can't be
+    /// replayed.
+    nonces: Mutex<HashMap<String, i64>>,
+}
+
+impl ControlAuth {
+    pub fn new(token: Option<String>,
+               trusted_users: Vec<String>,
+               service_group: &ServiceGroup)
+               -> ControlAuth {
+        ControlAuth {
+            token: token,
+            trusted_users: trusted_users,
+            service_key: service_group.organization.as_ref().map(|_| service_group.to_string()),
+            nonces: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Accept a signed request that has been verified, if a trusted user signed it and its
+    /// nonce hasn't been seen. Returns the name of the user key that signed it.
+    fn accept(&self, request: SignedRequest, now: i64) -> result::Result<String, String> {
+        let user = match parse_name_with_rev(&request.sender) {
+            Ok((user, _)) => user,
+            Err(e) => return Err(format!("the signed body has no valid sender: {}", e)),
+        };
+        if !self.trusted_users.contains(&user) {
+            return Err(format!("{} is not trusted to control this service", user));
+        }
+        let mut nonces = self.nonces.lock().unwrap();
+        let expired: Vec<String> = nonces.iter()
+            .filter(|&(_, signed_at)| now - *signed_at > CONTROL_REQUEST_MAX_AGE_SECS)
+            .map(|(nonce, _)| nonce.clone())
+            .collect();
+        for nonce in expired {
+            nonces.remove(&nonce);
+        }
+        if nonces.contains_key(&request.nonce) {
+            return Err(String::from("the signed body has been used before"));
+        }
+        nonces.insert(request.nonce, request.signed_at);
+        Ok(request.sender)
+    }
+}
+
+/// A control request whose signed body we have decrypted and checked
+#[derive(Debug, PartialEq, Eq)]
+struct SignedRequest {
+    /// The user key that signed it
+    sender: String,
+    signed_at: i64,
+    nonce: String,
+}
+
 impl SidecarState {
     pub fn new(listen: SocketAddrV4,
                package: Arc<RwLock<Package>>,
@@ -81,7 +254,11 @@ impl SidecarState {
                detector: Arc<RwLock<Detector>>,
                election_list: Arc<RwLock<ElectionList>>,
                supervisor: Arc<RwLock<Supervisor>>,
-               gossip_file_list: Arc<RwLock<GossipFileList>>)
+               gossip_file_list: Arc<RwLock<GossipFileList>>,
+               control: Sender<ControlRequest>,
+               control_auth: ControlAuth,
+               health_history: Arc<RwLock<health_check::History>>,
+               watcher: Arc<Watcher>)
                -> Self {
         SidecarState {
             listen: listen,
@@ -94,6 +271,10 @@ impl SidecarState {
             election_list: election_list,
             supervisor: supervisor,
             gossip_file_list: gossip_file_list,
+            control: Arc::new(Mutex::new(control)),
+            control_auth: Arc::new(control_auth),
+            health_history: health_history,
+            watcher: watcher,
         }
     }
 }
@@ -109,7 +290,11 @@ impl Sidecar {
                  detector: Arc<RwLock<Detector>>,
                  election_list: Arc<RwLock<ElectionList>>,
                  supervisor: Arc<RwLock<Supervisor>>,
-                 gossip_file_list: Arc<RwLock<GossipFileList>>)
+                 gossip_file_list: Arc<RwLock<GossipFileList>>,
+                 control: Sender<ControlRequest>,
+                 control_auth: ControlAuth,
+                 health_history: Arc<RwLock<health_check::History>>,
+                 watcher: Arc<Watcher>)
                  -> SidecarActor {
         let state = SidecarState::new(listen,
                                       package,
@@ -120,7 +305,11 @@ impl Sidecar {
                                       detector,
                                       election_list,
                                       supervisor,
-                                      gossip_file_list);
+                                      gossip_file_list,
+                                      control,
+                                      control_auth,
+                                      health_history,
+                                      watcher);
         wonder::actor::Builder::new(Sidecar).name("sidecar".to_string()).start(state).unwrap()
     }
 }
@@ -140,11 +329,39 @@ impl GenServer for Sidecar {
                       state: &mut Self::S)
                       -> HandleResult<Self::T> {
         let mut router = Router::new();
-        let package_1 = state.package.clone();
         let package_2 = state.package.clone();
         let config_1 = state.config.clone();
+        let watched = Watched {
+            package: state.package.clone(),
+            census_list: state.census_list.clone(),
+            election_list: state.election_list.clone(),
+            gossip_file_list: state.gossip_file_list.clone(),
+        };
+
+        for &(route, topic) in [(GET_CONFIG, Topic::Config),
+                                (GET_CENSUS, Topic::Census),
+                                (GET_ELECTION, Topic::Election),
+                                (GET_FILES, Topic::Files)]
+            .iter() {
+            let watched = watched.clone();
+            let watcher = state.watcher.clone();
+            router.get(route, move |r: &mut Request| blocking(topic, &watched, &watcher, r));
+        }
+
+        let history_package = state.package.clone();
+        router.get(GET_CONFIG_HISTORY,
+                   move |r: &mut Request| config_history(&history_package, r));
 
-        router.get(GET_CONFIG, move |r: &mut Request| config(&package_1, r));
+        let history_set_package = state.package.clone();
+        router.get(&format!("{}/:id", GET_CONFIG_HISTORY),
+                   move |r: &mut Request| config_history_set(&history_set_package, r));
+
+        let version_gfl = state.gossip_file_list.clone();
+        router.get(GET_CONFIG_VERSION,
+                   move |r: &mut Request| config_version(&version_gfl, r));
+
+        let watch_watcher = state.watcher.clone();
+        router.get(GET_WATCH, move |r: &mut Request| watch(&watched, &watch_watcher, r));
 
         let supervisor_1 = state.supervisor.clone();
         router.get(GET_STATUS, move |r: &mut Request| status(&supervisor_1, r));
@@ -164,11 +381,31 @@ impl GenServer for Sidecar {
         router.get(GET_GOSSIP,
                    move |r: &mut Request| gossip(&ml, &rl, &gfl, &detector, &id, r));
 
-        let cl1 = state.census_list.clone();
-        router.get(GET_CENSUS, move |r: &mut Request| census(&cl1, r));
+        let metrics_sup = state.supervisor.clone();
+        let metrics_health = state.health_history.clone();
+        let metrics_ml = state.member_list.clone();
+        let metrics_rl = state.rumor_list.clone();
+        let metrics_detector = state.detector.clone();
+        let metrics_cl = state.census_list.clone();
+        let metrics_el = state.election_list.clone();
+        router.get(GET_METRICS, move |r: &mut Request| {
+            metrics(&metrics_sup,
+                    &metrics_health,
+                    &metrics_ml,
+                    &metrics_rl,
+                    &metrics_detector,
+                    &metrics_cl,
+                    &metrics_el,
+                    r)
+        });
 
-        let el = state.election_list.clone();
-        router.get(GET_ELECTION, move |r: &mut Request| election(&el, r));
+        for action in ControlRequest::all().iter() {
+            let action = *action;
+            let tx = state.control.clone();
+            let auth = state.control_auth.clone();
+            router.post(&format!("{}/{}", POST_CONTROL, action),
+                        move |r: &mut Request| control(action, &tx, &auth, r));
+        }
 
         match Iron::new(router).http(state.listen) {
             Ok(_) => HandleResult::NoReply(None),
@@ -185,19 +422,16 @@ struct ElectionResponse<'a> {
     mine: Option<&'a Election>,
 }
 
-fn election(election_list: &Arc<RwLock<ElectionList>>, _req: &mut Request) -> IronResult<Response> {
+/// The /election body.
+///
+/// Returns every election we know about, and the one for our service group.
+fn election(election_list: &Arc<RwLock<ElectionList>>) -> Result<String> {
     let el = election_list.read().unwrap();
     let er = ElectionResponse {
         elections: &el.elections,
         mine: el.election(),
     };
-
-    let json_response = match json::encode(&er) {
-        Ok(json_response) => json_response,
-        Err(e) => return Err(IronError::from(sup_error!(Error::JsonEncode(e)))),
-    };
-
-    Ok(Response::with((status::Ok, json_response)))
+    Ok(try!(json::encode(&er)))
 }
 
 #[derive(Debug, RustcEncodable)]
@@ -251,10 +485,10 @@ struct CensusResponse<'a> {
     leader: Option<&'a CensusEntry>,
 }
 
-/// The /census callback.
+/// The /census body.
 ///
 /// Returns information about the census.
-fn census(census_list: &Arc<RwLock<CensusList>>, _req: &mut Request) -> IronResult<Response> {
+fn census(census_list: &Arc<RwLock<CensusList>>) -> Result<String> {
     let cl = census_list.read().unwrap();
     let response = CensusResponse {
         id: &cl.me().id.clone(),
@@ -265,38 +499,317 @@ fn census(census_list: &Arc<RwLock<CensusList>>, _req: &mut Request) -> IronResu
         quorum: cl.local_census().has_quorum(),
         leader: cl.local_census().get_leader(),
     };
+    Ok(try!(json::encode(&response)))
+}
 
-    let json_response = match json::encode(&response) {
-        Ok(json_response) => json_response,
-        Err(e) => return Err(IronError::from(sup_error!(Error::JsonEncode(e)))),
-    };
+#[derive(Debug, RustcEncodable)]
+struct FileResponse<'a> {
+    service_group: &'a ServiceGroup,
+    file_name: &'a str,
+    signer: Option<String>,
+}
 
-    Ok(Response::with((status::Ok, json_response)))
+/// The /files body.
+///
+/// Returns the service group and name of every file uploaded to the ring.
+fn files(gossip_file_list: &Arc<RwLock<GossipFileList>>) -> Result<String> {
+    let gfl = gossip_file_list.read().unwrap();
+    let mut files: Vec<FileResponse> = gfl.files()
+        .map(|file| {
+            FileResponse {
+                service_group: &file.service_group,
+                file_name: &file.file_name,
+                signer: file.signer(),
+            }
+        })
+        .collect();
+    files.sort_by(|a, b| {
+        (a.service_group.to_string(), a.file_name)
+            .cmp(&(b.service_group.to_string(), b.file_name))
+    });
+    Ok(try!(json::encode(&files)))
 }
 
-/// The /config callback.
+/// The /config body.
 ///
 /// Returns the current running configuration.
 ///
 /// # Failures
 ///
 /// * Fails if the configuration cannot be found.
-fn config(lock: &Arc<RwLock<Package>>, _req: &mut Request) -> IronResult<Response> {
+fn config(lock: &Arc<RwLock<Package>>) -> Result<String> {
     let package = lock.read().unwrap();
-    let last_config = try!(package.last_config());
-    Ok(Response::with((status::Ok, last_config)))
+    package.last_config()
+}
+
+#[derive(Debug, RustcEncodable)]
+struct ConfigSetSummary<'a> {
+    id: u64,
+    gossip_version: Option<u64>,
+    rendered_at: i64,
+    files: Vec<&'a String>,
+}
+
+/// The /config/history callback.
+///
+/// Returns a summary of every configuration in the service's history, newest first.
+///
+/// # Failures
+///
+/// * Fails if the history cannot be read.
+fn config_history(lock: &Arc<RwLock<Package>>, _req: &mut Request) -> IronResult<Response> {
+    let sets = {
+        let package = lock.read().unwrap();
+        try!(History::for_package(&package).list())
+    };
+    let summaries: Vec<ConfigSetSummary> = sets.iter()
+        .rev()
+        .map(|set| {
+            ConfigSetSummary {
+                id: set.id,
+                gossip_version: set.gossip_version,
+                rendered_at: set.rendered_at,
+                files: set.files.keys().collect(),
+            }
+        })
+        .collect();
+    let body = try!(json::encode(&summaries).map_err(|e| sup_error!(Error::JsonEncode(e))));
+    Ok(Response::with((status::Ok, body)))
+}
+
+/// The /config/history/:id callback.
+///
+/// Returns one configuration from the service's history.
+///
+/// # Failures
+///
+/// * `400 Bad Request` if the id isn't a number
+/// * `404 Not Found` if the history no longer has it
+/// * Fails if the history cannot be read.
+fn config_history_set(lock: &Arc<RwLock<Package>>, req: &mut Request) -> IronResult<Response> {
+    let id = req.extensions.get::<Router>().unwrap().find("id").map(|id| id.parse::<u64>());
+    let id = match id {
+        Some(Ok(id)) => id,
+        _ => return Ok(Response::with((status::BadRequest, "The id must be a number\n"))),
+    };
+    let set: Option<ConfigSet> = {
+        let package = lock.read().unwrap();
+        try!(History::for_package(&package).get(id))
+    };
+    match set {
+        Some(set) => {
+            let body = try!(json::encode(&set).map_err(|e| sup_error!(Error::JsonEncode(e))));
+            Ok(Response::with((status::Ok, body)))
+        }
+        None => Ok(Response::with((status::NotFound, format!("No configuration {}\n", id)))),
+    }
+}
+
+#[derive(Debug, RustcEncodable)]
+struct ConfigVersionResponse {
+    version: Option<u64>,
+}
+
+/// The /config/version callback.
+///
+/// Returns the version of the `gossip.toml` we have for our service group, or null if we have
+/// none. Rollbacks gossip the configuration they roll back to with a version past this one.
+fn config_version(gossip_file_list: &Arc<RwLock<GossipFileList>>,
+                  _req: &mut Request)
+                  -> IronResult<Response> {
+    let cvr = ConfigVersionResponse { version: gossip_file_list.read().unwrap().config_version() };
+    let body = try!(json::encode(&cvr).map_err(|e| sup_error!(Error::JsonEncode(e))));
+    Ok(Response::with((status::Ok, body)))
+}
+
+/// Everything the blocking queries and event streams describe.
+#[derive(Clone)]
+struct Watched {
+    package: Arc<RwLock<Package>>,
+    census_list: Arc<RwLock<CensusList>>,
+    election_list: Arc<RwLock<ElectionList>>,
+    gossip_file_list: Arc<RwLock<GossipFileList>>,
+}
+
+impl Watched {
+    /// The body of a topic's route.
+    fn render(&self, topic: Topic) -> Result<String> {
+        match topic {
+            Topic::Census => census(&self.census_list),
+            Topic::Election => election(&self.election_list),
+            Topic::Files => files(&self.gossip_file_list),
+            Topic::Config => config(&self.package),
+        }
+    }
+}
+
+/// The /census, /election, /files and /config callbacks.
+///
+/// Returns the topic, with its index in the `X-Hab-Index` header. If the request has an `index`,
+/// waits until the topic's index is past it, or for `wait`, before answering.
+///
+/// # Failures
+///
+/// * `400 Bad Request` if the index or wait can't be parsed
+/// * `503 Service Unavailable` if it would wait, and too many requests are waiting already
+/// * Fails if the topic cannot be rendered
+fn blocking(topic: Topic,
+            watched: &Watched,
+            watcher: &Arc<Watcher>,
+            req: &mut Request)
+            -> IronResult<Response> {
+    let index = match blocking_query(req) {
+        Ok(Some((index, wait))) => {
+            let _waiter = match watcher.waiter() {
+                Some(waiter) => waiter,
+                None => return Ok(too_many_waiters()),
+            };
+            watcher.wait(topic, index, wait)
+        }
+        Ok(None) => watcher.index(topic),
+        Err(reason) => return Ok(Response::with((status::BadRequest, format!("{}\n", reason)))),
+    };
+    let body = try!(watched.render(topic));
+    let mut response = Response::with((status::Ok, body));
+    response.headers.set_raw(INDEX_HEADER, vec![index.to_string().into_bytes()]);
+    Ok(response)
+}
+
+/// The index and wait of a blocking query, if the request is one.
+fn blocking_query(req: &mut Request) -> result::Result<Option<(u64, Duration)>, String> {
+    let index = match query_value("index", req) {
+        Some(index) => try!(index.parse::<u64>().map_err(|_| format!("invalid index {}", index))),
+        None => return Ok(None),
+    };
+    let wait = match query_value("wait", req) {
+        Some(wait) => try!(watch::parse_wait(&wait).ok_or(format!("invalid wait {}", wait))),
+        None => Duration::from_secs(watch::DEFAULT_WAIT_SECS),
+    };
+    Ok(Some((index, wait)))
+}
+
+fn query_value(key: &str, req: &mut Request) -> Option<String> {
+    match req.get_ref::<UrlEncodedQuery>() {
+        Ok(map) => map.get(key).and_then(|values| values.first().cloned()),
+        Err(_) => None,
+    }
+}
+
+/// The /watch callback.
+///
+/// Streams server-sent events for the topics in `topics`, a comma separated list, or every topic
+/// if there isn't one. Each topic is sent as it stands when the stream starts, then again every
+/// time it changes; the event is named for the topic, its id is the topic's index, and its data
+/// is the body of the topic's route.
+///
+/// # Failures
+///
+/// * `400 Bad Request` if a topic is unknown
+/// * `503 Service Unavailable` if too many requests are waiting already
+fn watch(watched: &Watched, watcher: &Arc<Watcher>, req: &mut Request) -> IronResult<Response> {
+    let topics = match query_value("topics", req) {
+        Some(list) => {
+            let mut topics = Vec::new();
+            for name in list.split(',') {
+                match name.trim().parse::<Topic>() {
+                    Ok(topic) => topics.push(topic),
+                    Err(reason) => {
+                        return Ok(Response::with((status::BadRequest, format!("{}\n", reason))))
+                    }
+                }
+            }
+            topics
+        }
+        None => Topic::all().to_vec(),
+    };
+    let waiter = match watcher.waiter() {
+        Some(waiter) => waiter,
+        None => return Ok(too_many_waiters()),
+    };
+    let stream = EventStream {
+        topics: topics,
+        watched: watched.clone(),
+        watcher: watcher.clone(),
+        seen: Indexes::default(),
+        _waiter: waiter,
+    };
+    let mut response = Response::with(status::Ok);
+    response.headers.set_raw("Content-Type", vec![b"text/event-stream".to_vec()]);
+    response.headers.set_raw("Cache-Control", vec![b"no-cache".to_vec()]);
+    response.body = Some(Box::new(stream));
+    Ok(response)
+}
+
+/// The answer to a request that would wait when `watch::MAX_WAITERS` requests are waiting.
+fn too_many_waiters() -> Response {
+    Response::with((status::ServiceUnavailable,
+                    format!("Too many requests are waiting for changes; at most {} may wait\n",
+                            watch::MAX_WAITERS)))
+}
+
+/// The body of a /watch response; writes events until the client goes away.
+struct EventStream {
+    topics: Vec<Topic>,
+    watched: Watched,
+    watcher: Arc<Watcher>,
+    /// The index of each topic we last sent
+    seen: Indexes,
+    /// Our room among the waiting requests, until the stream ends
+    _waiter: Waiter,
+}
+
+impl WriteBody for EventStream {
+    fn write_body(&mut self, res: &mut ResponseBody) -> io::Result<()> {
+        loop {
+            let mut sent = false;
+            for &topic in self.topics.iter() {
+                let index = self.watcher.index(topic);
+                if index <= self.seen.get(topic) {
+                    continue;
+                }
+                self.seen.set(topic, index);
+                let data = match self.watched.render(topic) {
+                    Ok(data) => data,
+                    Err(e) => {
+                        debug!("Not sending {} event {}: {}", topic, index, e);
+                        continue;
+                    }
+                };
+                try!(write!(res, "event: {}\nid: {}\n", topic, index));
+                for line in data.lines() {
+                    try!(write!(res, "data: {}\n", line));
+                }
+                try!(write!(res, "\n"));
+                sent = true;
+            }
+            if !sent {
+                try!(write!(res, ": keepalive\n\n"));
+            }
+            try!(res.flush());
+            self.watcher.wait_any(&self.topics,
+                                  &self.seen,
+                                  Duration::from_secs(WATCH_KEEPALIVE_SECS));
+        }
+    }
 }
 
 /// The /status callback.
 ///
-/// Returns the current status from the supervisors perspective.
+/// Returns the current status from the supervisors perspective, followed by the resource limits
+/// and usage of the service.
 ///
 /// # Failures
 ///
 /// * Fails if the supervisor cannot return the status.
 fn status(lock: &Arc<RwLock<Supervisor>>, _req: &mut Request) -> IronResult<Response> {
     let supervisor = lock.read().unwrap();
-    let (_health, output) = supervisor.status();
+    let (_health, mut output) = supervisor.status();
+    if !supervisor.limits.is_empty() {
+        output.push_str(&format!("\nlimits: {}", supervisor.limits));
+    }
+    if let Some(usage) = supervisor.usage() {
+        output.push_str(&format!("\nusage: {}", usage));
+    }
     Ok(Response::with((status::Ok, output)))
 }
 
@@ -332,6 +845,363 @@ fn health(package_lock: &Arc<RwLock<Package>>,
     }
 }
 
+/// The /metrics callback.
+///
+/// Returns metrics in the Prometheus text format: the uptime, restarts and exits of the service,
+/// the status and duration of the last health check, the members of the ring by health, the
+/// rumors we are spreading and how hot they are, what the failure detector has been up to, and
+/// the census and election of every service group we know about.
+fn metrics(supervisor_lock: &Arc<RwLock<Supervisor>>,
+           health_lock: &Arc<RwLock<health_check::History>>,
+           member_list: &Arc<RwLock<MemberList>>,
+           rumor_list: &Arc<RwLock<RumorList>>,
+           detector: &Arc<RwLock<Detector>>,
+           census_list: &Arc<RwLock<CensusList>>,
+           election_list: &Arc<RwLock<ElectionList>>,
+           _req: &mut Request)
+           -> IronResult<Response> {
+    let mut m = Metrics::new();
+    {
+        let supervisor = supervisor_lock.read().unwrap();
+        let uptime = if supervisor.pid.is_some() && supervisor.is_up() {
+            seconds(SteadyTime::now() - supervisor.state_entered)
+        } else {
+            0.0
+        };
+        m.single("service_up",
+                 Kind::Gauge,
+                 "1 if the service is running",
+                 flag(supervisor.pid.is_some()));
+        m.single("service_uptime_seconds",
+                 Kind::Gauge,
+                 "How long the service has been running",
+                 uptime);
+        m.single("service_restarts_total",
+                 Kind::Counter,
+                 "How many times the supervisor has restarted the service",
+                 supervisor.restarts.count as f64);
+        m.single("service_exits_total",
+                 Kind::Counter,
+                 "How many times the service has exited without being asked to",
+                 supervisor.exit_count as f64);
+    }
+    {
+        let history = health_lock.read().unwrap();
+        let current = history.last().map(|result| &result.status);
+        m.family("health_check_status",
+                 Kind::Gauge,
+                 "1 for the status of the most recent health check");
+        for &(ref status, name) in [(health_check::Status::Ok, "ok"),
+                                    (health_check::Status::Warning, "warning"),
+                                    (health_check::Status::Critical, "critical"),
+                                    (health_check::Status::Unknown, "unknown")]
+            .iter() {
+            m.sample("health_check_status",
+                     &[("status", name)],
+                     flag(current == Some(status)));
+        }
+        if let Some(duration) = history.last_duration() {
+            m.single("health_check_duration_seconds",
+                     Kind::Gauge,
+                     "How long the most recent health check took",
+                     seconds(duration));
+        }
+        m.single("health_checks_total",
+                 Kind::Counter,
+                 "How many health checks the supervisor has run",
+                 history.total() as f64);
+    }
+    {
+        let ml = member_list.read().unwrap();
+        m.family("members", Kind::Gauge, "Members of the gossip ring, by health");
+        for &(ref health, name) in [(Health::Alive, "alive"),
+                                    (Health::Suspect, "suspect"),
+                                    (Health::Confirmed, "confirmed"),
+                                    (Health::Departed, "departed")]
+            .iter() {
+            let count = ml.members().filter(|member| member.health == *health).count();
+            m.sample("members", &[("health", name)], count as f64);
+        }
+    }
+    {
+        let rl = rumor_list.read().unwrap();
+        let mut by_type: HashMap<&str, usize> = HashMap::new();
+        for rumor in rl.rumors.values() {
+            let kind = match rumor.payload {
+                Message::Member(_) => "member",
+                Message::CensusEntry(_) => "census_entry",
+                Message::Election(_) => "election",
+                Message::GossipFile(_) => "gossip_file",
+                Message::Departure(_) => "departure",
+                Message::Blank => "blank",
+            };
+            *by_type.entry(kind).or_insert(0) += 1;
+        }
+        m.family("rumors", Kind::Gauge, "Rumors we know about, by type");
+        for &kind in ["member", "census_entry", "election", "gossip_file", "departure", "blank"]
+            .iter() {
+            m.sample("rumors",
+                     &[("type", kind)],
+                     *by_type.get(&kind).unwrap_or(&0) as f64);
+        }
+        let (heat, heat_max) = rl.heat
+            .values()
+            .flat_map(|rumors| rumors.values())
+            .fold((0, 0), |(sum, max), &heat| (sum + heat, if heat > max { heat } else { max }));
+        m.single("rumor_heat",
+                 Kind::Gauge,
+                 "The total heat of every rumor for every member; rumors cool as we share them",
+                 heat as f64);
+        m.single("rumor_heat_max",
+                 Kind::Gauge,
+                 "The heat of the hottest rumor for any member",
+                 heat_max as f64);
+    }
+    {
+        let fd = detector.read().unwrap();
+        m.single("detector_pings_total",
+                 Kind::Counter,
+                 "Pings the failure detector has sent",
+                 fd.pings as f64);
+        m.single("detector_acks_total",
+                 Kind::Counter,
+                 "Acks the failure detector has received",
+                 fd.acks as f64);
+        m.single("detector_pingreqs_total",
+                 Kind::Counter,
+                 "Ping-reqs the failure detector has sent for members that didn't ack",
+                 fd.pingreqs as f64);
+        m.single("detector_timeouts_total",
+                 Kind::Counter,
+                 "Requests that timed out waiting for an ack or a ping-req",
+                 fd.timeouts as f64);
+        m.family("detector_open_requests",
+                 Kind::Gauge,
+                 "Requests the failure detector is waiting on, by status");
+        for &(ref status, name) in [(detector::Status::Running, "running"),
+                                    (detector::Status::AwaitingAck, "awaiting_ack"),
+                                    (detector::Status::PingReq, "pingreq"),
+                                    (detector::Status::Failed, "failed")]
+            .iter() {
+            let count = fd.open_requests.values().filter(|rs| rs.status == *status).count();
+            m.sample("detector_open_requests", &[("status", name)], count as f64);
+        }
+    }
+    {
+        let cl = census_list.read().unwrap();
+        let mut censuses: Vec<(String, &Census)> = cl.censuses()
+            .map(|census| (census.service_group(), census))
+            .collect();
+        censuses.sort_by(|a, b| a.0.cmp(&b.0));
+        m.family("census_population", Kind::Gauge, "Members of each service group");
+        for &(ref sg, census) in censuses.iter() {
+            m.sample("census_population",
+                     &[("service_group", &sg[..])],
+                     census.total_population() as f64);
+        }
+        m.family("census_alive", Kind::Gauge, "Alive members of each service group");
+        for &(ref sg, census) in censuses.iter() {
+            m.sample("census_alive",
+                     &[("service_group", &sg[..])],
+                     census.alive_population() as f64);
+        }
+        m.family("census_quorum",
+                 Kind::Gauge,
+                 "1 if more than half of each service group is alive");
+        for &(ref sg, census) in censuses.iter() {
+            m.sample("census_quorum",
+                     &[("service_group", &sg[..])],
+                     flag(census.alive_population() * 2 > census.total_population()));
+        }
+        m.family("census_has_leader",
+                 Kind::Gauge,
+                 "1 if each service group has an alive leader");
+        for &(ref sg, census) in censuses.iter() {
+            m.sample("census_has_leader",
+                     &[("service_group", &sg[..])],
+                     flag(census.has_leader()));
+        }
+    }
+    {
+        let el = election_list.read().unwrap();
+        let mut elections: Vec<(&String, &Election)> = el.elections.iter().collect();
+        elections.sort_by(|a, b| a.0.cmp(b.0));
+        m.family("election_running",
+                 Kind::Gauge,
+                 "1 if an election is running in each service group");
+        for &(sg, election) in elections.iter() {
+            m.sample("election_running",
+                     &[("service_group", &sg[..])],
+                     flag(election.status == ElectionStatus::Running));
+        }
+        m.family("election_term", Kind::Gauge, "The term of each service group's election");
+        for &(sg, election) in elections.iter() {
+            m.sample("election_term", &[("service_group", &sg[..])], election.term as f64);
+        }
+        m.family("election_votes",
+                 Kind::Gauge,
+                 "Votes for the leading candidate in each service group's election");
+        for &(sg, election) in elections.iter() {
+            m.sample("election_votes",
+                     &[("service_group", &sg[..])],
+                     election.votes.len() as f64);
+        }
+    }
+    let mut response = Response::with((status::Ok, m.as_str().to_string()));
+    response.headers.set_raw("Content-Type", vec![CONTENT_TYPE.as_bytes().to_vec()]);
+    Ok(response)
+}
+
+/// A duration in seconds, with millisecond precision
+fn seconds(duration: time::Duration) -> f64 {
+    duration.num_milliseconds() as f64 / 1000.0
+}
+
+/// The /control callbacks.
+///
+/// Authorizes the request, then hands it to the main loop. Returns `202 Accepted` once the main
+/// loop has the request; the main loop acts on it within a loop or two.
+///
+/// # Failures
+///
+/// * `401 Unauthorized` if the request has neither the control token nor a valid signed body
+///   from a trusted user that we haven't seen before
+/// * `503 Service Unavailable` if the main loop has gone away
+fn control(action: ControlRequest,
+           tx: &Arc<Mutex<Sender<ControlRequest>>>,
+           auth: &ControlAuth,
+           req: &mut Request)
+           -> IronResult<Response> {
+    let requester = match authorize(action, auth, req) {
+        Ok(requester) => requester,
+        Err(reason) => {
+            outputln!("Refusing control request to {}: {}", action, reason);
+            return Ok(Response::with((status::Unauthorized, format!("{}\n", reason))));
+        }
+    };
+    outputln!("Control request to {} from {}", action, requester);
+    match tx.lock().unwrap().send(action) {
+        Ok(_) => Ok(Response::with((status::Accepted, format!("{}\n", action)))),
+        Err(_) => {
+            Ok(Response::with((status::ServiceUnavailable, "The supervisor is shutting down\n")))
+        }
+    }
+}
+
+/// Check that a control request carries the control token or a signed body. Returns who made the
+/// request: the user key that signed it, or "the control token".
+fn authorize(action: ControlRequest,
+             auth: &ControlAuth,
+             req: &mut Request)
+             -> result::Result<String, String> {
+    if let Some(ref token) = auth.token {
+        let given = req.headers
+            .get_raw("Authorization")
+            .and_then(|values| values.first())
+            .and_then(|value| str::from_utf8(value).ok())
+            .and_then(|value| if value.starts_with("Bearer ") {
+                Some(value["Bearer ".len()..].trim().to_string())
+            } else {
+                None
+            });
+        if let Some(given) = given {
+            if token_matches(token, &given) {
+                return Ok(String::from("the control token"));
+            }
+            return Err(String::from("the control token does not match"));
+        }
+    }
+    let mut body = Vec::new();
+    if let Err(e) = req.body.read_to_end(&mut body) {
+        return Err(format!("can't read the request body: {}", e));
+    }
+    if body.is_empty() {
+        return Err(String::from("the request has neither a control token nor a signed body"));
+    }
+    if auth.trusted_users.is_empty() {
+        return Err(String::from("no users are trusted to sign control requests"));
+    }
+    let service_key = match auth.service_key {
+        Some(ref service_key) => service_key,
+        None => {
+            return Err(String::from("signed control requests need the service group's \
+                                     organization"))
+        }
+    };
+    let now = time::get_time().sec;
+    let request = try!(verify_signed(&body,
+                                     action,
+                                     service_key,
+                                     &default_cache_key_path(None),
+                                     now));
+    auth.accept(request, now)
+}
+
+/// Compare the control token to the one a request gave, taking the same time however many
+/// characters match, so the token can't be guessed one character at a time.
+fn token_matches(token: &str, given: &str) -> bool {
+    if token.len() != given.len() {
+        return false;
+    }
+    token.bytes().zip(given.bytes()).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+/// The names with revision of the sender and receiver keys in the header of a payload that
+/// `BoxKeyPair::encrypt` made: the lines after the format version.
+fn box_header(payload: &[u8]) -> result::Result<(String, String), String> {
+    let mut lines = match str::from_utf8(payload) {
+        Ok(payload) => payload.lines().skip(1),
+        Err(_) => return Err(String::from("the signed body is not text")),
+    };
+    match (lines.next(), lines.next()) {
+        (Some(sender), Some(receiver)) => Ok((sender.to_string(), receiver.to_string())),
+        _ => Err(String::from("the signed body names no sender and receiver")),
+    }
+}
+
+/// Decrypt a signed control request with the keys in our key cache, and check that it was
+/// encrypted to a revision of `service_key`, asks for `action`, was made within
+/// `CONTROL_REQUEST_MAX_AGE_SECS` of `now`, and has a nonce.
+fn verify_signed<P: AsRef<Path> + ?Sized>(payload: &[u8],
+                                          action: ControlRequest,
+                                          service_key: &str,
+                                          cache_key_path: &P,
+                                          now: i64)
+                                          -> result::Result<SignedRequest, String> {
+    let (sender, receiver) = try!(box_header(payload));
+    match parse_name_with_rev(&receiver) {
+        Ok((ref name, _)) if name == service_key => {}
+        _ => return Err(format!("the signed body is for {}, not {}", receiver, service_key)),
+    }
+    let plaintext = match BoxKeyPair::decrypt(payload, cache_key_path) {
+        Ok(plaintext) => plaintext,
+        Err(e) => return Err(format!("the signed body can't be verified: {}", e)),
+    };
+    let plaintext = String::from_utf8_lossy(&plaintext);
+    let mut lines = plaintext.lines();
+    match lines.next().map(|a| a.trim().parse::<ControlRequest>()) {
+        Some(Ok(ref signed)) if *signed == action => {}
+        _ => return Err(format!("the signed body does not ask to {}", action)),
+    }
+    let signed_at = match lines.next().and_then(|t| t.trim().parse::<i64>().ok()) {
+        Some(signed_at) => signed_at,
+        None => return Err(String::from("the signed body has no time")),
+    };
+    if (now - signed_at).abs() > CONTROL_REQUEST_MAX_AGE_SECS {
+        return Err(format!("the signed body is more than {} seconds old",
+                           CONTROL_REQUEST_MAX_AGE_SECS));
+    }
+    let nonce = match lines.next().map(|n| n.trim()) {
+        Some(nonce) if !nonce.is_empty() => nonce.to_string(),
+        _ => return Err(String::from("the signed body has no nonce")),
+    };
+    Ok(SignedRequest {
+        sender: sender,
+        signed_at: signed_at,
+        nonce: nonce,
+    })
+}
+
 /// Translates SupErrors into IronErrors
 impl From<SupError> for IronError {
     fn from(err: SupError) -> IronError {
@@ -341,3 +1211,143 @@ impl From<SupError> for IronError {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::crypto::BoxKeyPair;
+    use hcore::service::ServiceGroup;
+    use tempdir::TempDir;
+
+    use super::{token_matches, verify_signed, ControlAuth, ControlRequest, SignedRequest};
+
+    static SERVICE_KEY: &'static str = "redis.default@acme";
+
+    fn service_group() -> ServiceGroup {
+        ServiceGroup::from_str(SERVICE_KEY).unwrap()
+    }
+
+    fn verify(payload: &[u8],
+              action: ControlRequest,
+              cache: &TempDir,
+              now: i64)
+              -> Result<SignedRequest, String> {
+        verify_signed(payload, action, SERVICE_KEY, cache.path(), now)
+    }
+
+    fn signed(cache: &TempDir, plaintext: &str) -> Vec<u8> {
+        let user = BoxKeyPair::generate_pair_for_user("ops", cache.path()).unwrap();
+        let service = BoxKeyPair::generate_pair_for_service("acme", "redis.default", cache.path())
+            .unwrap();
+        user.encrypt(plaintext.as_bytes(), &service).unwrap()
+    }
+
+    #[test]
+    fn control_request_round_trip() {
+        for action in ControlRequest::all().iter() {
+            assert_eq!(action.to_string().parse::<ControlRequest>().unwrap(), *action);
+        }
+        assert!("explode".parse::<ControlRequest>().is_err());
+    }
+
+    #[test]
+    fn token_must_match_exactly() {
+        assert!(token_matches("sekrit", "sekrit"));
+        assert!(!token_matches("sekrit", "sekriT"));
+        assert!(!token_matches("sekrit", "sekrit2"));
+        assert!(!token_matches("sekrit", ""));
+    }
+
+    fn request(sender: &str, signed_at: i64, nonce: &str) -> SignedRequest {
+        SignedRequest {
+            sender: sender.to_string(),
+            signed_at: signed_at,
+            nonce: nonce.to_string(),
+        }
+    }
+
+    #[test]
+    fn verify_signed_request() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let payload = signed(&cache, "restart\n1000\nabc123");
+        let request = verify(&payload, ControlRequest::Restart, &cache, 1010).unwrap();
+        assert!(request.sender.starts_with("ops-"));
+        assert_eq!(request.signed_at, 1000);
+        assert_eq!(request.nonce, "abc123");
+    }
+
+    #[test]
+    fn verify_signed_refuses_other_service_groups() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let payload = signed(&cache, "restart\n1000\nabc123");
+        BoxKeyPair::generate_pair_for_service("acme", "nginx.default", cache.path()).unwrap();
+        assert!(verify_signed(&payload,
+                              ControlRequest::Restart,
+                              "nginx.default@acme",
+                              cache.path(),
+                              1000)
+            .is_err());
+    }
+
+    #[test]
+    fn verify_signed_refuses_other_actions() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let payload = signed(&cache, "restart\n1000\nabc123");
+        assert!(verify(&payload, ControlRequest::Stop, &cache, 1000).is_err());
+    }
+
+    #[test]
+    fn verify_signed_refuses_old_requests() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let payload = signed(&cache, "stop\n1000\nabc123");
+        assert!(verify(&payload, ControlRequest::Stop, &cache, 2000).is_err());
+    }
+
+    #[test]
+    fn verify_signed_needs_a_nonce() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let payload = signed(&cache, "stop\n1000");
+        assert!(verify(&payload, ControlRequest::Stop, &cache, 1000).is_err());
+    }
+
+    #[test]
+    fn accept_only_trusted_users() {
+        let auth = ControlAuth::new(None, vec!["ops".to_string()], &service_group());
+        assert_eq!(auth.accept(request("ops-20160101000000", 1000, "a"), 1000),
+                   Ok("ops-20160101000000".to_string()));
+        assert!(auth.accept(request("dev-20160101000000", 1000, "b"), 1000).is_err());
+        assert!(auth.accept(request("garbage", 1000, "c"), 1000).is_err());
+    }
+
+    #[test]
+    fn control_auth_needs_an_organization_for_signed_requests() {
+        let auth = ControlAuth::new(None, vec!["ops".to_string()], &service_group());
+        assert_eq!(auth.service_key, Some(SERVICE_KEY.to_string()));
+        let no_org = ServiceGroup::from_str("redis.default").unwrap();
+        let auth = ControlAuth::new(None, vec!["ops".to_string()], &no_org);
+        assert_eq!(auth.service_key, None);
+    }
+
+    #[test]
+    fn accept_refuses_replays() {
+        let auth = ControlAuth::new(None, vec!["ops".to_string()], &service_group());
+        assert!(auth.accept(request("ops-20160101000000", 1000, "a"), 1000).is_ok());
+        assert!(auth.accept(request("ops-20160101000000", 1000, "a"), 1100).is_err());
+        assert!(auth.accept(request("ops-20160101000000", 1000, "b"), 1100).is_ok());
+    }
+
+    #[test]
+    fn accept_forgets_nonces_too_old_to_replay() {
+        let auth = ControlAuth::new(None, vec!["ops".to_string()], &service_group());
+        assert!(auth.accept(request("ops-20160101000000", 1000, "a"), 1000).is_ok());
+        assert!(auth.accept(request("ops-20160101000000", 2000, "b"), 2000).is_ok());
+        assert!(!auth.nonces.lock().unwrap().contains_key("a"));
+    }
+
+    #[test]
+    fn verify_signed_refuses_garbage() {
+        let cache = TempDir::new("key_cache").unwrap();
+        assert!(verify(b"not signed", ControlRequest::Stop, &cache, 1000).is_err());
+    }
+}
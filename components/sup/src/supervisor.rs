@@ -9,26 +9,39 @@
 ///
 /// The supervisor is responsible for running any services we are asked to start. It handles
 /// spawning the new process, watching for failure, and ensuring the service is either up or down.
-/// If the process dies, the supervisor will restart it.
+/// If the process dies, the supervisor will restart it according to its restart policy, backing
+/// off exponentially if the service keeps crashing.
 
 use std::fmt;
 use std::fs::{self, File};
-use std::io::BufReader;
+use std::io::{self, BufReader};
 use std::io::prelude::*;
+use std::os::unix::process::CommandExt;
 use std::path::PathBuf;
-use std::process::{Command, Stdio, Child};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::Duration as StdDuration;
 
 use hcore;
 use hcore::package::PackageIdent;
-use libc::{pid_t, c_int};
+use libc::{self, pid_t, c_int};
 use time::{Duration, SteadyTime};
 
+use config::{Config, RestartPolicy};
 use error::{Result, Error};
-use util::signals;
+use limits::{Cgroup, Limits, Usage};
+use output::StructuredOutput;
+use package::Package;
+use package::hooks::Hook;
+use service_config::ServiceConfig;
+use service_log::{ServiceLog, Stream};
+use util::{reaper, signals, users};
 
 const PIDFILE_NAME: &'static str = "PID";
 static LOGKEY: &'static str = "SV";
+/// How long `stop` waits between checks on whether the service has exited
+const STOP_POLL_MS: u64 = 100;
 
 // Functions from POSIX libc.
 extern "C" {
@@ -111,6 +124,99 @@ impl fmt::Display for ProcessState {
     }
 }
 
+/// What to do about a service that exited on its own.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RestartPlan {
+    /// Restart it once the backoff has passed
+    After(Duration),
+    /// Leave it down
+    GiveUp(String),
+}
+
+/// Tracks the restarts of a service, and applies the restart policy and backoff.
+#[derive(Debug)]
+pub struct Restarts {
+    policy: RestartPolicy,
+    backoff_max: Duration,
+    max: u32,
+    window: Duration,
+    recent: Vec<SteadyTime>,
+    /// How many times we have restarted the service since the supervisor started
+    pub count: u64,
+    /// The backoff we are currently waiting out, if any
+    pub backoff: Option<Duration>,
+    /// When we will next try to start the service
+    pub next_attempt: Option<SteadyTime>,
+}
+
+impl Restarts {
+    pub fn new(policy: RestartPolicy, backoff_max_secs: u64, max: u32, window_secs: u64) -> Self {
+        Restarts {
+            policy: policy,
+            backoff_max: Duration::seconds(backoff_max_secs as i64),
+            max: max,
+            window: Duration::seconds(window_secs as i64),
+            recent: Vec::new(),
+            count: 0,
+            backoff: None,
+            next_attempt: None,
+        }
+    }
+
+    /// Decide what to do about a service that exited at `now`. `failed` is true if it exited
+    /// with a non-zero code or was killed by a signal.
+    ///
+    /// The backoff starts at one second, and doubles for every restart within the window, up to
+    /// the maximum.
+    pub fn plan(&mut self, failed: bool, now: SteadyTime) -> RestartPlan {
+        match self.policy {
+            RestartPolicy::Never => {
+                return RestartPlan::GiveUp(String::from("restart policy is never"));
+            }
+            RestartPolicy::OnFailure if !failed => {
+                return RestartPlan::GiveUp(String::from("service exited cleanly and restart \
+                                                         policy is on-failure"));
+            }
+            _ => {}
+        }
+        let window = self.window;
+        self.recent.retain(|t| now - *t < window);
+        if self.max > 0 && self.recent.len() as u32 >= self.max {
+            return RestartPlan::GiveUp(format!("restarted {} times in {} seconds",
+                                               self.recent.len(),
+                                               self.window.num_seconds()));
+        }
+        let shift = if self.recent.len() > 30 {
+            30
+        } else {
+            self.recent.len()
+        };
+        let mut backoff = Duration::seconds(1 << shift);
+        if backoff > self.backoff_max {
+            backoff = self.backoff_max;
+        }
+        self.recent.push(now);
+        self.count += 1;
+        self.backoff = Some(backoff);
+        self.next_attempt = Some(now + backoff);
+        RestartPlan::After(backoff)
+    }
+
+    /// Returns true if we are waiting out a backoff at `now`.
+    pub fn waiting(&self, now: SteadyTime) -> bool {
+        match self.next_attempt {
+            Some(at) => now < at,
+            None => false,
+        }
+    }
+
+    /// The backoff has passed, and we are starting the service again.
+    pub fn attempted(&mut self) {
+        self.backoff = None;
+        self.next_attempt = None;
+    }
+}
+
 #[derive(Debug)]
 pub struct Supervisor {
     pub pid: Option<Pid>,
@@ -118,29 +224,89 @@ pub struct Supervisor {
     pub state: ProcessState,
     pub state_entered: SteadyTime,
     pub has_started: bool,
+    /// How many times the service has exited without us asking it to
+    pub exit_count: u64,
+    /// True if the service last exited with a non-zero code, or was killed by a signal
+    pub last_exit_failed: bool,
+    pub restarts: Restarts,
+    /// The signal we send the service to ask it to stop
+    pub shutdown_signal: signals::Signal,
+    /// How long we wait for the service to stop before we kill it
+    pub shutdown_timeout: Duration,
+    /// Run after the service exits, so it can clean up
+    pub post_stop: Option<Hook>,
+    /// The user and group we run the service as; `None` runs it as ourselves
+    pub run_as: Option<(String, String)>,
+    /// The resource limits we apply to the service
+    pub limits: Limits,
+    /// The resource limits given on the command line, which win over the service's configuration
+    pub limit_flags: Limits,
+    /// The cgroup the service runs in, once we have put it in one
+    pub cgroup: Option<Cgroup>,
+    /// The process group the service leads, once it has started
+    pub pgid: Option<Pid>,
+    /// Where we write the service's output, shared with the threads that read it
+    pub log: Arc<Mutex<ServiceLog>>,
 }
 
 impl Supervisor {
-    pub fn new(package_ident: PackageIdent) -> Supervisor {
+    pub fn new(package_ident: PackageIdent, config: &Config) -> Supervisor {
+        let log = ServiceLog::new(hcore::fs::svc_var_path(&package_ident.name).join("logs"),
+                                  config.log_max_bytes(),
+                                  config.log_max_files());
         Supervisor {
             pid: None,
             package_ident: package_ident,
             state: ProcessState::Down,
             state_entered: SteadyTime::now(),
             has_started: false,
+            exit_count: 0,
+            last_exit_failed: false,
+            restarts: Restarts::new(config.restart_policy(),
+                                    config.restart_backoff_max(),
+                                    config.restart_max(),
+                                    config.restart_window()),
+            shutdown_signal: signals::Signal::SIGTERM,
+            shutdown_timeout: Duration::seconds(8),
+            post_stop: None,
+            run_as: None,
+            limits: config.limits().clone(),
+            limit_flags: config.limits().clone(),
+            cgroup: None,
+            pgid: None,
+            log: Arc::new(Mutex::new(log)),
         }
     }
 
+    /// Pick up the shutdown settings, post_stop hook and service user of the package we are
+    /// running. The service's configuration can override the package's shutdown settings, and
+    /// sets any limits that weren't given on the command line; new limits apply the next time
+    /// the service starts.
+    pub fn configure(&mut self, package: &Package, service_config: &ServiceConfig) {
+        self.limits = self.limit_flags.or(&service_config.limits());
+        self.shutdown_signal = service_config.shutdown_signal()
+            .unwrap_or_else(|| package.shutdown_signal());
+        let timeout = service_config.shutdown_timeout()
+            .unwrap_or_else(|| package.shutdown_timeout());
+        self.shutdown_timeout = Duration::seconds(timeout as i64);
+        self.post_stop = package.hooks().post_stop_hook;
+        self.run_as = Some((package.svc_user(), package.svc_group()));
+    }
+
     fn enter_state(&mut self, state: ProcessState) {
         self.state = state;
         self.state_entered = SteadyTime::now();
     }
 
     pub fn status(&self) -> (bool, String) {
-        let status = format!("{}: {} for {}",
-                             self.package_ident,
-                             self.state,
-                             SteadyTime::now() - self.state_entered);
+        let mut status = format!("{}: {} for {}, {} restarts",
+                                 self.package_ident,
+                                 self.state,
+                                 SteadyTime::now() - self.state_entered,
+                                 self.restarts.count);
+        if let Some(at) = self.restarts.next_attempt {
+            status.push_str(&format!(", restarting in {}", at - SteadyTime::now()));
+        }
         let healthy = match self.state {
             ProcessState::Up | ProcessState::Start | ProcessState::Restart => true,
             ProcessState::Down => false,
@@ -148,21 +314,55 @@ impl Supervisor {
         (healthy, status)
     }
 
+    /// The resources the service is using, if it is running.
+    pub fn usage(&self) -> Option<Usage> {
+        self.pid.map(|pid| Usage::read(pid, self.cgroup.as_ref()))
+    }
+
     pub fn start(&mut self) -> Result<()> {
         if self.pid.is_none() {
             outputln!(preamble & self.package_ident.name, "Starting");
             self.enter_state(ProcessState::Start);
-            let mut child = try!(Command::new(self.run_cmd())
-                .stdin(Stdio::null())
+            if let Some(pgid) = self.pgid.take() {
+                if signals::process_group_alive(pgid) {
+                    outputln!(preamble & self.package_ident.name,
+                              "Killing processes left over from the last run");
+                    let _ = signals::send_signal_to_pgid(pgid, signals::Signal::SIGKILL);
+                }
+            }
+            let mut cmd = Command::new(self.run_cmd());
+            cmd.stdin(Stdio::null())
                 .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn());
+                .stderr(Stdio::piped());
+            // The service starts a session of its own, so it leads a process group we can signal
+            // along with everything it forks. It then sets its own limits before it execs, and
+            // only then drops root, since raising limits and joining a cgroup need it.
+            let ids = match self.run_as {
+                Some((ref user, ref group)) => try!(users::lookup(user, group)),
+                None => None,
+            };
+            let (cgroup, child_limits) = self.limits.prepare(&self.package_ident.name);
+            self.cgroup = cgroup;
+            cmd.before_exec(move || {
+                if unsafe { libc::setsid() } == -1 {
+                    return Err(io::Error::last_os_error());
+                }
+                try!(child_limits.apply());
+                match ids {
+                    Some((uid, gid)) => users::switch_to(uid, gid),
+                    None => Ok(()),
+                }
+            });
+            let mut child = try!(cmd.spawn());
             self.pid = Some(child.id());
+            self.pgid = Some(child.id());
             try!(self.create_pidfile());
-            let package_name = self.package_ident.name.clone();
-            try!(thread::Builder::new()
-                .name(String::from("sup-service-read"))
-                .spawn(move || -> Result<()> { child_reader(&mut child, package_name) }));
+            if let Some(stdout) = child.stdout.take() {
+                try!(self.spawn_reader(stdout, Stream::Stdout));
+            }
+            if let Some(stderr) = child.stderr.take() {
+                try!(self.spawn_reader(stderr, Stream::Stderr));
+            }
             self.enter_state(ProcessState::Up);
             self.has_started = true;
         } else {
@@ -171,12 +371,27 @@ impl Supervisor {
         Ok(())
     }
 
-    /// Send a SIGTERM to a process, wait 8 seconds, then send SIGKILL
+    /// Read one of the service's output streams on a thread of its own, until it closes.
+    fn spawn_reader<R: Read + Send + 'static>(&self, output: R, stream: Stream) -> Result<()> {
+        let package_name = self.package_ident.name.clone();
+        let ident = self.package_ident.to_string();
+        let log = self.log.clone();
+        try!(thread::Builder::new()
+            .name(format!("sup-service-{}", stream))
+            .spawn(move || child_reader(output, stream, package_name, ident, log)));
+        Ok(())
+    }
+
+    /// Send the shutdown signal to the service, wait for the shutdown timeout, then send SIGKILL.
+    /// The service's whole process group is signalled, and we wait for every process in it to
+    /// exit.
     pub fn stop(&mut self) -> Result<()> {
         let wait = match self.pid {
-            Some(ref pid) => {
-                outputln!(preamble & self.package_ident.name, "Stopping");
-                try!(signals::send_signal_to_pid(*pid, signals::Signal::SIGTERM));
+            Some(_) => {
+                outputln!(preamble & self.package_ident.name,
+                          "Stopping with {:?}",
+                          self.shutdown_signal);
+                try!(self.signal_service(self.shutdown_signal.clone()));
                 true
             }
             None => {
@@ -185,22 +400,25 @@ impl Supervisor {
             }
         };
         if wait {
-            let stop_time = SteadyTime::now() + Duration::seconds(8);
+            let stop_time = SteadyTime::now() + self.shutdown_timeout;
             loop {
                 try!(self.check_process());
-                if SteadyTime::now() > stop_time {
-                    outputln!(preamble & self.package_ident.name,
-                              "Process failed to stop with SIGTERM; sending SIGKILL");
-                    if let Some(pid) = self.pid {
-                        try!(signals::send_signal_to_pid(pid, signals::Signal::SIGKILL));
-                    }
+                if reaper::am_i_pid1() {
+                    reaper::reap_orphans(self.pid);
+                }
+                let group_alive = self.pgid.map_or(false, signals::process_group_alive);
+                if self.pid.is_none() && !group_alive {
                     break;
                 }
-                if self.pid.is_none() {
+                if SteadyTime::now() > stop_time {
+                    outputln!(preamble & self.package_ident.name,
+                              "Process failed to stop with {:?} after {} seconds; sending SIGKILL",
+                              self.shutdown_signal,
+                              self.shutdown_timeout.num_seconds());
+                    try!(self.signal_service(signals::Signal::SIGKILL));
                     break;
-                } else {
-                    continue;
                 }
+                thread::sleep(StdDuration::from_millis(STOP_POLL_MS));
             }
         }
         Ok(())
@@ -214,6 +432,40 @@ impl Supervisor {
         }
     }
 
+    /// Keep a service that should be up running. The first start happens right away; after
+    /// that, the restart policy decides whether and when we start it again.
+    pub fn keep_up(&mut self) -> Result<()> {
+        if self.pid.is_some() {
+            return Ok(());
+        }
+        if !self.has_started {
+            return self.start();
+        }
+        let now = SteadyTime::now();
+        if self.restarts.next_attempt.is_none() {
+            match self.restarts.plan(self.last_exit_failed, now) {
+                RestartPlan::After(backoff) => {
+                    outputln!(preamble & self.package_ident.name,
+                              "Restarting in {} seconds",
+                              backoff.num_seconds());
+                }
+                RestartPlan::GiveUp(reason) => {
+                    outputln!(preamble & self.package_ident.name,
+                              "Not restarting: {}",
+                              reason);
+                    self.enter_state(ProcessState::Down);
+                    self.cleanup_pidfile();
+                    return Ok(());
+                }
+            }
+        }
+        if self.restarts.waiting(now) {
+            return Ok(());
+        }
+        self.restarts.attempted();
+        self.start()
+    }
+
     pub fn is_down(&self) -> bool {
         if let ProcessState::Down = self.state {
             true
@@ -236,6 +488,19 @@ impl Supervisor {
         Ok(())
     }
 
+    /// Signal the service's whole process group, so the processes it forked get the signal too.
+    fn signal_service(&self, sig: signals::Signal) -> Result<()> {
+        if let Some(pgid) = self.pgid {
+            if signals::send_signal_to_pgid(pgid, sig.clone()).is_ok() {
+                return Ok(());
+            }
+        }
+        match self.pid {
+            Some(pid) => signals::send_signal_to_pid(pid, sig),
+            None => Ok(()),
+        }
+    }
+
     /// Pass through a Unix signal to a process
     pub fn send_unix_signal(&self, sig: signals::Signal) -> Result<()> {
         if let Some(pid) = self.pid {
@@ -258,6 +523,7 @@ impl Supervisor {
             match waitpid(cpid, &mut status, 1 as c_int) {
                 0 => {} // Nothing returned,
                 pid if pid == cpid => {
+                    self.last_exit_failed = !(WIFEXITED(status) && WEXITSTATUS(status) == 0);
                     if WIFEXITED(status) {
                         let exit_code = WEXITSTATUS(status);
                         outputln!("{} - process {} died with exit code {}",
@@ -276,7 +542,12 @@ impl Supervisor {
                                   pid);
                     }
                     match self.state {
-                        ProcessState::Up | ProcessState::Start | ProcessState::Restart => {
+                        ProcessState::Up => {
+                            outputln!("{} - Service exited", self.package_ident.name);
+                            self.exit_count += 1;
+                            self.pid = None;
+                        }
+                        ProcessState::Start | ProcessState::Restart => {
                             outputln!("{} - Service exited", self.package_ident.name);
                             self.pid = None;
                         }
@@ -285,6 +556,7 @@ impl Supervisor {
                             self.pid = None;
                         }
                     }
+                    self.run_post_stop();
                 }
                 // ZOMBIES! Bad zombies! We listen for zombies. ZOMBOCOM!
                 pid => {
@@ -303,6 +575,16 @@ impl Supervisor {
         Ok(())
     }
 
+    /// Run the post_stop hook, if the package has one. A failing hook is reported, but doesn't
+    /// stop us from supervising the service.
+    fn run_post_stop(&self) {
+        if let Some(ref hook) = self.post_stop {
+            if let Err(e) = hook.exec() {
+                outputln!(preamble & self.package_ident.name, "post_stop hook failed: {}", e);
+            }
+        }
+    }
+
     pub fn run_cmd(&self) -> PathBuf {
         self.service_dir().join("run")
     }
@@ -376,22 +658,116 @@ impl Drop for Supervisor {
     }
 }
 
-/// Consume output from a child process until EOF, then finish
-fn child_reader(child: &mut Child, package_name: String) -> Result<()> {
-    let c_stdout = match child.stdout {
-        Some(ref mut s) => s,
-        None => return Err(sup_error!(Error::UnpackFailed)),
+/// Consume output from a child process until EOF, then finish. Each line is printed, tagged
+/// with the stream it came from and the package ident, and written to the service's log.
+fn child_reader<R: Read>(output: R,
+                         stream: Stream,
+                         package_name: String,
+                         ident: String,
+                         log: Arc<Mutex<ServiceLog>>) {
+    let logkey = match stream {
+        Stream::Stdout => "O",
+        Stream::Stderr => "E",
     };
-
-    let mut reader = BufReader::new(c_stdout);
-    let mut buffer = String::new();
-
-    while reader.read_line(&mut buffer).unwrap() > 0 {
-        let mut line = output_format!(preamble &package_name, logkey "O");
-        line.push_str(&buffer);
-        print!("{}", line);
+    let stream_name = stream.to_string();
+    let mut reader = BufReader::new(output);
+    let mut buffer = Vec::new();
+    loop {
         buffer.clear();
+        match reader.read_until(b'\n', &mut buffer) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(e) => {
+                debug!("Failed to read the service's {}: {}", stream, e);
+                break;
+            }
+        }
+        let line = String::from_utf8_lossy(&buffer);
+        let content = line.trim_right_matches(|c| c == '\n' || c == '\r');
+        let mut so = StructuredOutput::new(&package_name,
+                                           logkey,
+                                           line!(),
+                                           file!(),
+                                           column!(),
+                                           content);
+        so.ident = Some(&ident);
+        so.stream = Some(&stream_name);
+        println!("{}", so);
+        if let Err(e) = log.lock().unwrap().write_line(stream, &ident, content) {
+            debug!("Failed to write the service's {} to its log: {}", stream, e);
+        }
     }
     debug!("child_reader exiting");
-    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use time::{Duration, SteadyTime};
+
+    use config::RestartPolicy;
+    use super::{RestartPlan, Restarts};
+
+    #[test]
+    fn backoff_doubles_up_to_max() {
+        let mut restarts = Restarts::new(RestartPolicy::Always, 5, 0, 300);
+        let now = SteadyTime::now();
+        assert_eq!(restarts.plan(true, now), RestartPlan::After(Duration::seconds(1)));
+        assert_eq!(restarts.plan(true, now), RestartPlan::After(Duration::seconds(2)));
+        assert_eq!(restarts.plan(true, now), RestartPlan::After(Duration::seconds(4)));
+        assert_eq!(restarts.plan(true, now), RestartPlan::After(Duration::seconds(5)));
+        assert_eq!(restarts.count, 4);
+    }
+
+    #[test]
+    fn backoff_resets_after_window() {
+        let mut restarts = Restarts::new(RestartPolicy::Always, 60, 0, 300);
+        let now = SteadyTime::now();
+        restarts.plan(true, now);
+        restarts.plan(true, now);
+        let later = now + Duration::seconds(301);
+        assert_eq!(restarts.plan(true, later), RestartPlan::After(Duration::seconds(1)));
+    }
+
+    #[test]
+    fn gives_up_after_max_restarts_in_window() {
+        let mut restarts = Restarts::new(RestartPolicy::Always, 60, 2, 300);
+        let now = SteadyTime::now();
+        restarts.plan(true, now);
+        restarts.plan(true, now);
+        match restarts.plan(true, now) {
+            RestartPlan::GiveUp(_) => {}
+            plan => panic!("Expected to give up, got {:?}", plan),
+        }
+    }
+
+    #[test]
+    fn on_failure_only_restarts_failures() {
+        let mut restarts = Restarts::new(RestartPolicy::OnFailure, 60, 0, 300);
+        let now = SteadyTime::now();
+        assert_eq!(restarts.plan(true, now), RestartPlan::After(Duration::seconds(1)));
+        match restarts.plan(false, now) {
+            RestartPlan::GiveUp(_) => {}
+            plan => panic!("Expected to give up, got {:?}", plan),
+        }
+    }
+
+    #[test]
+    fn never_policy_gives_up() {
+        let mut restarts = Restarts::new(RestartPolicy::Never, 60, 0, 300);
+        match restarts.plan(true, SteadyTime::now()) {
+            RestartPlan::GiveUp(_) => {}
+            plan => panic!("Expected to give up, got {:?}", plan),
+        }
+    }
+
+    #[test]
+    fn waiting_until_next_attempt() {
+        let mut restarts = Restarts::new(RestartPolicy::Always, 60, 0, 300);
+        let now = SteadyTime::now();
+        restarts.plan(true, now);
+        assert!(restarts.waiting(now));
+        assert!(!restarts.waiting(now + Duration::seconds(2)));
+        restarts.attempted();
+        assert!(restarts.next_attempt.is_none());
+    }
 }
@@ -0,0 +1,413 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! The Handlebars registry configuration files and hooks are rendered with, and the helpers
+//! templates can use on top of the built in ones:
+//!
+//! * `{{toJson cfg.database}}`, `{{toToml cfg.database}}`, `{{toYaml cfg.database}}`: a subtree
+//!   of the data as JSON, TOML or YAML
+//! * `{{toUppercase cfg.name}}`, `{{toLowercase cfg.name}}`: a string in upper or lower case
+//! * `{{strReplace cfg.name "-" "_"}}`: a string with every match of one string replaced by
+//!   another
+//! * `{{join cfg.hosts ","}}`: the items of an array, separated by a string
+//! * `{{default cfg.port 6379}}`: a value, or another if it is missing, null or empty
+//! * `{{#gt cfg.workers 4}}...{{else}}...{{/gt}}`, and `gte`, `lt`, `lte`, `eq` and `ne`: render
+//!   the block if the comparison holds for two numbers, and the inverse if it doesn't. Strings
+//!   that hold numbers are compared as numbers
+//! * `{{#eachAlive bind.database.members}}{{ip}}{{/eachAlive}}`: render the block for every
+//!   alive member in a list of members, with the member as the context
+
+use std::ascii::AsciiExt;
+use std::io::Write;
+use std::result;
+
+use handlebars::{Context, Handlebars, Helper, JsonRender, RenderContext, RenderError, Renderable};
+use rustc_serialize::json::{self, Json};
+
+use util::convert;
+
+type HelperResult = result::Result<(), RenderError>;
+
+/// Handlebars escapes HTML by default. We don't want that.
+pub fn never_escape_fn(data: &str) -> String {
+    String::from(data)
+}
+
+/// A Handlebars registry with our escape function and helpers registered.
+pub fn handlebars() -> Handlebars {
+    let mut handlebars = Handlebars::new();
+    handlebars.register_escape_fn(never_escape_fn);
+    handlebars.register_helper("toJson", Box::new(to_json));
+    handlebars.register_helper("toToml", Box::new(to_toml));
+    handlebars.register_helper("toYaml", Box::new(to_yaml));
+    handlebars.register_helper("toUppercase", Box::new(to_uppercase));
+    handlebars.register_helper("toLowercase", Box::new(to_lowercase));
+    handlebars.register_helper("strReplace", Box::new(str_replace));
+    handlebars.register_helper("join", Box::new(join));
+    handlebars.register_helper("default", Box::new(default));
+    handlebars.register_helper("eq", Box::new(eq));
+    handlebars.register_helper("ne", Box::new(ne));
+    handlebars.register_helper("gt", Box::new(gt));
+    handlebars.register_helper("gte", Box::new(gte));
+    handlebars.register_helper("lt", Box::new(lt));
+    handlebars.register_helper("lte", Box::new(lte));
+    handlebars.register_helper("eachAlive", Box::new(each_alive));
+    handlebars
+}
+
+fn param<'a>(h: &'a Helper, idx: usize) -> result::Result<&'a Json, RenderError> {
+    match h.param(idx) {
+        Some(param) => Ok(param.value()),
+        None => {
+            Err(RenderError::new(&format!("Param {} not found for helper \"{}\"",
+                                          idx,
+                                          h.name())))
+        }
+    }
+}
+
+fn write(rc: &mut RenderContext, output: &str) -> HelperResult {
+    try!(rc.writer.write_all(output.as_bytes()));
+    Ok(())
+}
+
+fn to_json(_: &Context, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    let value = try!(param(h, 0));
+    write(rc, &format!("{}", json::as_pretty_json(value)))
+}
+
+fn to_toml(_: &Context, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    let value = try!(param(h, 0));
+    match convert::json_to_toml(value) {
+        Some(toml) => write(rc, &format!("{}", toml)),
+        None => Ok(()),
+    }
+}
+
+fn to_yaml(_: &Context, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    let value = try!(param(h, 0));
+    write(rc, &yaml(value))
+}
+
+fn to_uppercase(_: &Context, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    let value = try!(param(h, 0));
+    write(rc, &value.render().to_uppercase())
+}
+
+fn to_lowercase(_: &Context, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    let value = try!(param(h, 0));
+    write(rc, &value.render().to_lowercase())
+}
+
+fn str_replace(_: &Context, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    let value = try!(param(h, 0)).render();
+    let from = try!(param(h, 1)).render();
+    let to = try!(param(h, 2)).render();
+    write(rc, &value.replace(&from, &to))
+}
+
+fn join(_: &Context, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    let value = try!(param(h, 0));
+    let separator = try!(param(h, 1)).render();
+    let output = match *value {
+        Json::Array(ref items) => {
+            items.iter().map(|item| item.render()).collect::<Vec<String>>().join(&separator)
+        }
+        Json::Null => String::new(),
+        ref other => other.render(),
+    };
+    write(rc, &output)
+}
+
+fn default(_: &Context, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    let value = try!(param(h, 0));
+    let value = match *value {
+        Json::Null => try!(param(h, 1)),
+        Json::String(ref s) if s.is_empty() => try!(param(h, 1)),
+        _ => value,
+    };
+    write(rc, &value.render())
+}
+
+/// A number, or a string that holds one
+fn number(value: &Json) -> Option<f64> {
+    match *value {
+        Json::I64(i) => Some(i as f64),
+        Json::U64(u) => Some(u as f64),
+        Json::F64(f) => Some(f),
+        Json::String(ref s) => s.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+}
+
+/// Render the block if `test` holds for the first two params, and the inverse if it doesn't.
+fn compare<F>(c: &Context,
+              h: &Helper,
+              r: &Handlebars,
+              rc: &mut RenderContext,
+              test: F)
+              -> HelperResult
+    where F: Fn(f64, f64) -> bool
+{
+    let left = try!(param(h, 0));
+    let right = try!(param(h, 1));
+    let holds = match (number(left), number(right)) {
+        (Some(left), Some(right)) => test(left, right),
+        _ => {
+            return Err(RenderError::new(&format!("Helper \"{}\" compares numbers, but got {} \
+                                                  and {}",
+                                                 h.name(),
+                                                 left,
+                                                 right)))
+        }
+    };
+    let template = if holds { h.template() } else { h.inverse() };
+    match template {
+        Some(t) => t.render(c, r, rc),
+        None => Ok(()),
+    }
+}
+
+fn eq(c: &Context, h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    compare(c, h, r, rc, |left, right| left == right)
+}
+
+fn ne(c: &Context, h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    compare(c, h, r, rc, |left, right| left != right)
+}
+
+fn gt(c: &Context, h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    compare(c, h, r, rc, |left, right| left > right)
+}
+
+fn gte(c: &Context, h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    compare(c, h, r, rc, |left, right| left >= right)
+}
+
+fn lt(c: &Context, h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    compare(c, h, r, rc, |left, right| left < right)
+}
+
+fn lte(c: &Context, h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    compare(c, h, r, rc, |left, right| left <= right)
+}
+
+fn each_alive(_: &Context, h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> HelperResult {
+    let members = match *try!(param(h, 0)) {
+        Json::Array(ref members) => members,
+        Json::Null => return Ok(()),
+        ref other => {
+            return Err(RenderError::new(&format!("Helper \"eachAlive\" needs a list of \
+                                                  members, but got {}",
+                                                 other)))
+        }
+    };
+    let template = match h.template() {
+        Some(t) => t,
+        None => return Ok(()),
+    };
+    for member in members.iter() {
+        let alive = member.find("alive").and_then(|alive| alive.as_boolean()).unwrap_or(false);
+        if !alive {
+            continue;
+        }
+        let context = Context::wraps(member);
+        let mut local_rc = rc.derive();
+        local_rc.set_path("this".to_string());
+        try!(template.render(&context, r, &mut local_rc));
+    }
+    Ok(())
+}
+
+/// Render JSON as a YAML document.
+fn yaml(value: &Json) -> String {
+    let mut out = String::new();
+    match *value {
+        Json::Object(ref o) if !o.is_empty() => yaml_object(value, 0, &mut out),
+        Json::Array(ref a) if !a.is_empty() => yaml_array(a, 0, &mut out),
+        _ => {
+            out.push_str(&yaml_scalar(value));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn yaml_object(value: &Json, indent: usize, out: &mut String) {
+    if let Json::Object(ref o) = *value {
+        for (key, value) in o.iter() {
+            push_indent(indent, out);
+            out.push_str(&yaml_key(key));
+            out.push(':');
+            yaml_value(value, indent, out);
+        }
+    }
+}
+
+fn yaml_array(items: &[Json], indent: usize, out: &mut String) {
+    for item in items.iter() {
+        push_indent(indent, out);
+        out.push('-');
+        yaml_value(item, indent, out);
+    }
+}
+
+/// Write a value after its key or list marker; collections go on the lines below, indented.
+fn yaml_value(value: &Json, indent: usize, out: &mut String) {
+    match *value {
+        Json::Object(ref o) if !o.is_empty() => {
+            out.push('\n');
+            yaml_object(value, indent + 2, out);
+        }
+        Json::Array(ref a) if !a.is_empty() => {
+            out.push('\n');
+            yaml_array(a, indent + 2, out);
+        }
+        _ => {
+            out.push(' ');
+            out.push_str(&yaml_scalar(value));
+            out.push('\n');
+        }
+    }
+}
+
+/// Strings are written as JSON strings, which are valid YAML double quoted scalars.
+fn yaml_scalar(value: &Json) -> String {
+    match *value {
+        Json::Object(_) => String::from("{}"),
+        Json::Array(_) => String::from("[]"),
+        Json::Null => String::from("null"),
+        ref other => other.to_string(),
+    }
+}
+
+fn yaml_key(key: &str) -> String {
+    if !key.is_empty() &&
+       key.chars().all(|c| (c.is_alphanumeric() && c.is_ascii()) || c == '_' || c == '-') {
+        key.to_string()
+    } else {
+        Json::String(key.to_string()).to_string()
+    }
+}
+
+fn push_indent(indent: usize, out: &mut String) {
+    for _ in 0..indent {
+        out.push(' ');
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rustc_serialize::json::Json;
+
+    use census::{Census, CensusEntry, CensusList};
+    use config::Config;
+    use gossip::member::MemberId;
+    use package::test::gen_pkg;
+    use service_config::ServiceConfig;
+    use util::convert;
+
+    use super::{handlebars, yaml};
+
+    /// The data a template for a service bound to a three member `db.default` sees, one of
+    /// them suspect, with some cfg to work with.
+    fn data() -> Json {
+        let mut leader = CensusEntry::new("db", "default", MemberId::new_v4());
+        leader.leader = true;
+        leader.ip = String::from("10.0.0.1");
+        let mut follower = CensusEntry::new("db", "default", MemberId::new_v4());
+        follower.follower = true;
+        follower.ip = String::from("10.0.0.2");
+        let mut suspect = CensusEntry::new("db", "default", MemberId::new_v4());
+        suspect.set_suspect();
+        suspect.ip = String::from("10.0.0.3");
+        let mut census = Census::new(leader);
+        census.add(follower);
+        census.add(suspect);
+        let mut cl = CensusList::new(Census::new(CensusEntry::new("redis",
+                                                                  "default",
+                                                                  MemberId::new_v4())));
+        cl.insert(census);
+        let sc = ServiceConfig::new(&Config::default(),
+                                    &gen_pkg(),
+                                    &cl,
+                                    vec![String::from("database:db.default")])
+            .unwrap();
+        let mut data = convert::toml_to_json(sc.to_toml().unwrap());
+        if let Json::Object(ref mut top) = data {
+            let cfg = Json::from_str(r#"{"name": "My-App", "workers": "8", "hosts": ["a", "b"],
+                                       "db": {"port": 5432, "ssl": true}, "empty": ""}"#)
+                .unwrap();
+            top.insert(String::from("cfg"), cfg);
+        }
+        data
+    }
+
+    fn render(template: &str) -> String {
+        let mut hb = handlebars();
+        hb.register_template_string("t", template.to_string()).unwrap();
+        hb.render("t", &data()).unwrap()
+    }
+
+    #[test]
+    fn to_json() {
+        let rendered = render("{{toJson cfg.db}}");
+        let parsed = Json::from_str(&rendered).unwrap();
+        assert_eq!(parsed.find("port").and_then(|p| p.as_i64()), Some(5432));
+    }
+
+    #[test]
+    fn to_toml() {
+        let rendered = render("{{toToml cfg.db}}");
+        assert!(rendered.contains("port = 5432"));
+        assert!(rendered.contains("ssl = true"));
+    }
+
+    #[test]
+    fn to_yaml() {
+        assert_eq!(render("{{toYaml cfg.db}}"), "port: 5432\nssl: true\n");
+        let nested = Json::from_str(r#"{"a": [1, {"b": "c d"}], "e": {}, "f g": null}"#).unwrap();
+        assert_eq!(yaml(&nested),
+                   "a:\n  - 1\n  -\n    b: \"c d\"\ne: {}\n\"f g\": null\n");
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(render("{{toUppercase cfg.name}}"), "MY-APP");
+        assert_eq!(render("{{toLowercase cfg.name}}"), "my-app");
+        assert_eq!(render("{{strReplace cfg.name \"-\" \"_\"}}"), "My_App");
+        assert_eq!(render("{{join cfg.hosts \",\"}}"), "a,b");
+    }
+
+    #[test]
+    fn defaults() {
+        assert_eq!(render("{{default cfg.name \"x\"}}"), "My-App");
+        assert_eq!(render("{{default cfg.missing \"x\"}}"), "x");
+        assert_eq!(render("{{default cfg.empty 6379}}"), "6379");
+    }
+
+    #[test]
+    fn comparisons() {
+        assert_eq!(render("{{#gt cfg.workers 4}}many{{else}}few{{/gt}}"), "many");
+        assert_eq!(render("{{#lt cfg.workers 4}}few{{else}}many{{/lt}}"), "many");
+        assert_eq!(render("{{#eq cfg.db.port 5432}}yes{{/eq}}"), "yes");
+        assert_eq!(render("{{#ne cfg.db.port 5432}}yes{{/ne}}"), "");
+        assert_eq!(render("{{#gte cfg.workers 8}}yes{{/gte}}{{#lte cfg.workers 7}}no{{/lte}}"),
+                   "yes");
+    }
+
+    #[test]
+    fn each_alive() {
+        let rendered = render("{{#eachAlive bind.database.members}}{{ip}};{{/eachAlive}}");
+        let mut ips: Vec<&str> = rendered.split(';').filter(|ip| !ip.is_empty()).collect();
+        ips.sort();
+        assert_eq!(ips, vec!["10.0.0.1", "10.0.0.2"]);
+        assert_eq!(render("{{#eachAlive bind.database.members}}{{#if leader}}L{{/if}}\
+                           {{/eachAlive}}"),
+                   "L");
+    }
+}
@@ -62,6 +62,7 @@ pub fn state_determine_viability(worker: &mut Worker) -> Result<(State, u64)> {
 
 pub fn state_start_election(worker: &mut Worker) -> Result<(State, u64)> {
     outputln!("Starting an election");
+    topology::update_suitability(worker);
     {
         let mut cl = worker.census_list.write().unwrap();
         let mut ce = cl.me_mut();
@@ -87,14 +87,15 @@ fn state_restore_dataset(worker: &mut Worker) -> Result<(State, u64)> {
 }
 
 fn state_check_for_election(worker: &mut Worker) -> Result<(State, u64)> {
-    let (has_quorum, am_leader, am_follower, has_leader) = {
+    let (has_quorum, am_leader, am_follower, has_leader, leader_suspect, leader_departed) = {
         let cl = worker.census_list.read().unwrap();
         let census = cl.local_census();
-        let has_quorum = census.has_quorum();
-        let am_leader = census.me().leader;
-        let am_follower = census.me().follower;
-        let has_leader = census.has_leader();
-        (has_quorum, am_leader, am_follower, has_leader)
+        (census.has_quorum(),
+         census.me().leader,
+         census.me().follower,
+         census.has_leader(),
+         census.leader_suspect(),
+         census.leader_departed())
     };
 
     if has_quorum {
@@ -108,8 +109,19 @@ fn state_check_for_election(worker: &mut Worker) -> Result<(State, u64)> {
                 // If you aren't, you should be a follower!
                 Ok((State::BecomeFollower, 0))
             }
+        } else if leader_suspect {
+            debug!("The leader is suspect; waiting to see if it comes back");
+            Ok((State::CheckForElection, 500))
         } else {
-            outputln!("I have quorum, but no leader; starting an election!");
+            if leader_departed {
+                outputln!("The leader has departed; starting a new term");
+                let mut cl = worker.census_list.write().unwrap();
+                let mut census = cl.local_census_mut();
+                census.no_leaders_allowed();
+                census.me_mut().follower(false);
+            } else {
+                outputln!("I have quorum, but no leader; starting an election!");
+            }
             Ok((State::StartElection, 0))
         }
     } else {
@@ -124,6 +136,7 @@ fn state_check_for_election(worker: &mut Worker) -> Result<(State, u64)> {
                 me.leader(false);
                 me.follower(false);
             }
+            demote(worker);
             outputln!("Stopping the service to ensure there is only one master");
             {
                 let mut supervisor = worker.supervisor.write().unwrap();
@@ -147,6 +160,7 @@ fn state_check_for_election(worker: &mut Worker) -> Result<(State, u64)> {
 
 pub fn state_start_election(worker: &mut Worker) -> Result<(State, u64)> {
     outputln!("Starting election");
+    topology::update_suitability(worker);
     let rumor_list = {
         let el = worker.election_list.read().unwrap();
         el.generate_rumor_list_for(worker.package_name.clone(),
@@ -210,6 +224,10 @@ pub fn state_election(worker: &mut Worker) -> Result<(State, u64)> {
 }
 
 pub fn state_become_leader(worker: &mut Worker) -> Result<(State, u64)> {
+    let was_leader = {
+        let cl = worker.census_list.read().unwrap();
+        cl.me().leader
+    };
     {
         let mut cl = worker.census_list.write().unwrap();
         let mut census = cl.local_census_mut();
@@ -224,6 +242,10 @@ pub fn state_become_leader(worker: &mut Worker) -> Result<(State, u64)> {
             me.vote(None)
         }
     }
+    if !was_leader {
+        outputln!("Becoming the leader");
+        promote(worker);
+    }
     Ok((State::Starting, 200))
 }
 
@@ -237,6 +259,13 @@ pub fn state_become_follower(worker: &mut Worker) -> Result<(State, u64)> {
         }
     }
     outputln!("Becoming a follower");
+    let was_leader = {
+        let cl = worker.census_list.read().unwrap();
+        cl.me().leader
+    };
+    if was_leader {
+        demote(worker);
+    }
     {
         let mut cl = worker.census_list.write().unwrap();
         {
@@ -282,3 +311,22 @@ fn initialize(worker: &mut Worker) -> Result<()> {
         Err(e) => Err(e),
     }
 }
+
+/// Run the promote hook. A failing hook is reported, but we stay the leader; the election has
+/// already been decided.
+fn promote(worker: &mut Worker) {
+    let service_config = worker.service_config.read().unwrap();
+    let package = worker.package.read().unwrap();
+    if let Err(e) = package.promote(&service_config) {
+        outputln!("Promote hook failed: {}", e);
+    }
+}
+
+/// Run the demote hook. A failing hook is reported, but we step down regardless.
+fn demote(worker: &mut Worker) {
+    let service_config = worker.service_config.read().unwrap();
+    let package = worker.package.read().unwrap();
+    if let Err(e) = package.demote(&service_config) {
+        outputln!("Demote hook failed: {}", e);
+    }
+}
@@ -22,34 +22,47 @@ pub mod initializer;
 use std::mem;
 use std::net::SocketAddrV4;
 use std::ops::DerefMut;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::{Arc, RwLock};
-use std::sync::mpsc::TryRecvError;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
 use std::thread;
 use std::time::Duration;
 
+use hcore;
+use hcore::service::ServiceGroup;
+use dns;
 use wonder;
 
 use state_machine::StateMachine;
 use census::{self, CensusList};
 use common::gossip_file::GossipFileList;
-use package::{self, Package, PackageUpdaterActor};
+use package::{self, Package, PackageUpdaterActor, UpdateWatch};
 use util::signals::SignalNotifier;
 use error::{Result, SupError};
 use config::Config;
+use config_history::History;
 use service_config::ServiceConfig;
-use sidecar;
+use sidecar::{self, ControlAuth, ControlRequest};
 use supervisor::Supervisor;
 use gossip;
 use gossip::rumor::{Rumor, RumorList};
 use gossip::member::MemberList;
 use election::ElectionList;
-use time::SteadyTime;
-use util::signals;
+use health_check::{self, CheckResult};
+use time::{self, SteadyTime};
+use util::{reaper, signals};
+use watch::{Topic, Watcher};
 use config::UpdateStrategy;
 
 static LOGKEY: &'static str = "TP";
 static MINIMUM_LOOP_TIME_MS: i64 = 200;
+/// How often we run the health check while waiting for a package update to prove itself
+static UPDATE_HEALTH_INTERVAL_MS: i64 = 5000;
+/// How many health check results we keep
+static HEALTH_HISTORY_SIZE: usize = 10;
+/// How often we snapshot the gossip ring state to disk
+static RING_SNAPSHOT_INTERVAL_MS: i64 = 10000;
 
 #[derive(PartialEq, Eq, Debug, RustcEncodable)]
 pub enum Topology {
@@ -107,9 +120,29 @@ pub struct Worker<'a> {
     pub gossip_file_list: Arc<RwLock<GossipFileList>>,
     /// Our Sidecar Actor; exposes a restful HTTP interface to the outside world
     pub sidecar_actor: sidecar::SidecarActor,
+    /// The requests made through the sidecar's control API
+    pub control: Receiver<ControlRequest>,
     /// Watches a package Depot for updates and signals the main thread when an update is available. Optionally
     /// started if a value is passed for the url option on startup.
     pub pkg_updater: Option<PackageUpdaterActor>,
+    /// A package update we are holding until it is our turn in a rolling update
+    pub pending_update: Option<Package>,
+    /// Watches the last applied update, so we can roll back if it fails
+    pub update_watch: Option<UpdateWatch>,
+    /// When we last ran the health check
+    pub health_checked: Option<SteadyTime>,
+    /// True if the health check was asked for through the control API
+    pub health_check_requested: bool,
+    /// The most recent health check results, shared with the sidecar
+    pub health_history: Arc<RwLock<health_check::History>>,
+    /// Where we record changes the sidecar's blocking queries and event streams wait on
+    pub watcher: Arc<Watcher>,
+    /// Where we snapshot the gossip ring state
+    pub ring_snapshot_path: PathBuf,
+    /// When we last snapshot the gossip ring state
+    pub ring_snapshotted: Option<SteadyTime>,
+    /// True if we are PID 1, and so have to reap orphaned processes
+    pub reap_orphans: bool,
     /// The service supervisor
     pub supervisor: Arc<RwLock<Supervisor>>,
     pub return_state: Option<State>,
@@ -139,6 +172,21 @@ impl<'a> Worker<'a> {
             }
         }
 
+        let ring_snapshot_path = hcore::fs::svc_data_path(&package_name)
+            .join(gossip::snapshot::SNAPSHOT_FILENAME);
+        let snapshot = match gossip::snapshot::Snapshot::load(&ring_snapshot_path) {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                outputln!("Ignoring ring snapshot {}: {}",
+                          ring_snapshot_path.display(),
+                          e);
+                None
+            }
+        };
+
+        let service_group = ServiceGroup::new(package_name.clone(),
+                                              config.group(),
+                                              config.organization().clone());
         let gossip_server = gossip::server::Server::new(String::from(config.gossip_listen_ip()),
                                                         config.gossip_listen_port(),
                                                         config.gossip_permanent(),
@@ -147,14 +195,31 @@ impl<'a> Worker<'a> {
                                                         config.group().to_string(),
                                                         config.organization().clone(),
                                                         Some(package_exposes),
-                                                        package_port);
+                                                        package_port,
+                                                        snapshot.as_ref());
+        {
+            let mut gfl = gossip_server.gossip_file_list.write().unwrap();
+            gfl.set_trusted_users(config.config_trusted_users().to_vec());
+        }
+        if let Some(snapshot) = snapshot {
+            gossip_server.restore(snapshot);
+        }
 
         try!(gossip_server.start_inbound());
         try!(gossip_server.initial_peers(config.gossip_peer()));
         gossip_server.start_outbound();
         gossip_server.start_failure_detector();
+        gossip_server.start_sync();
         census::start_health_adjuster(gossip_server.census_list.clone(),
                                       gossip_server.member_list.clone());
+        if let Some((ref ip, port)) = *config.dns_listen() {
+            let listen = try!(SocketAddrV4::from_str(&format!("{}:{}", ip, port)));
+            try!(dns::start(listen, gossip_server.census_list.clone()));
+        }
+        {
+            let mut cl = gossip_server.census_list.write().unwrap();
+            cl.me_mut().package_ident(Some(package_ident.to_string()));
+        }
 
         // Setup the Service Configuration
         let service_config = {
@@ -166,7 +231,11 @@ impl<'a> Worker<'a> {
         let service_config_lock = Arc::new(RwLock::new(service_config));
         let service_config_lock_1 = service_config_lock.clone();
 
-        let supervisor = Arc::new(RwLock::new(Supervisor::new(package_ident)));
+        let supervisor = {
+            let mut supervisor = Supervisor::new(package_ident, &config);
+            supervisor.configure(&pkg_lock.read().unwrap(), &service_config_lock.read().unwrap());
+            Arc::new(RwLock::new(supervisor))
+        };
 
         let sidecar_ml = gossip_server.member_list.clone();
         let sidecar_rl = gossip_server.rumor_list.clone();
@@ -175,6 +244,9 @@ impl<'a> Worker<'a> {
         let sidecar_detector = gossip_server.detector.clone();
         let sidecar_el = gossip_server.election_list.clone();
         let sidecar_sup = supervisor.clone();
+        let health_history = Arc::new(RwLock::new(health_check::History::new(HEALTH_HISTORY_SIZE)));
+        let (control_tx, control_rx) = mpsc::channel();
+        let watcher = Arc::new(Watcher::new());
         let sidecar_listen = try!(SocketAddrV4::from_str(&format!("{}:{}",
                                                                   &config.http_listen_ip(),
                                                                   config.http_listen_port())));
@@ -199,26 +271,305 @@ impl<'a> Worker<'a> {
                                                    sidecar_detector,
                                                    sidecar_el,
                                                    sidecar_sup,
-                                                   sidecar_gfl),
+                                                   sidecar_gfl,
+                                                   control_tx,
+                                                   ControlAuth::new(
+                                                       config.control_token().clone(),
+                                                       config.control_trusted_users().to_vec(),
+                                                       &service_group),
+                                                   health_history.clone(),
+                                                   watcher.clone()),
+            control: control_rx,
             supervisor: supervisor,
             pkg_updater: pkg_updater,
+            pending_update: None,
+            update_watch: None,
+            health_checked: None,
+            health_check_requested: false,
+            health_history: health_history,
+            watcher: watcher,
+            ring_snapshot_path: ring_snapshot_path,
+            ring_snapshotted: None,
+            reap_orphans: reaper::am_i_pid1(),
             return_state: None,
         })
     }
 
-    /// update a package, but does NOT restart the service
-    pub fn update_package(&self, updated: Package) -> Result<()> {
+    /// update a package, but does NOT restart the service. Returns the package we were running
+    /// before.
+    pub fn update_package(&self, updated: Package) -> Result<Package> {
         let service_config = self.service_config.read().unwrap();
-        {
+        let previous = {
             let mut package = self.package.write().unwrap();
-            mem::replace(package.deref_mut(), updated);
-        }
+            mem::replace(package.deref_mut(), updated)
+        };
         let package = self.package.read().unwrap();
         try!(package.copy_run(&service_config));
+        self.supervisor.write().unwrap().configure(&package, &service_config);
+        Ok(previous)
+    }
+
+    /// Apply a package update, and start watching it for failure if a rollback window is
+    /// configured.
+    pub fn apply_update(&mut self, updated: Package) -> Result<()> {
+        let updated_ident = updated.ident().clone();
+        let previous = try!(self.update_package(updated));
+        if self.config.rollback_window() > 0 {
+            let exit_count = self.supervisor.read().unwrap().exit_count;
+            self.update_watch = Some(UpdateWatch::new(previous,
+                                                      updated_ident,
+                                                      self.config.rollback_window(),
+                                                      exit_count));
+        }
         Ok(())
     }
 }
 
+/// Run the suitability hook, if the package has one, and record the result in our census entry
+/// and in the elections we start. Called before an election; if the hook fails, we keep our
+/// previous suitability.
+pub fn update_suitability(worker: &mut Worker) {
+    let result = {
+        let package = worker.package.read().unwrap();
+        let service_config = worker.service_config.read().unwrap();
+        package.suitability(&service_config)
+    };
+    match result {
+        Ok(Some(suitability)) => {
+            outputln!("Our suitability is {}", suitability);
+            {
+                let mut cl = worker.census_list.write().unwrap();
+                cl.me_mut().suitability(suitability as u64);
+            }
+            worker.election_list.write().unwrap().suitability = suitability;
+        }
+        Ok(None) => {}
+        Err(e) => outputln!("Suitability hook failed; keeping our previous suitability: {}", e),
+    }
+}
+
+/// Run the health check if it is due, record the result, and publish the status in our census
+/// entry. The check is due every `health_check_interval` seconds, or every
+/// `UPDATE_HEALTH_INTERVAL_MS` while we are waiting on an update to prove itself, or right away
+/// if it was asked for through the control API.
+fn scheduled_health_check(worker: &mut Worker) -> Option<CheckResult> {
+    let updating = {
+        let cl = worker.census_list.read().unwrap();
+        cl.me().is_updating()
+    };
+    let interval = if updating || worker.update_watch.is_some() {
+        Some(time::Duration::milliseconds(UPDATE_HEALTH_INTERVAL_MS))
+    } else if worker.config.health_check_interval() > 0 {
+        Some(time::Duration::seconds(worker.config.health_check_interval() as i64))
+    } else {
+        None
+    };
+    let requested = mem::replace(&mut worker.health_check_requested, false);
+    let check_due = match (interval, worker.health_checked) {
+        _ if requested => true,
+        (Some(interval), Some(last)) => SteadyTime::now() - last > interval,
+        (Some(_), None) => true,
+        (None, _) => false,
+    };
+    if !check_due {
+        return None;
+    }
+    let started = SteadyTime::now();
+    let result = {
+        let package = worker.package.read().unwrap();
+        let service_config = worker.service_config.read().unwrap();
+        let supervisor = worker.supervisor.read().unwrap();
+        if !supervisor.has_started {
+            return None;
+        }
+        if supervisor.pid.is_none() {
+            CheckResult::critical(format!("{} is not running", package))
+        } else {
+            match package.health_check(&service_config, &supervisor) {
+                Ok(result) => result,
+                Err(e) => CheckResult::unknown(format!("{}", e)),
+            }
+        }
+    };
+    let now = SteadyTime::now();
+    worker.health_checked = Some(now);
+    debug!("Health check: {}", result);
+    {
+        let mut cl = worker.census_list.write().unwrap();
+        cl.me_mut().health_check(Some(result.status.clone()));
+    }
+    worker.health_history.write().unwrap().record(result.clone(), now - started);
+    Some(result)
+}
+
+/// Drive a rolling update forward. Returns true if the service should be restarted.
+///
+/// 1. If we are holding an update, and it is our turn according to the census, mark ourselves as
+///    updating, swap in the new package and restart
+/// 1. If we are updating and the health check returns `Ok`, record the new package in our census
+///    entry, and let the next member go
+fn rolling_update(worker: &mut Worker, check: Option<&CheckResult>) -> Result<bool> {
+    let my_turn = match worker.pending_update {
+        Some(ref package) => {
+            let cl = worker.census_list.read().unwrap();
+            cl.local_census().rolling_update_turn(&package.ident().to_string())
+        }
+        None => false,
+    };
+    if my_turn {
+        let package = worker.pending_update.take().unwrap();
+        outputln!("It is our turn in the rolling update to {}", package);
+        {
+            let mut cl = worker.census_list.write().unwrap();
+            cl.me_mut().updating(true);
+        }
+        try!(worker.apply_update(package));
+        worker.health_checked = Some(SteadyTime::now());
+        outputln!("Restarting because the package was updated");
+        return Ok(true);
+    }
+
+    let updating = {
+        let cl = worker.census_list.read().unwrap();
+        cl.me().is_updating()
+    };
+    if !updating {
+        return Ok(false);
+    }
+    match check {
+        Some(result) if result.status == health_check::Status::Ok => {
+            let ident = worker.package.read().unwrap().ident().to_string();
+            outputln!("Rolling update to {} is healthy; handing off to the next member",
+                      ident);
+            {
+                let mut cl = worker.census_list.write().unwrap();
+                let me = cl.me_mut();
+                me.package_ident(Some(ident));
+                me.updating(false);
+            }
+            if let Some(ref updater) = worker.pkg_updater {
+                try!(package::PackageUpdater::run(updater));
+            }
+        }
+        Some(result) => debug!("Waiting for rolling update to become healthy: {}", result),
+        None => {}
+    }
+    Ok(false)
+}
+
+/// Watch a freshly applied update for failure. Returns true if the service should be restarted.
+///
+/// If the service exits too often or keeps failing its health check within the rollback window,
+/// we restore the previous release and tell the package updater to never apply the failed one
+/// again. The failed release is recorded in our census entry, so a rolling update moves on to the
+/// next member rather than waiting for us. Once the window passes, the update is considered good.
+fn watch_update(worker: &mut Worker, check: Option<&CheckResult>) -> Result<bool> {
+    let exit_count = worker.supervisor.read().unwrap().exit_count;
+    let (failed, expired) = match worker.update_watch {
+        Some(ref mut watch) => {
+            if let Some(result) = check {
+                watch.record_check(result);
+            }
+            (watch.failed(exit_count), watch.expired())
+        }
+        None => return Ok(false),
+    };
+    if failed {
+        let watch = worker.update_watch.take().unwrap();
+        outputln!("Update to {} failed; rolling back to {}",
+                  watch.updated,
+                  watch.previous);
+        try!(worker.update_package(watch.previous));
+        {
+            let mut cl = worker.census_list.write().unwrap();
+            let me = cl.me_mut();
+            me.rejected_ident(Some(watch.updated.to_string()));
+            me.updating(false);
+        }
+        if let Some(ref updater) = worker.pkg_updater {
+            try!(package::PackageUpdater::reject(updater, watch.updated));
+            try!(package::PackageUpdater::run(updater));
+        }
+        outputln!("Restarting because the package was rolled back");
+        Ok(true)
+    } else if expired {
+        let watch = worker.update_watch.take().unwrap();
+        outputln!("Update to {} survived the rollback window", watch.updated);
+        Ok(false)
+    } else {
+        Ok(false)
+    }
+}
+
+/// Act on the requests made through the sidecar's control API. Returns true if the service should
+/// be restarted.
+///
+/// Restart, stop and start act on the supervisor right away. We only start or restart a service
+/// the topology has started before, so the control API can't jump ahead of an election or a
+/// quorum.
+fn handle_control(worker: &mut Worker) -> Result<bool> {
+    let mut restart_process = false;
+    loop {
+        let request = match worker.control.try_recv() {
+            Ok(request) => request,
+            Err(TryRecvError::Empty) => break,
+            Err(TryRecvError::Disconnected) => {
+                debug!("The sidecar has gone away; no more control requests");
+                break;
+            }
+        };
+        match request {
+            ControlRequest::Restart | ControlRequest::Start => {
+                let mut supervisor = worker.supervisor.write().unwrap();
+                if !supervisor.has_started {
+                    outputln!("Not starting {}; the topology hasn't started it yet",
+                              worker.package_name);
+                } else if request == ControlRequest::Restart {
+                    outputln!("Restarting because the control API asked us to");
+                    try!(supervisor.restart());
+                } else if supervisor.pid.is_none() {
+                    supervisor.restarts.attempted();
+                    try!(supervisor.start());
+                }
+            }
+            ControlRequest::Stop => {
+                outputln!("Stopping because the control API asked us to");
+                let mut supervisor = worker.supervisor.write().unwrap();
+                try!(supervisor.down());
+            }
+            ControlRequest::Reconfigure => {
+                let mut service_config = worker.service_config.write().unwrap();
+                let package = worker.package.read().unwrap();
+                service_config.cfg(&package);
+                worker.supervisor.write().unwrap().configure(&package, &service_config);
+                if try!(service_config.write(&package)) {
+                    worker.watcher.changed(Topic::Config);
+                    record_config(worker, &package);
+                }
+                try!(package.copy_run(&service_config));
+                let existed = try!(package.reconfigure(&service_config));
+                if !existed {
+                    outputln!("Restarting because the control API asked us to reconfigure, and \
+                               there is no reconfigure hook");
+                    restart_process = true;
+                }
+            }
+            ControlRequest::HealthCheck => worker.health_check_requested = true,
+        }
+    }
+    Ok(restart_process)
+}
+
+/// Add the configuration we just rendered to the service's history, with the version of the
+/// gossip file it came from. Failing to record it is not fatal; the service still runs with it.
+fn record_config(worker: &Worker, package: &Package) {
+    let gossip_version = worker.gossip_file_list.read().unwrap().config_version();
+    let history = History::for_package(package);
+    if let Err(e) = history.record(package, gossip_version, worker.config.config_history()) {
+        outputln!("Failed to record the configuration history: {}", e);
+    }
+}
+
 /// The main loop of a topology.
 ///
 /// 1. Loops forever
@@ -226,6 +577,8 @@ impl<'a> Worker<'a> {
 /// 1. Checks the current `state` of our [StateMachine](../state_machine)
 /// 1. If it is running, we run a non-blocking `waitpid`, and inspect why the child died;
 ///    depending on the circumstances, we may exit with an error here
+/// 1. If we are PID 1, reaps any orphaned processes that have been re-parented to us
+/// 1. Acts on any requests made through the sidecar's control API
 /// 1. Process any discovery events
 /// 1. Trigger the next iteration of the state machine
 ///
@@ -256,8 +609,11 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
                 debug!("SIG = {:?}", sig);
                 match sig {
                     signals::Signal::SIGINT | signals::Signal::SIGTERM => {
-                        let mut supervisor = worker.supervisor.write().unwrap();
-                        try!(supervisor.down());
+                        {
+                            let mut supervisor = worker.supervisor.write().unwrap();
+                            try!(supervisor.down());
+                        }
+                        snapshot_ring(worker);
                         break;
                     }
                     _ => {
@@ -277,9 +633,20 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
         {
             let mut supervisor = worker.supervisor.write().unwrap();
             try!(supervisor.check_process());
+            if worker.reap_orphans {
+                reaper::reap_orphans(supervisor.pid);
+            }
         }
 
-        let mut restart_process = false;
+        let mut restart_process = try!(handle_control(worker));
+
+        {
+            let mut el = worker.election_list.write().unwrap();
+            if el.needs_write() {
+                el.written();
+                worker.watcher.changed(Topic::Election);
+            }
+        }
 
         // This section, and the following really need to be refactored:
         //
@@ -310,6 +677,7 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
                 }
                 let mut cl = worker.census_list.write().unwrap();
                 cl.written();
+                worker.watcher.changed(Topic::Census);
             }
 
             // Don't bother trying to reconfigure if we are in an event - just wait till
@@ -323,6 +691,8 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
                         let package = worker.package.read().unwrap();
                         // Write the configuration, and restart if needed
                         if try!(service_config.write(&package)) {
+                            worker.watcher.changed(Topic::Config);
+                            record_config(worker, &package);
                             try!(package.copy_run(&service_config));
                             try!(package.reconfigure(&service_config));
                             outputln!("Restarting because the service config was updated via the \
@@ -353,6 +723,9 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
                     (false, false)
                 }
             };
+            if needs_file_updated || needs_reconfigure {
+                worker.watcher.changed(Topic::Files);
+            }
             if needs_file_updated {
                 let service_config = worker.service_config.read().unwrap();
                 let package = worker.package.read().unwrap();
@@ -365,7 +738,10 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
                 let mut service_config = worker.service_config.write().unwrap();
                 let package = worker.package.read().unwrap();
                 service_config.cfg(&package);
+                worker.supervisor.write().unwrap().configure(&package, &service_config);
                 if try!(service_config.write(&package)) {
+                    worker.watcher.changed(Topic::Config);
+                    record_config(worker, &package);
                     try!(package.copy_run(&service_config));
                     let existed = try!(package.reconfigure(&service_config));
                     if !existed {
@@ -375,33 +751,59 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
             }
         }
 
-        if let Some(ref updater) = worker.pkg_updater {
-            match updater.receiver.try_recv() {
-                Ok(wonder::actor::Message::Cast(package::UpdaterMessage::Update(package))) => {
-                    debug!("Main loop received package update notification: {:?}",
-                           &package);
-                    try!(worker.update_package(package));
-                    try!(package::PackageUpdater::run(&updater));
+        let update = match worker.pkg_updater {
+            Some(ref updater) => {
+                match updater.receiver.try_recv() {
+                    Ok(wonder::actor::Message::Cast(package::UpdaterMessage::Update(package))) => {
+                        debug!("Main loop received package update notification: {:?}",
+                               &package);
+                        Some(package)
+                    }
+                    Ok(_) => None,
+                    Err(TryRecvError::Empty) => None,
+                    Err(TryRecvError::Disconnected) => {
+                        panic!("package updater crashed!");
+                    }
+                }
+            }
+            None => None,
+        };
+        if let Some(package) = update {
+            match worker.config.update_strategy() {
+                UpdateStrategy::Rolling => {
+                    outputln!("Waiting for our turn in the rolling update to {}", package);
+                    worker.pending_update = Some(package);
+                }
+                _ => {
+                    try!(worker.apply_update(package));
+                    if let Some(ref updater) = worker.pkg_updater {
+                        try!(package::PackageUpdater::run(updater));
+                    }
                     // force the package to restart
                     outputln!("Restarting because the package was updated");
                     restart_process = true;
                 }
-                Ok(_) => {}
-                Err(TryRecvError::Empty) => {}
-                Err(TryRecvError::Disconnected) => {
-                    panic!("package updater crashed!");
-                }
             }
         }
 
+        let check = scheduled_health_check(worker);
+        if let UpdateStrategy::Rolling = worker.config.update_strategy() {
+            if try!(rolling_update(worker, check.as_ref())) {
+                restart_process = true;
+            }
+        }
+        if try!(watch_update(worker, check.as_ref())) {
+            restart_process = true;
+        }
+
         {
             let mut supervisor = worker.supervisor.write().unwrap();
             // If our target is that the process is up
             if supervisor.is_up() {
                 // And no process is running
                 if supervisor.pid.is_none() {
-                    // Start a new one
-                    try!(supervisor.start());
+                    // Start a new one, if our restart policy allows it
+                    try!(supervisor.keep_up());
                 } else {
                     // If we were supposed to restart
                     if restart_process {
@@ -415,6 +817,24 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
             }
         }
 
+        {
+            let supervisor = worker.supervisor.read().unwrap();
+            let mut cl = worker.census_list.write().unwrap();
+            let me = cl.me_mut();
+            me.restart_count(supervisor.restarts.count);
+            me.restart_backoff(supervisor.restarts.backoff.map(|b| b.num_seconds() as u64));
+        }
+
+        let snapshot_due = match worker.ring_snapshotted {
+            Some(last) => {
+                SteadyTime::now() - last >= time::Duration::milliseconds(RING_SNAPSHOT_INTERVAL_MS)
+            }
+            None => true,
+        };
+        if snapshot_due {
+            snapshot_ring(worker);
+        }
+
         // Next state!
         try!(sm.next(worker));
 
@@ -428,3 +848,14 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
     }
     Ok(())
 }
+
+/// Write a snapshot of the gossip ring state, so we can pick up where we left off if we are
+/// restarted. Failing to write it is not fatal; we just try again next time.
+fn snapshot_ring(worker: &mut Worker) {
+    worker.ring_snapshotted = Some(SteadyTime::now());
+    if let Err(e) = worker.gossip_server.snapshot(&worker.ring_snapshot_path) {
+        outputln!("Failed to write ring snapshot {}: {}",
+                  worker.ring_snapshot_path.display(),
+                  e);
+    }
+}
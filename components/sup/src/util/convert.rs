@@ -38,3 +38,26 @@ pub fn toml_table_to_json(toml: BTreeMap<String, toml::Value>) -> Json {
     }
     Json::Object(hashmap)
 }
+
+/// Translates JSON to TOML. TOML has no null, so nulls are left out of arrays and tables, and a
+/// null on its own is `None`.
+pub fn json_to_toml(value: &Json) -> Option<toml::Value> {
+    match *value {
+        Json::I64(i) => Some(toml::Value::Integer(i)),
+        Json::U64(u) => Some(toml::Value::Integer(u as i64)),
+        Json::F64(f) => Some(toml::Value::Float(f)),
+        Json::String(ref s) => Some(toml::Value::String(s.clone())),
+        Json::Boolean(b) => Some(toml::Value::Boolean(b)),
+        Json::Array(ref a) => Some(toml::Value::Array(a.iter().filter_map(json_to_toml).collect())),
+        Json::Object(ref o) => {
+            let mut table = toml::Table::new();
+            for (key, value) in o.iter() {
+                if let Some(value) = json_to_toml(value) {
+                    table.insert(key.clone(), value);
+                }
+            }
+            Some(toml::Value::Table(table))
+        }
+        Json::Null => None,
+    }
+}
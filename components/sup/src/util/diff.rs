@@ -0,0 +1,143 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! Line-by-line diffs, for showing how a configuration file would change.
+
+use std::cmp;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Same,
+    Removed,
+    Added,
+}
+
+/// A unified diff of `old` and `new`, with `context` unchanged lines around each change. Returns
+/// an empty string if they have the same lines.
+pub fn unified(old: &str, new: &str, context: usize) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let ops = ops(&old, &new);
+
+    // Each hunk is a range of ops: every change, with its context, merged with its neighbours.
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for (k, &(op, _)) in ops.iter().enumerate() {
+        if op == Op::Same {
+            continue;
+        }
+        let start = k.saturating_sub(context);
+        let end = cmp::min(ops.len(), k + context + 1);
+        let merged = match hunks.last_mut() {
+            Some(last) => {
+                if start <= last.1 {
+                    last.1 = end;
+                    true
+                } else {
+                    false
+                }
+            }
+            None => false,
+        };
+        if !merged {
+            hunks.push((start, end));
+        }
+    }
+
+    let mut out = String::new();
+    for (start, end) in hunks {
+        let before = &ops[..start];
+        let old_start = before.iter().filter(|&&(op, _)| op != Op::Added).count();
+        let new_start = before.iter().filter(|&&(op, _)| op != Op::Removed).count();
+        let hunk = &ops[start..end];
+        let old_count = hunk.iter().filter(|&&(op, _)| op != Op::Added).count();
+        let new_count = hunk.iter().filter(|&&(op, _)| op != Op::Removed).count();
+        out.push_str(&format!("@@ -{},{} +{},{} @@\n",
+                              if old_count == 0 { old_start } else { old_start + 1 },
+                              old_count,
+                              if new_count == 0 { new_start } else { new_start + 1 },
+                              new_count));
+        for &(op, line) in hunk {
+            let prefix = match op {
+                Op::Same => ' ',
+                Op::Removed => '-',
+                Op::Added => '+',
+            };
+            out.push(prefix);
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// The edits that turn `old` into `new`, from their longest common subsequence.
+fn ops<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(Op, &'a str)> {
+    // lcs[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                cmp::max(lcs[i + 1][j], lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            ops.push((Op::Same, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push((Op::Removed, old[i]));
+            i += 1;
+        } else {
+            ops.push((Op::Added, new[j]));
+            j += 1;
+        }
+    }
+    for line in &old[i..] {
+        ops.push((Op::Removed, *line));
+    }
+    for line in &new[j..] {
+        ops.push((Op::Added, *line));
+    }
+    ops
+}
+
+#[cfg(test)]
+mod test {
+    use super::unified;
+
+    #[test]
+    fn same_is_empty() {
+        assert_eq!(unified("a\nb\n", "a\nb\n", 3), "");
+    }
+
+    #[test]
+    fn shows_changes_with_context() {
+        let old = "a\nb\nc\nd\ne\nf\ng\n";
+        let new = "a\nb\nc\nD\ne\nf\ng\n";
+        assert_eq!(unified(old, new, 1), "@@ -3,3 +3,3 @@\n c\n-d\n+D\n e\n");
+    }
+
+    #[test]
+    fn splits_distant_changes_into_hunks() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n";
+        let new = "one\n2\n3\n4\n5\n6\n7\neight\n";
+        assert_eq!(unified(old, new, 1),
+                   "@@ -1,2 +1,2 @@\n-1\n+one\n 2\n@@ -7,2 +7,2 @@\n 7\n-8\n+eight\n");
+    }
+
+    #[test]
+    fn adds_to_an_empty_file() {
+        assert_eq!(unified("", "port = 80\n", 3), "@@ -0,0 +1,1 @@\n+port = 80\n");
+    }
+}
@@ -6,9 +6,12 @@
 // open source license such as the Apache 2.0 License.
 
 pub mod convert;
+pub mod diff;
 pub mod path;
 pub mod sys;
 pub mod signals;
+pub mod users;
+pub mod reaper;
 
 use std::net::Ipv4Addr;
 use std::net::SocketAddrV4;
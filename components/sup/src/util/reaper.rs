@@ -0,0 +1,189 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! Reaps orphaned processes when the supervisor is PID 1.
+//!
+//! In a container the supervisor is usually the init process, so every process whose parent
+//! exits is re-parented to it, and stays a zombie until it is waited for. We find those zombies
+//! in `/proc` and reap them.
+//!
+//! Children we spawned ourselves are left alone: whoever spawned them is waiting for them, and
+//! reaping them here would steal their exit status. Children we wait for are started with `spawn`
+//! or `output`, which register them while holding the same lock the reaper holds while it reaps,
+//! so a child can't exit and be reaped before it is registered. The caller passes in the
+//! service's pid, which it spawns under the supervisor's lock.
+
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::{Child, Command, Output, Stdio};
+use std::sync::Mutex;
+
+use libc::{self, c_int, pid_t};
+
+/// Where the proc filesystem is mounted
+const PROC_ROOT: &'static str = "/proc";
+
+lazy_static! {
+    static ref WAITING: Mutex<HashSet<u32>> = Mutex::new(HashSet::new());
+}
+
+/// Returns true if the supervisor is the init process, and therefore has to reap orphans.
+pub fn am_i_pid1() -> bool {
+    unsafe { libc::getpid() == 1 }
+}
+
+/// Marks a child as waited for by whoever spawned it, until it is dropped.
+pub struct Waiting(u32);
+
+impl Drop for Waiting {
+    fn drop(&mut self) {
+        WAITING.lock().unwrap().remove(&self.0);
+    }
+}
+
+/// Spawn a child we are going to wait for ourselves, and tell the reaper to leave it alone until
+/// the returned `Waiting` is dropped.
+pub fn spawn(cmd: &mut Command) -> io::Result<(Child, Waiting)> {
+    let mut waiting = WAITING.lock().unwrap();
+    let child = try!(cmd.spawn());
+    let pid = child.id();
+    waiting.insert(pid);
+    Ok((child, Waiting(pid)))
+}
+
+/// Run a command to completion and collect its output, like `Command::output`, without the reaper
+/// taking its exit status.
+pub fn output(cmd: &mut Command) -> io::Result<Output> {
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let (child, _waiting) = try!(spawn(cmd));
+    child.wait_with_output()
+}
+
+/// Reap every zombie child except `service` and the children started with `spawn` or `output`.
+/// Returns how many we reaped.
+pub fn reap_orphans(service: Option<u32>) -> usize {
+    let me = unsafe { libc::getpid() } as u32;
+    let mut reaped = 0;
+    // Hold the lock while we reap, so nothing can be spawned and exit before it's registered
+    let waiting = WAITING.lock().unwrap();
+    for pid in zombie_children(Path::new(PROC_ROOT), me) {
+        if Some(pid) == service || waiting.contains(&pid) {
+            continue;
+        }
+        let mut status: c_int = 0;
+        if unsafe { libc::waitpid(pid as pid_t, &mut status, libc::WNOHANG) } == pid as pid_t {
+            debug!("Reaped orphaned process {} with status {}", pid, status);
+            reaped += 1;
+        }
+    }
+    reaped
+}
+
+/// The pids of the zombie processes whose parent is `parent`
+fn zombie_children(proc_root: &Path, parent: u32) -> Vec<u32> {
+    let entries = match fs::read_dir(proc_root) {
+        Ok(entries) => entries,
+        Err(e) => {
+            debug!("Failed to read {}: {}", proc_root.display(), e);
+            return Vec::new();
+        }
+    };
+    let mut zombies = Vec::new();
+    for entry in entries.filter_map(|e| e.ok()) {
+        let pid = match entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) {
+            Some(pid) => pid,
+            None => continue,
+        };
+        let mut stat = String::new();
+        if File::open(entry.path().join("stat"))
+            .and_then(|mut f| f.read_to_string(&mut stat))
+            .is_err() {
+            continue;
+        }
+        if let Some(('Z', ppid)) = parse_stat(&stat) {
+            if ppid == parent {
+                zombies.push(pid);
+            }
+        }
+    }
+    zombies
+}
+
+/// Parse the state and parent pid out of the contents of `/proc/<pid>/stat`. The command name
+/// comes before them in parentheses, and may itself contain spaces and parentheses, so we start
+/// after the last closing parenthesis.
+fn parse_stat(stat: &str) -> Option<(char, u32)> {
+    let rest = match stat.rfind(')') {
+        Some(i) => &stat[i + 1..],
+        None => return None,
+    };
+    let mut fields = rest.split_whitespace();
+    let state = match fields.next().and_then(|s| s.chars().next()) {
+        Some(state) => state,
+        None => return None,
+    };
+    match fields.next().and_then(|ppid| ppid.parse().ok()) {
+        Some(ppid) => Some((state, ppid)),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::process::Command;
+
+    use tempdir::TempDir;
+
+    use super::{output, parse_stat, spawn, zombie_children, WAITING};
+
+    #[test]
+    fn parse_stat_fields() {
+        assert_eq!(parse_stat("42 (redis-server) S 1 42 42 0 -1"), Some(('S', 1)));
+        assert_eq!(parse_stat("43 (a (weird) name) Z 1 42 42 0 -1"), Some(('Z', 1)));
+        assert_eq!(parse_stat("44 (sh)"), None);
+        assert_eq!(parse_stat("garbage"), None);
+    }
+
+    #[test]
+    fn finds_zombie_children() {
+        let proc_root = TempDir::new("proc").unwrap();
+        for &(pid, stat) in [(42, "42 (sh) Z 1 42 42"),
+                             (43, "43 (sh) S 1 43 43"),
+                             (44, "44 (sh) Z 7 44 44")]
+            .iter() {
+            let dir = proc_root.path().join(pid.to_string());
+            fs::create_dir(&dir).unwrap();
+            File::create(dir.join("stat")).unwrap().write_all(stat.as_bytes()).unwrap();
+        }
+        fs::create_dir(proc_root.path().join("self")).unwrap();
+        assert_eq!(zombie_children(proc_root.path(), 1), vec![42]);
+    }
+
+    #[test]
+    fn spawn_registers_until_dropped() {
+        let pid = {
+            let (mut child, _waiting) = spawn(&mut Command::new("true")).unwrap();
+            assert!(WAITING.lock().unwrap().contains(&child.id()));
+            assert!(child.wait().unwrap().success());
+            child.id()
+        };
+        assert!(!WAITING.lock().unwrap().contains(&pid));
+    }
+
+    #[test]
+    fn output_collects_stdout() {
+        let out = output(Command::new("echo").arg("hello")).unwrap();
+        assert!(out.status.success());
+        assert_eq!(out.stdout, b"hello\n");
+    }
+}
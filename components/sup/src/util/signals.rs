@@ -11,6 +11,7 @@
 //! sent to the running process and notifies the receiver channel of a caught
 //! `signals::Signal`.
 
+use std::str::FromStr;
 use std::sync::{Once, ONCE_INIT};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering, ATOMIC_USIZE_INIT, ATOMIC_BOOL_INIT};
 
@@ -48,7 +49,7 @@ pub enum Message {
 }
 
 /// `i32` representation of each Unix Signal of interest.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Signal {
     /// terminate process - terminal line hangup
     SIGHUP = 1,
@@ -68,6 +69,26 @@ pub enum Signal {
     SIGUSR2 = 31,
 }
 
+impl FromStr for Signal {
+    type Err = SupError;
+
+    /// Parse a signal name, with or without the `SIG` prefix (e.g. `QUIT` or `SIGQUIT`).
+    fn from_str(s: &str) -> Result<Signal> {
+        let name = s.trim().to_uppercase();
+        match name.trim_left_matches("SIG") {
+            "HUP" => Ok(Signal::SIGHUP),
+            "INT" => Ok(Signal::SIGINT),
+            "QUIT" => Ok(Signal::SIGQUIT),
+            "KILL" => Ok(Signal::SIGKILL),
+            "ALRM" => Ok(Signal::SIGALRM),
+            "TERM" => Ok(Signal::SIGTERM),
+            "USR1" => Ok(Signal::SIGUSR1),
+            "USR2" => Ok(Signal::SIGUSR2),
+            _ => Err(sup_error!(Error::InvalidSignal(s.to_string()))),
+        }
+    }
+}
+
 /// Thread worker that traps UNIX signals and sends a `Signal` down the receiver
 /// channel representing the trapped UNIX signal.
 pub struct SignalNotifier;
@@ -189,3 +210,35 @@ pub fn send_signal_to_pid(pid: u32, sig: Signal) -> Result<()> {
         }
     }
 }
+
+/// send a Unix signal to every process in a process group
+pub fn send_signal_to_pgid(pgid: u32, sig: Signal) -> Result<()> {
+    let s = sig as u32;
+    debug!("sending signal {} to process group {}", s, pgid);
+    unsafe {
+        match kill(-(pgid as i32), s) {
+            0 => Ok(()),
+            _ => Err(sup_error!(Error::SignalFailed)),
+        }
+    }
+}
+
+/// Returns true if any process is left in a process group
+pub fn process_group_alive(pgid: u32) -> bool {
+    unsafe { kill(-(pgid as i32), 0) == 0 }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Signal;
+
+    #[test]
+    fn signal_from_str() {
+        assert_eq!(Signal::from_str("QUIT").unwrap(), Signal::SIGQUIT);
+        assert_eq!(Signal::from_str("SIGINT").unwrap(), Signal::SIGINT);
+        assert_eq!(Signal::from_str("term\n").unwrap(), Signal::SIGTERM);
+        assert!(Signal::from_str("SIGBOGUS").is_err());
+    }
+}
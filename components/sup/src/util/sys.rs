@@ -9,6 +9,7 @@ use std::process::Command;
 
 use error::{Error, Result};
 use hcore::util::sys;
+use util::reaper;
 
 static LOGKEY: &'static str = "SY";
 
@@ -27,7 +28,7 @@ pub fn hostname(path: Option<&str>) -> Result<String> {
         cmd.env("PATH", path);
         debug!("Setting shell out PATH={}", path);
     }
-    let output = try!(cmd.output());
+    let output = try!(reaper::output(&mut cmd));
     match output.status.success() {
         true => {
             debug!("Hostname address is {}",
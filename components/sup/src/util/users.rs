@@ -0,0 +1,183 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! Users and groups for the services we supervise.
+//!
+//! Packages declare the user and group their service runs as. When the supervisor runs as root,
+//! it switches to that user and group before it execs the service or any of its hooks. When it
+//! runs as anyone else it can't switch, so children run as the supervisor's own user.
+
+use std::ffi::CString;
+use std::fs;
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::MetadataExt;
+use std::os::unix::process::CommandExt;
+use std::path::Path;
+use std::process::Command;
+use std::ptr;
+
+use libc;
+
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "US";
+
+/// Returns the uid of the named user, or `None` if there is no such user.
+pub fn get_uid_by_name(user: &str) -> Option<u32> {
+    let name = match CString::new(user) {
+        Ok(name) => name,
+        Err(_) => return None,
+    };
+    unsafe {
+        let pwd = libc::getpwnam(name.as_ptr());
+        if pwd.is_null() {
+            None
+        } else {
+            Some((*pwd).pw_uid)
+        }
+    }
+}
+
+/// Returns the gid of the named group, or `None` if there is no such group.
+pub fn get_gid_by_name(group: &str) -> Option<u32> {
+    let name = match CString::new(group) {
+        Ok(name) => name,
+        Err(_) => return None,
+    };
+    unsafe {
+        let grp = libc::getgrnam(name.as_ptr());
+        if grp.is_null() {
+            None
+        } else {
+            Some((*grp).gr_gid)
+        }
+    }
+}
+
+/// Returns true if the supervisor is running as root, and can therefore switch users.
+pub fn am_i_root() -> bool {
+    unsafe { libc::geteuid() == 0 }
+}
+
+/// Set up a command to run as the given user and group. Does nothing unless we are root.
+///
+/// # Errors
+///
+/// * If the user or the group does not exist. We never fall back to running as root.
+pub fn run_as(cmd: &mut Command, user: &str, group: &str) -> Result<()> {
+    if let Some((uid, gid)) = try!(lookup(user, group)) {
+        cmd.uid(uid).gid(gid);
+    }
+    Ok(())
+}
+
+/// The uid and gid to run as the given user and group, or `None` unless we are root.
+///
+/// # Errors
+///
+/// * If the user or the group does not exist. We never fall back to running as root.
+pub fn lookup(user: &str, group: &str) -> Result<Option<(u32, u32)>> {
+    if !am_i_root() {
+        return Ok(None);
+    }
+    let uid = match get_uid_by_name(user) {
+        Some(uid) => uid,
+        None => return Err(sup_error!(Error::UserNotFound(user.to_string()))),
+    };
+    let gid = match get_gid_by_name(group) {
+        Some(gid) => gid,
+        None => return Err(sup_error!(Error::GroupNotFound(group.to_string()))),
+    };
+    Ok(Some((uid, gid)))
+}
+
+/// Switch the calling process to the given uid and gid, dropping any supplementary groups. This
+/// is what `run_as` has the child do, for children that need root for something else before they
+/// exec; call it last from `CommandExt::before_exec`.
+pub fn switch_to(uid: u32, gid: u32) -> io::Result<()> {
+    unsafe {
+        if libc::setgroups(0, ptr::null()) != 0 || libc::setgid(gid) != 0 ||
+           libc::setuid(uid) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+/// Give the given user and group ownership of a path, so a service that has dropped privileges
+/// can still use it. Does nothing unless we are root. This calls `chown(2)` rather than running
+/// `chown`, so there is no child process for the orphan reaper to take from us.
+pub fn give_to<P: AsRef<Path>>(path: P, user: &str, group: &str) -> Result<()> {
+    let (uid, gid) = match try!(lookup(user, group)) {
+        Some(ids) => ids,
+        None => return Ok(()),
+    };
+    let path = try!(CString::new(path.as_ref().as_os_str().as_bytes()));
+    if unsafe { libc::chown(path.as_ptr(), uid, gid) } != 0 {
+        return Err(SupError::from(io::Error::last_os_error()));
+    }
+    Ok(())
+}
+
+/// Whether a file already belongs to the given user and group. Always true unless we are root,
+/// since we only give files away when we are.
+pub fn owned_by<P: AsRef<Path>>(path: P, user: &str, group: &str) -> bool {
+    if !am_i_root() {
+        return true;
+    }
+    match fs::metadata(path) {
+        Ok(meta) => {
+            Some(meta.uid()) == get_uid_by_name(user) && Some(meta.gid()) == get_gid_by_name(group)
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::process::Command;
+
+    use tempdir::TempDir;
+
+    use super::{am_i_root, get_gid_by_name, get_uid_by_name, give_to, owned_by, run_as};
+
+    #[test]
+    fn root_user_and_group() {
+        assert_eq!(get_uid_by_name("root"), Some(0));
+        assert_eq!(get_gid_by_name("root"), Some(0));
+    }
+
+    #[test]
+    fn missing_user_and_group() {
+        assert_eq!(get_uid_by_name("no-such-habitat-user"), None);
+        assert_eq!(get_gid_by_name("no-such-habitat-group"), None);
+        assert_eq!(get_uid_by_name("nul\0user"), None);
+    }
+
+    #[test]
+    fn run_as_missing_user_fails_when_root() {
+        let mut cmd = Command::new("true");
+        let result = run_as(&mut cmd, "no-such-habitat-user", "root");
+        assert_eq!(result.is_err(), am_i_root());
+    }
+
+    #[test]
+    fn root_owns_the_root_directory() {
+        assert!(owned_by("/", "root", "root"));
+    }
+
+    #[test]
+    fn give_to_root() {
+        let dir = TempDir::new("users").unwrap();
+        let path = dir.path().join("hook");
+        File::create(&path).unwrap();
+        give_to(&path, "root", "root").unwrap();
+        assert!(owned_by(&path, "root", "root"));
+    }
+}
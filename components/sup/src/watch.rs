@@ -0,0 +1,285 @@
+// Copyright:: Copyright (c) 2015-2016 The Habitat Maintainers
+//
+// The terms of the Evaluation Agreement (Habitat) between Chef Software Inc.
+// and the party accessing this file ("Licensee") apply to Licensee's use of
+// the Software until such time that the Software is made available under an
+// open source license such as the Apache 2.0 License.
+
+//! Tracks changes to the census, elections, gossip files and rendered configuration, so the
+//! sidecar can hold a request until something changes.
+//!
+//! Every topic has an index that starts at 1 and goes up by one each time the main loop sees it
+//! change. A blocking query passes the index it last saw, and waits until the topic moves past
+//! it; the event stream waits for any of the topics it follows to move.
+//!
+//! Every waiting request holds one of the HTTP API's worker threads, so only `MAX_WAITERS` may
+//! wait at once, and the rest of the threads are left for everything else.
+
+use std::fmt;
+use std::result;
+use std::str::FromStr;
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// How long a blocking query waits when it doesn't say
+pub const DEFAULT_WAIT_SECS: u64 = 30;
+
+/// The longest a blocking query may wait
+pub const MAX_WAIT_SECS: u64 = 300;
+
+/// How many blocking queries and event streams may wait at once
+pub const MAX_WAITERS: usize = 4;
+
+/// Something a client can watch for changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topic {
+    Census,
+    Election,
+    Files,
+    Config,
+}
+
+impl Topic {
+    /// Every topic
+    pub fn all() -> [Topic; 4] {
+        [Topic::Census, Topic::Election, Topic::Files, Topic::Config]
+    }
+
+    fn slot(&self) -> usize {
+        match *self {
+            Topic::Census => 0,
+            Topic::Election => 1,
+            Topic::Files => 2,
+            Topic::Config => 3,
+        }
+    }
+}
+
+impl fmt::Display for Topic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let topic = match *self {
+            Topic::Census => "census",
+            Topic::Election => "election",
+            Topic::Files => "files",
+            Topic::Config => "config",
+        };
+        write!(f, "{}", topic)
+    }
+}
+
+impl FromStr for Topic {
+    type Err = String;
+
+    fn from_str(topic: &str) -> result::Result<Topic, String> {
+        match topic {
+            "census" => Ok(Topic::Census),
+            "election" => Ok(Topic::Election),
+            "files" => Ok(Topic::Files),
+            "config" => Ok(Topic::Config),
+            _ => Err(format!("unknown topic {}", topic)),
+        }
+    }
+}
+
+/// The index of every topic at some point in time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Indexes([u64; 4]);
+
+impl Indexes {
+    pub fn get(&self, topic: Topic) -> u64 {
+        self.0[topic.slot()]
+    }
+
+    pub fn set(&mut self, topic: Topic, index: u64) {
+        self.0[topic.slot()] = index;
+    }
+}
+
+/// Where the main loop records changes, and the sidecar waits for them.
+#[derive(Debug)]
+pub struct Watcher {
+    indexes: Mutex<Indexes>,
+    changed: Condvar,
+    /// How many requests are waiting
+    waiters: Arc<AtomicUsize>,
+}
+
+impl Watcher {
+    pub fn new() -> Watcher {
+        Watcher {
+            indexes: Mutex::new(Indexes([1; 4])),
+            changed: Condvar::new(),
+            waiters: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Make room for a request that is going to wait, or return `None` if `MAX_WAITERS`
+    /// requests are waiting already. The room is given up when the `Waiter` is dropped.
+    pub fn waiter(&self) -> Option<Waiter> {
+        if self.waiters.fetch_add(1, Ordering::SeqCst) >= MAX_WAITERS {
+            self.waiters.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        Some(Waiter(self.waiters.clone()))
+    }
+
+    /// The current index of a topic.
+    pub fn index(&self, topic: Topic) -> u64 {
+        self.indexes.lock().unwrap().get(topic)
+    }
+
+    /// Record a change to a topic, waking everything waiting on it.
+    pub fn changed(&self, topic: Topic) {
+        let mut indexes = self.indexes.lock().unwrap();
+        let next = indexes.get(topic) + 1;
+        indexes.set(topic, next);
+        self.changed.notify_all();
+    }
+
+    /// Wait until the index of `topic` is past `index`, or `wait` has gone by. Returns the index
+    /// of the topic.
+    pub fn wait(&self, topic: Topic, index: u64, wait: Duration) -> u64 {
+        let mut seen = Indexes::default();
+        seen.set(topic, index);
+        self.wait_any(&[topic], &seen, wait).get(topic)
+    }
+
+    /// Wait until the index of any of `topics` is past its index in `seen`, or `wait` has gone
+    /// by. Returns the index of every topic.
+    pub fn wait_any(&self, topics: &[Topic], seen: &Indexes, wait: Duration) -> Indexes {
+        let deadline = Instant::now() + wait;
+        let mut indexes = self.indexes.lock().unwrap();
+        while !topics.iter().any(|&topic| indexes.get(topic) > seen.get(topic)) {
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+            indexes = self.changed.wait_timeout(indexes, deadline - now).unwrap().0;
+        }
+        *indexes
+    }
+}
+
+/// One of the requests waiting on a `Watcher`
+#[derive(Debug)]
+pub struct Waiter(Arc<AtomicUsize>);
+
+impl Drop for Waiter {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Parse how long a blocking query should wait: a number of seconds, optionally followed by `s`,
+/// or a number followed by `ms` or `m`. Waits longer than `MAX_WAIT_SECS` are cut short.
+pub fn parse_wait(wait: &str) -> Option<Duration> {
+    let wait = wait.trim();
+    let (number, millis_per) = if wait.ends_with("ms") {
+        (&wait[..wait.len() - 2], 1)
+    } else if wait.ends_with('m') {
+        (&wait[..wait.len() - 1], 60_000)
+    } else if wait.ends_with('s') {
+        (&wait[..wait.len() - 1], 1_000)
+    } else {
+        (wait, 1_000)
+    };
+    match number.parse::<u64>() {
+        Ok(n) => {
+            let millis = n.saturating_mul(millis_per);
+            if millis > MAX_WAIT_SECS * 1_000 {
+                Some(Duration::from_secs(MAX_WAIT_SECS))
+            } else {
+                Some(Duration::from_millis(millis))
+            }
+        }
+        Err(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use super::{parse_wait, Indexes, Topic, Watcher, MAX_WAITERS, MAX_WAIT_SECS};
+
+    #[test]
+    fn indexes_start_at_one_and_count_changes() {
+        let watcher = Watcher::new();
+        assert_eq!(watcher.index(Topic::Census), 1);
+        watcher.changed(Topic::Census);
+        watcher.changed(Topic::Census);
+        assert_eq!(watcher.index(Topic::Census), 3);
+        assert_eq!(watcher.index(Topic::Config), 1);
+    }
+
+    #[test]
+    fn wait_returns_at_once_when_behind() {
+        let watcher = Watcher::new();
+        watcher.changed(Topic::Files);
+        let start = Instant::now();
+        assert_eq!(watcher.wait(Topic::Files, 1, Duration::from_secs(10)), 2);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn wait_times_out() {
+        let watcher = Watcher::new();
+        assert_eq!(watcher.wait(Topic::Election, 1, Duration::from_millis(50)), 1);
+    }
+
+    #[test]
+    fn wait_wakes_on_change() {
+        let watcher = Arc::new(Watcher::new());
+        let changer = watcher.clone();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            changer.changed(Topic::Config);
+        });
+        assert_eq!(watcher.wait(Topic::Config, 1, Duration::from_secs(10)), 2);
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn wait_any_ignores_other_topics() {
+        let watcher = Watcher::new();
+        watcher.changed(Topic::Census);
+        let mut seen = Indexes::default();
+        seen.set(Topic::Census, 2);
+        seen.set(Topic::Config, 1);
+        let indexes = watcher.wait_any(&[Topic::Census, Topic::Config],
+                                       &seen,
+                                       Duration::from_millis(50));
+        assert_eq!(indexes.get(Topic::Census), 2);
+        assert_eq!(indexes.get(Topic::Config), 1);
+    }
+
+    #[test]
+    fn waiters_are_capped() {
+        let watcher = Watcher::new();
+        let waiters: Vec<_> = (0..MAX_WAITERS).map(|_| watcher.waiter().unwrap()).collect();
+        assert!(watcher.waiter().is_none());
+        drop(waiters);
+        assert!(watcher.waiter().is_some());
+    }
+
+    #[test]
+    fn topics_round_trip() {
+        for topic in Topic::all().iter() {
+            assert_eq!(topic.to_string().parse::<Topic>(), Ok(*topic));
+        }
+        assert!("rumors".parse::<Topic>().is_err());
+    }
+
+    #[test]
+    fn parses_waits() {
+        assert_eq!(parse_wait("30s"), Some(Duration::from_secs(30)));
+        assert_eq!(parse_wait("30"), Some(Duration::from_secs(30)));
+        assert_eq!(parse_wait("500ms"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_wait("2m"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_wait("1h"), None);
+        assert_eq!(parse_wait("10m"), Some(Duration::from_secs(MAX_WAIT_SECS)));
+    }
+}
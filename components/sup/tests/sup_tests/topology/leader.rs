@@ -117,6 +117,44 @@ fn elects_on_failure() {
     }
 }
 
+// Start three supervisors; once they have a leader, kill its container outright. Once the failure
+// detector confirms the leader is dead, the two followers should start a new term, elect a new
+// leader from amongst themselves, and run the promote hook on it.
+#[test]
+fn fails_over_when_leader_is_killed() {
+    setup::origin_setup();
+    setup::simple_service_gossip();
+
+    let mut sups = vec![Supervisor::new_with_topology("leader")];
+    sups.push(Supervisor::with_peer_topology(&sups[0], "leader"));
+    sups.push(Supervisor::with_peer_topology(&sups[0], "leader"));
+
+    for sup in sups.iter() {
+        for other in sups.iter().filter(|s| s.id != sup.id) {
+            assert!(sup.wait_for_alive(other));
+        }
+        assert!(sup.wait_for_leader());
+    }
+    let term = sups[0].term();
+
+    let og_leader_id = sups.iter_mut()
+        .filter_map(|sup| sup.kill_if_leader())
+        .next()
+        .expect("We had a leader, but then.. we couldn't find it in our list");
+
+    let survivors: Vec<&Supervisor> = sups.iter().filter(|s| s.id != og_leader_id).collect();
+    assert_eq!(survivors.len(), 2);
+    for sup in survivors.iter() {
+        assert!(sup.wait_for_term_newer_than(term));
+        assert!(sup.wait_for_leader());
+        assert!(sup.leader() != og_leader_id);
+    }
+    assert_eq!(survivors[0].leader(), survivors[1].leader());
+
+    let new_leader = survivors.iter().find(|s| s.id == survivors[0].leader()).unwrap();
+    assert_docker_log!(new_leader.docker, r"Promoted to leader");
+}
+
 // Start five supervisors. Partition the leader and one follower. This results in the leader not
 // having quorum, stopping the service, and the three supervisors with quorum electing a new leader
 // amongst themselves.
@@ -233,6 +233,13 @@ impl Docker {
         cmd.wait_with_output();
     }
 
+    /// Kill the container outright, without giving the supervisor a chance to shut down.
+    pub fn kill(&self) {
+        let mut cmd = command::run("docker", &["kill", &self.container_id])
+            .unwrap_or_else(|x| panic!("{:?}", x));
+        cmd.wait_with_output();
+    }
+
     pub fn exec(&self, args: &[&str]) {
         let mut real_args = vec!["exec", &self.container_id];
         for arg in args.iter() {
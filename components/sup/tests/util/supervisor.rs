@@ -340,6 +340,21 @@ impl Supervisor {
         self.running = false;
     }
 
+    pub fn kill(&mut self) {
+        self.docker.kill();
+        self.running = false;
+    }
+
+    pub fn kill_if_leader(&mut self) -> Option<String> {
+        let leader = self.leader();
+        if leader == self.id {
+            self.kill();
+            Some(self.id.clone())
+        } else {
+            None
+        }
+    }
+
     pub fn stop_if_leader<'a>(&'a mut self) -> Option<String> {
         let leader = self.leader();
         if leader == self.id {